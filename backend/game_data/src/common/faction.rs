@@ -4,10 +4,14 @@ use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 use ts_rs::TS;
 
-use crate::components::{
-    planet::Planet,
-    system::{systems, SystemType},
-    tech::Technology,
+use crate::{
+    components::{
+        planet::Planet,
+        system::{systems, SystemType},
+        tech::Technology,
+        unit::{FactionUnitInfo, Production, Roll, Unit, UnitAbility, UnitStats},
+    },
+    enum_map::EnumMap,
 };
 
 use super::{expansions::Expansion, game_settings::Expansions};
@@ -53,6 +57,47 @@ pub enum Faction {
     FirmamentObsidian,
 }
 
+/// Where a faction picks its starting technologies from during setup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum StartingTechPool {
+    /// Any base game technology without prerequisites.
+    NoPrerequisites,
+    /// One of the listed technologies.
+    Listed(Vec<Technology>),
+    /// Base game technologies owned by the other players.
+    OtherPlayers,
+    /// The technologies are researched one at a time, prerequisites apply.
+    Research,
+}
+
+/// A choice of starting technologies made during setup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct StartingTechChoice {
+    /// How many technologies are picked.
+    pub count: usize,
+    /// Where the technologies are picked from.
+    pub pool: StartingTechPool,
+}
+
+/// The faction specific choices a player has to make during setup.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct FactionSetup {
+    /// The starting technologies the player picks, if any.
+    pub techs: Option<StartingTechChoice>,
+    /// The factions whose home system the player may pick their starting planets from.
+    ///
+    /// Empty if the faction starts with the planets of its own home system.
+    pub home_system_choices: Vec<Faction>,
+    /// The units the faction starts the game with, `None` if they aren't known and have to be
+    /// placed by the player.
+    pub starting_units: Option<EnumMap<Unit, u32>>,
+}
+
 impl Faction {
     /// Returns which expansion the faction belongs to.
     pub fn expansion(&self) -> Expansion {
@@ -126,9 +171,104 @@ impl Faction {
         })
     }
 
+    /// Returns the printed commodity value of the faction, if known.
+    pub fn commodities(&self) -> Option<u32> {
+        Some(match self {
+            Faction::Arborec => 3,
+            Faction::BaronyOfLetnev => 2,
+            Faction::ClanOfSaar => 3,
+            Faction::EmbersOfMuaat => 4,
+            Faction::EmiratesOfHacan => 6,
+            Faction::FederationOfSol => 4,
+            Faction::GhostsOfCreuss => 4,
+            Faction::L1Z1XMindnet => 2,
+            Faction::MentakCoalition => 2,
+            Faction::NaaluCollective => 3,
+            Faction::NekroVirus => 3,
+            Faction::SardakkNorr => 3,
+            Faction::UniversitiesOfJolNar => 4,
+            Faction::Winnu => 3,
+            Faction::XxchaKingdom => 4,
+            Faction::YinBrotherhood => 2,
+            Faction::YssarilTribes => 3,
+            Faction::ArgentFlight => 3,
+            Faction::Empyrean => 4,
+            Faction::MahactGeneSorcerers => 3,
+            Faction::NaazRokhaAlliance => 3,
+            Faction::Nomad => 4,
+            Faction::TitansOfUl => 2,
+            Faction::VuilRaithCabal => 2,
+            Faction::CouncilKeleres => 2,
+            // TODO: Fill in once the Thunder's Edge faction sheets are available.
+            Faction::LastBastion
+            | Faction::RalNelConsortium
+            | Faction::DeepwroughtScolarate
+            | Faction::CrimsonRebellion
+            | Faction::FirmamentObsidian => return None,
+        })
+    }
+
+    /// Returns the choices the faction has to make during setup, and the units it starts with.
+    pub fn setup(&self) -> FactionSetup {
+        let techs = |count, pool| Some(StartingTechChoice { count, pool });
+        let setup = match self {
+            Faction::Winnu => FactionSetup {
+                techs: techs(1, StartingTechPool::NoPrerequisites),
+                ..Default::default()
+            },
+            Faction::ArgentFlight => FactionSetup {
+                techs: techs(
+                    2,
+                    StartingTechPool::Listed(vec![
+                        Technology::NeuralMotivator,
+                        Technology::SarweenTools,
+                        Technology::PlasmaScoring,
+                    ]),
+                ),
+                ..Default::default()
+            },
+            Faction::CouncilKeleres => FactionSetup {
+                techs: techs(2, StartingTechPool::OtherPlayers),
+                home_system_choices: vec![
+                    Faction::MentakCoalition,
+                    Faction::XxchaKingdom,
+                    Faction::ArgentFlight,
+                ],
+                ..Default::default()
+            },
+            Faction::DeepwroughtScolarate => FactionSetup {
+                techs: techs(2, StartingTechPool::Research),
+                ..Default::default()
+            },
+            // TODO: Fill in once the Thunder's Edge faction sheets are available, these factions
+            // pick their starting techs.
+            Faction::LastBastion
+            | Faction::RalNelConsortium
+            | Faction::CrimsonRebellion
+            | Faction::FirmamentObsidian => FactionSetup::default(),
+            _ => FactionSetup::default(),
+        };
+
+        FactionSetup {
+            starting_units: self.starting_units(),
+            ..setup
+        }
+    }
+
     /// Returns a set of the planets the faction starts with.
+    ///
+    /// Factions that pick their starting planets during setup (see [Faction::setup]) start
+    /// without planets.
     pub fn get_starting_planets(&self) -> HashSet<Planet> {
-        // TODO: Handle Council Keleres (they get to chose one from the Mentak/XXcha/Argent Flights starting systems)
+        if !self.setup().home_system_choices.is_empty() {
+            return HashSet::new();
+        }
+
+        self.home_system_planets()
+    }
+
+    /// Returns a set of the planets in the home system of the faction.
+    pub fn home_system_planets(&self) -> HashSet<Planet> {
         systems()
             .values()
             .filter(|s| match &s.system_type {
@@ -143,10 +283,7 @@ impl Faction {
     /// Returns a set of the technologies the faction starts with.
     pub fn get_starting_techs(&self, expansions: &Expansions) -> HashSet<Technology> {
         match self {
-            Faction::Arborec => vec![
-                Technology::MagenDefenceGrid,
-                Technology::MagenDefenceGridOmega,
-            ],
+            Faction::Arborec => vec![Technology::MagenDefenceGrid],
             Faction::BaronyOfLetnev => {
                 vec![Technology::AntimassDeflectors, Technology::PlasmaScoring]
             }
@@ -189,20 +326,390 @@ impl Faction {
                 Technology::ScanlinkDroneNetwork,
             ],
             Faction::VuilRaithCabal => vec![Technology::SelfAssemblyRoutines],
-            Faction::CouncilKeleres => vec![/* Gets to pick starting techs */],
-            Faction::LastBastion => vec![/* Gets to pick starting techs */],
-            Faction::RalNelConsortium => vec![/* Gets to pick starting techs */],
-            Faction::DeepwroughtScolarate => {
-                vec![/* Gets to research twice at the start of the game */]
-            }
-            Faction::CrimsonRebellion => vec![/* Gets to pick a starting tech */],
-            Faction::FirmamentObsidian => vec![/* Gets to pick a starting tech */],
+            // Picked during setup, see [Faction::setup].
+            Faction::CouncilKeleres | Faction::DeepwroughtScolarate => vec![],
+            // TODO: Fill in once the Thunder's Edge faction sheets are available.
+            Faction::LastBastion
+            | Faction::RalNelConsortium
+            | Faction::CrimsonRebellion
+            | Faction::FirmamentObsidian => vec![],
         }
         .into_iter()
+        .map(|tech| tech.version_in(expansions))
         .filter(|tech| tech.is_enabled_in(expansions))
         .collect()
     }
 
+    /// Returns the flagship of the faction, if known.
+    pub fn flagship(&self) -> Option<FactionUnitInfo> {
+        let (name, stats) = match self {
+            Faction::Arborec => (
+                "Duha Menaimon",
+                UnitStats::flagship(Roll::multiple(7, 2), 1, 5),
+            ),
+            Faction::BaronyOfLetnev => (
+                "Arc Secundus",
+                UnitStats::flagship(Roll::multiple(5, 2), 1, 3)
+                    .with_ability(UnitAbility::Bombardment(Roll::multiple(5, 3))),
+            ),
+            Faction::ClanOfSaar => (
+                "Son of Ragh",
+                UnitStats::flagship(Roll::multiple(5, 2), 1, 3)
+                    .with_ability(UnitAbility::AntiFighterBarrage(Roll::multiple(6, 4))),
+            ),
+            Faction::EmbersOfMuaat => (
+                "The Inferno",
+                UnitStats::flagship(Roll::multiple(5, 2), 1, 3),
+            ),
+            Faction::EmiratesOfHacan => (
+                "Wrath of Kenara",
+                UnitStats::flagship(Roll::multiple(7, 2), 1, 3),
+            ),
+            Faction::FederationOfSol => {
+                ("Genesis", UnitStats::flagship(Roll::multiple(5, 2), 1, 12))
+            }
+            Faction::GhostsOfCreuss => ("Hil Colish", UnitStats::flagship(Roll::single(5), 1, 3)),
+            Faction::L1Z1XMindnet => ("[0.0.1]", UnitStats::flagship(Roll::multiple(5, 2), 1, 5)),
+            Faction::MentakCoalition => (
+                "Fourth Moon",
+                UnitStats::flagship(Roll::multiple(7, 2), 1, 3),
+            ),
+            Faction::NaaluCollective => {
+                ("Matriarch", UnitStats::flagship(Roll::multiple(9, 2), 1, 6))
+            }
+            Faction::NekroVirus => (
+                "The Alastor",
+                UnitStats::flagship(Roll::multiple(9, 2), 1, 3),
+            ),
+            Faction::SardakkNorr => (
+                "C'morran N'orr",
+                UnitStats::flagship(Roll::multiple(6, 2), 1, 3),
+            ),
+            Faction::UniversitiesOfJolNar => (
+                "J.N.S. Hylarim",
+                UnitStats::flagship(Roll::multiple(6, 2), 1, 3),
+            ),
+            // Rolls one die for each of the opponent's non-fighter ships.
+            Faction::Winnu => (
+                "Salai Sai Corian",
+                UnitStats::flagship(Roll::single(7), 1, 3),
+            ),
+            Faction::XxchaKingdom => (
+                "Loncara Ssodu",
+                UnitStats::flagship(Roll::multiple(7, 2), 1, 3).with_ability(
+                    UnitAbility::SpaceCannon {
+                        roll: Roll::multiple(5, 3),
+                        adjacent: true,
+                    },
+                ),
+            ),
+            Faction::YinBrotherhood => {
+                ("Van Hauge", UnitStats::flagship(Roll::multiple(9, 2), 1, 3))
+            }
+            Faction::YssarilTribes => (
+                "Y'sia Y'ssrila",
+                UnitStats::flagship(Roll::multiple(5, 2), 2, 3),
+            ),
+            Faction::ArgentFlight => (
+                "Quetzecoatl",
+                UnitStats::flagship(Roll::multiple(7, 2), 1, 3),
+            ),
+            Faction::Empyrean => ("Dynamo", UnitStats::flagship(Roll::multiple(5, 2), 1, 3)),
+            Faction::MahactGeneSorcerers => (
+                "Arvicon Rex",
+                UnitStats::flagship(Roll::multiple(5, 2), 1, 3),
+            ),
+            Faction::NaazRokhaAlliance => (
+                "Visz El Vir",
+                UnitStats::flagship(Roll::multiple(9, 2), 1, 4),
+            ),
+            Faction::Nomad => (
+                "Memoria",
+                UnitStats::flagship(Roll::multiple(7, 2), 1, 3)
+                    .with_ability(UnitAbility::AntiFighterBarrage(Roll::multiple(8, 3))),
+            ),
+            Faction::TitansOfUl => ("Ouranos", UnitStats::flagship(Roll::multiple(7, 2), 1, 3)),
+            Faction::VuilRaithCabal => (
+                "The Terror Between",
+                UnitStats::flagship(Roll::multiple(5, 2), 1, 3)
+                    .with_ability(UnitAbility::Bombardment(Roll::single(5))),
+            ),
+            Faction::CouncilKeleres => {
+                ("Artemiris", UnitStats::flagship(Roll::multiple(7, 2), 1, 6))
+            }
+            // TODO: Fill in once the Thunder's Edge faction sheets are available.
+            Faction::LastBastion
+            | Faction::RalNelConsortium
+            | Faction::DeepwroughtScolarate
+            | Faction::CrimsonRebellion
+            | Faction::FirmamentObsidian => return None,
+        };
+
+        Some(FactionUnitInfo {
+            name: name.to_string(),
+            unit: Unit::Flagship,
+            stats,
+        })
+    }
+
+    /// Returns the mech of the faction, if known.
+    ///
+    /// Mechs are only enabled if [Unit::Mech] is enabled.
+    pub fn mech(&self) -> Option<FactionUnitInfo> {
+        let (name, stats) = match self {
+            Faction::Arborec => (
+                "Letani Behemoth",
+                UnitStats::mech()
+                    .with_production(Production::Fixed(2))
+                    .with_ability(UnitAbility::PlanetaryShield),
+            ),
+            Faction::BaronyOfLetnev => ("Dunlain Reaper", UnitStats::mech()),
+            Faction::ClanOfSaar => ("Scavenger Zeta", UnitStats::mech()),
+            Faction::EmbersOfMuaat => ("Ember Colossus", UnitStats::mech()),
+            Faction::EmiratesOfHacan => ("Pride of Kenara", UnitStats::mech()),
+            Faction::FederationOfSol => ("ZS Thunderbolt M2", UnitStats::mech()),
+            Faction::GhostsOfCreuss => ("Icarus Drive", UnitStats::mech()),
+            Faction::L1Z1XMindnet => (
+                "Annihilator",
+                UnitStats::mech().with_ability(UnitAbility::Bombardment(Roll::single(8))),
+            ),
+            Faction::MentakCoalition => ("Moll Terminus", UnitStats::mech()),
+            Faction::NaaluCollective => ("Iconoclast", UnitStats::mech()),
+            Faction::NekroVirus => ("Mordred", UnitStats::mech()),
+            Faction::SardakkNorr => ("Valkyrie Exoskeleton", UnitStats::mech()),
+            Faction::UniversitiesOfJolNar => ("Shield Paling", UnitStats::mech()),
+            Faction::Winnu => ("Reclamation", UnitStats::mech()),
+            Faction::XxchaKingdom => (
+                "Indomitus",
+                UnitStats::mech().with_ability(UnitAbility::SpaceCannon {
+                    roll: Roll::single(8),
+                    adjacent: false,
+                }),
+            ),
+            Faction::YinBrotherhood => ("Moyin's Ashes", UnitStats::mech()),
+            Faction::YssarilTribes => ("Blackshade Infiltrator", UnitStats::mech()),
+            Faction::ArgentFlight => ("Aerie Sentinel", UnitStats::mech()),
+            Faction::Empyrean => ("Watcher", UnitStats::mech()),
+            Faction::MahactGeneSorcerers => ("Starlancer", UnitStats::mech()),
+            Faction::NaazRokhaAlliance => ("Eidolon", UnitStats::mech()),
+            Faction::Nomad => ("Quantum Manipulator", UnitStats::mech()),
+            Faction::TitansOfUl => ("Hecatoncheires", UnitStats::mech()),
+            Faction::VuilRaithCabal => ("Reanimator", UnitStats::mech()),
+            Faction::CouncilKeleres => ("Omniopiares", UnitStats::mech()),
+            // TODO: Fill in once the Thunder's Edge faction sheets are available.
+            Faction::LastBastion
+            | Faction::RalNelConsortium
+            | Faction::DeepwroughtScolarate
+            | Faction::CrimsonRebellion
+            | Faction::FirmamentObsidian => return None,
+        };
+
+        Some(FactionUnitInfo {
+            name: name.to_string(),
+            unit: Unit::Mech,
+            stats,
+        })
+    }
+
+    /// Returns the units the faction starts the game with, if known.
+    fn starting_units(&self) -> Option<EnumMap<Unit, u32>> {
+        use Unit::*;
+
+        let units: &[(Unit, u32)] = match self {
+            Faction::Arborec => &[
+                (Carrier, 1),
+                (Cruiser, 1),
+                (Fighter, 2),
+                (Infantry, 4),
+                (Pds, 1),
+                (SpaceDock, 1),
+            ],
+            Faction::BaronyOfLetnev => &[
+                (Dreadnought, 2),
+                (Carrier, 1),
+                (Destroyer, 1),
+                (Fighter, 1),
+                (Infantry, 3),
+                (SpaceDock, 1),
+            ],
+            Faction::ClanOfSaar => &[
+                (Carrier, 2),
+                (Cruiser, 1),
+                (Fighter, 2),
+                (Infantry, 4),
+                (SpaceDock, 1),
+            ],
+            Faction::EmbersOfMuaat => &[(WarSun, 1), (Fighter, 2), (Infantry, 4), (SpaceDock, 1)],
+            Faction::EmiratesOfHacan => &[
+                (Carrier, 2),
+                (Cruiser, 1),
+                (Fighter, 2),
+                (Infantry, 4),
+                (SpaceDock, 1),
+            ],
+            Faction::FederationOfSol => &[
+                (Carrier, 2),
+                (Destroyer, 1),
+                (Fighter, 3),
+                (Infantry, 5),
+                (SpaceDock, 1),
+            ],
+            Faction::GhostsOfCreuss => &[
+                (Carrier, 2),
+                (Destroyer, 2),
+                (Fighter, 2),
+                (Infantry, 4),
+                (SpaceDock, 1),
+            ],
+            Faction::L1Z1XMindnet => &[
+                (Dreadnought, 1),
+                (Carrier, 1),
+                (Fighter, 3),
+                (Infantry, 5),
+                (Pds, 1),
+                (SpaceDock, 1),
+            ],
+            Faction::MentakCoalition => &[
+                (Carrier, 1),
+                (Cruiser, 2),
+                (Fighter, 3),
+                (Infantry, 4),
+                (Pds, 1),
+                (SpaceDock, 1),
+            ],
+            Faction::NaaluCollective => &[
+                (Carrier, 1),
+                (Cruiser, 1),
+                (Destroyer, 1),
+                (Fighter, 3),
+                (Infantry, 4),
+                (Pds, 1),
+                (SpaceDock, 1),
+            ],
+            Faction::NekroVirus => &[
+                (Dreadnought, 1),
+                (Carrier, 1),
+                (Cruiser, 1),
+                (Fighter, 2),
+                (Infantry, 2),
+                (SpaceDock, 1),
+            ],
+            Faction::SardakkNorr => &[
+                (Carrier, 2),
+                (Cruiser, 1),
+                (Infantry, 5),
+                (Pds, 1),
+                (SpaceDock, 1),
+            ],
+            Faction::UniversitiesOfJolNar => &[
+                (Dreadnought, 1),
+                (Carrier, 2),
+                (Fighter, 1),
+                (Infantry, 2),
+                (Pds, 2),
+                (SpaceDock, 1),
+            ],
+            Faction::Winnu => &[
+                (Carrier, 1),
+                (Cruiser, 1),
+                (Fighter, 2),
+                (Infantry, 2),
+                (Pds, 1),
+                (SpaceDock, 1),
+            ],
+            Faction::XxchaKingdom => &[
+                (Carrier, 1),
+                (Cruiser, 2),
+                (Fighter, 3),
+                (Infantry, 4),
+                (Pds, 1),
+                (SpaceDock, 1),
+            ],
+            Faction::YinBrotherhood => &[
+                (Carrier, 2),
+                (Destroyer, 1),
+                (Fighter, 4),
+                (Infantry, 4),
+                (SpaceDock, 1),
+            ],
+            Faction::YssarilTribes => &[
+                (Carrier, 2),
+                (Cruiser, 1),
+                (Fighter, 2),
+                (Infantry, 5),
+                (Pds, 1),
+                (SpaceDock, 1),
+            ],
+            Faction::ArgentFlight => &[
+                (Carrier, 1),
+                (Destroyer, 2),
+                (Fighter, 2),
+                (Infantry, 5),
+                (SpaceDock, 1),
+            ],
+            Faction::Empyrean => &[
+                (Carrier, 2),
+                (Destroyer, 1),
+                (Fighter, 2),
+                (Infantry, 4),
+                (SpaceDock, 1),
+            ],
+            Faction::MahactGeneSorcerers => &[
+                (Dreadnought, 1),
+                (Carrier, 2),
+                (Fighter, 2),
+                (Infantry, 3),
+                (SpaceDock, 1),
+            ],
+            Faction::NaazRokhaAlliance => &[
+                (Carrier, 2),
+                (Destroyer, 1),
+                (Fighter, 2),
+                (Mech, 1),
+                (Infantry, 3),
+                (SpaceDock, 1),
+            ],
+            Faction::Nomad => &[
+                (Flagship, 1),
+                (Carrier, 1),
+                (Destroyer, 1),
+                (Fighter, 3),
+                (Infantry, 4),
+                (SpaceDock, 1),
+            ],
+            Faction::TitansOfUl => &[
+                (Dreadnought, 1),
+                (Cruiser, 2),
+                (Fighter, 2),
+                (Infantry, 3),
+                (SpaceDock, 1),
+            ],
+            Faction::VuilRaithCabal => &[
+                (Dreadnought, 1),
+                (Carrier, 1),
+                (Cruiser, 1),
+                (Fighter, 3),
+                (Infantry, 3),
+                (SpaceDock, 1),
+            ],
+            Faction::CouncilKeleres => &[
+                (Carrier, 2),
+                (Cruiser, 1),
+                (Fighter, 2),
+                (Infantry, 2),
+                (SpaceDock, 1),
+            ],
+            // TODO: Fill in once the Thunder's Edge faction sheets are available.
+            Faction::LastBastion
+            | Faction::RalNelConsortium
+            | Faction::DeepwroughtScolarate
+            | Faction::CrimsonRebellion
+            | Faction::FirmamentObsidian => return None,
+        };
+
+        Some(units.iter().copied().collect())
+    }
+
     /// Tries to parse the name of a faction into a faction (note: Must match w/e naming scheme milty draft is using!)
     pub fn parse(name: &str) -> eyre::Result<Self> {
         Ok(match name {
@@ -276,3 +783,67 @@ impl Faction {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        common::game_settings::Expansions,
+        components::{tech::Technology, unit::Unit},
+        enum_map::EnumMap,
+    };
+
+    use super::Faction;
+
+    fn units(units: &[(Unit, u32)]) -> Option<EnumMap<Unit, u32>> {
+        Some(units.iter().copied().collect())
+    }
+
+    #[test]
+    fn starting_units_match_the_faction_sheets() {
+        use Unit::*;
+
+        assert_eq!(
+            Faction::UniversitiesOfJolNar.setup().starting_units,
+            units(&[
+                (Dreadnought, 1),
+                (Carrier, 2),
+                (Fighter, 1),
+                (Infantry, 2),
+                (Pds, 2),
+                (SpaceDock, 1),
+            ])
+        );
+        assert_eq!(
+            Faction::FederationOfSol.setup().starting_units,
+            units(&[
+                (Carrier, 2),
+                (Destroyer, 1),
+                (Fighter, 3),
+                (Infantry, 5),
+                (SpaceDock, 1),
+            ])
+        );
+        assert_eq!(
+            Faction::EmbersOfMuaat.setup().starting_units,
+            units(&[(WarSun, 1), (Fighter, 2), (Infantry, 4), (SpaceDock, 1)])
+        );
+    }
+
+    #[test]
+    fn thunders_edge_starting_units_are_unknown() {
+        assert_eq!(Faction::LastBastion.setup().starting_units, None);
+    }
+
+    #[test]
+    fn starting_techs_use_the_codex_1_revisions_when_enabled() {
+        let mut expansions = Expansions::default();
+        let arborec = |expansions: &Expansions| Faction::Arborec.get_starting_techs(expansions);
+        assert_eq!(arborec(&expansions), [Technology::MagenDefenceGrid].into());
+
+        expansions.codex_1 = true;
+        assert_eq!(
+            arborec(&expansions),
+            [Technology::MagenDefenceGridOmega].into()
+        );
+    }
+}
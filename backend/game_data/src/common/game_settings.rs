@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use crate::components::galactic_event::GalacticEvent;
+
 use super::expansions::Expansion;
 
 /// Settings for a game.
@@ -12,6 +14,38 @@ pub struct GameSettings {
     pub max_points: u32,
     /// Which expansions are in play this game.
     pub expansions: Expansions,
+    /// If technology prerequisites should be validated when researching technologies.
+    ///
+    /// Games created before prerequisites were validated don't have this set.
+    #[serde(default)]
+    pub validate_prerequisites: bool,
+    /// If secret objectives should only be scored in their scoring window, or when an out of phase
+    /// scoring opportunity has been recorded.
+    ///
+    /// Games created before scoring windows were validated don't have this set.
+    #[serde(default)]
+    pub validate_scoring_windows: bool,
+    /// If units on the board are tracked, which requires tactical actions to activate a system.
+    ///
+    /// Games created before units were tracked don't have this set.
+    #[serde(default)]
+    pub track_units: bool,
+    /// If players must take their actions in initiative order, starting with their lowest
+    /// strategy card.
+    ///
+    /// Games created before the turn order was enforced don't have this set. Their action phase
+    /// turn order depended on hash map iteration order, so a player starting an action becomes
+    /// the current player instead.
+    #[serde(default)]
+    pub validate_turn_order: bool,
+    /// If action cards that change tracked state must be committed with their payload.
+    ///
+    /// Games created before these payloads were validated don't have this set.
+    #[serde(default)]
+    pub validate_action_card_payloads: bool,
+    /// Which galactic events are in play this game.
+    #[serde(default)]
+    pub galactic_events: Vec<GalacticEvent>,
 }
 
 impl Default for GameSettings {
@@ -19,6 +53,12 @@ impl Default for GameSettings {
         Self {
             max_points: 10,
             expansions: Default::default(),
+            validate_prerequisites: false,
+            validate_scoring_windows: false,
+            track_units: false,
+            validate_turn_order: false,
+            validate_action_card_payloads: false,
+            galactic_events: Vec::new(),
         }
     }
 }
@@ -33,6 +73,7 @@ pub struct Expansions {
     pub codex_1: bool,
     pub codex_2: bool,
     pub codex_3: bool,
+    #[serde(default)]
     pub thunders_edge: bool,
 }
 
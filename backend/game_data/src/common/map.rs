@@ -4,7 +4,7 @@ use eyre::{Context, ContextCompat};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::components::system::{SystemId, systems};
+use crate::components::system::{systems, SystemId};
 
 /// The galactic map.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, TS)]
@@ -25,6 +25,8 @@ impl HexMap {
     const MECATOL_REX_ID: u32 = 18;
     /// The system ID for the mecatol rex system in the Thunder's Edge expansion.
     const MECATOL_REX_OMEGA_ID: u32 = 112;
+    /// The system IDs for the systems in the Fracture, from the Thunder's Edge expansion.
+    const FRACTURE_SYSTEM_IDS: [&'static str; 5] = ["F1", "F2", "F3", "F4", "F5"];
 
     /// Create the map from a milty export string (space separated system ids).
     /// Example `87A1 89B3 47 87A4 89B0 78 37 64 46 29 72 22 24 63 44 40 23 76 50 30 48 28 43 83B2 67 69 34 27 77 26 36 74 83B2 79 19 38 53 42 59 7 0 0 14 21 0 4 39 71 15 80 68 52 0 0 17 75 0 58 41 60`
@@ -112,11 +114,48 @@ impl HexMap {
             });
         }
 
+        if use_te_tiles {
+            for system_id in Self::FRACTURE_SYSTEM_IDS {
+                tiles.push(Tile {
+                    system: systems
+                        .get(system_id)
+                        .wrap_err("Fracture system not in list of systems?")?
+                        .id
+                        .clone(),
+                    position: HexPosition::Fracture,
+                });
+            }
+        }
+
         Ok(HexMap {
             tiles,
             ring_count: ring,
         })
     }
+
+    /// Returns true if the two systems are next to each other on the galactic grid.
+    ///
+    /// Systems outside of the grid, e.g. the Fracture, are only adjacent through wormholes.
+    pub fn are_neighbors(&self, a: &SystemId, b: &SystemId) -> bool {
+        let coordinate = |system: &SystemId| {
+            self.tiles.iter().find_map(|tile| match &tile.position {
+                HexPosition::Pos(coord) if &tile.system == system => Some(coord.clone()),
+                _ => None,
+            })
+        };
+
+        match (coordinate(a), coordinate(b)) {
+            (Some(a), Some(b)) => a.distance_to(&b) == 1,
+            _ => false,
+        }
+    }
+
+    /// Returns the tiles that are in the Fracture, they are only in play once it has been revealed.
+    pub fn fracture_tiles(&self) -> impl Iterator<Item = &Tile> {
+        self.tiles
+            .iter()
+            .filter(|tile| matches!(tile.position, HexPosition::Fracture))
+    }
 }
 
 /// A tile in play.
@@ -135,6 +174,8 @@ pub enum HexPosition {
     OutsideGalaxy,
     /// Inside the galactic grid.
     Pos(Coordinate),
+    /// In the Fracture, the region from Thunder's Edge that is outside of the galactic grid.
+    Fracture,
 }
 
 /// A coordinate of a tile in the system.
@@ -148,6 +189,47 @@ pub struct Coordinate {
     pub rotation: u32,
 }
 
+impl Coordinate {
+    /// The direction to step in along each of the six sides of a ring, going clockwise from the
+    /// top, in cube coordinates.
+    const RING_DIRECTIONS: [(i32, i32, i32); 6] = [
+        (1, 0, -1),
+        (0, 1, -1),
+        (-1, 1, 0),
+        (-1, 0, 1),
+        (0, -1, 1),
+        (1, -1, 0),
+    ];
+
+    /// The number of tiles between the two coordinates.
+    pub fn distance_to(&self, other: &Coordinate) -> u32 {
+        let (q1, r1, s1) = self.cube();
+        let (q2, r2, s2) = other.cube();
+        q1.abs_diff(q2).max(r1.abs_diff(r2)).max(s1.abs_diff(s2))
+    }
+
+    /// The cube coordinates of the tile, with mecatol rex at the origin.
+    fn cube(&self) -> (i32, i32, i32) {
+        let ring = self.ring as i32;
+        let mut cube = (0, -ring, ring);
+        if ring == 0 {
+            return cube;
+        }
+
+        let side = (self.position / self.ring) as usize;
+        let steps = (self.position % self.ring) as i32;
+        for (dq, dr, ds) in &Self::RING_DIRECTIONS[..side] {
+            cube = (cube.0 + dq * ring, cube.1 + dr * ring, cube.2 + ds * ring);
+        }
+        let (dq, dr, ds) = Self::RING_DIRECTIONS[side % 6];
+        (
+            cube.0 + dq * steps,
+            cube.1 + dr * steps,
+            cube.2 + ds * steps,
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 enum MiltySystemId {
     Standard {
@@ -264,3 +346,36 @@ struct HyperLaneTile {
     variant: String,
     rotation: u32,
 }
+
+#[cfg(test)]
+mod test {
+    use super::Coordinate;
+
+    fn coord(ring: u32, position: u32) -> Coordinate {
+        Coordinate {
+            ring,
+            position,
+            rotation: 0,
+        }
+    }
+
+    #[test]
+    fn mecatol_rex_neighbors_the_first_ring() {
+        for position in 0..6 {
+            assert_eq!(coord(0, 0).distance_to(&coord(1, position)), 1);
+        }
+        assert_eq!(coord(0, 0).distance_to(&coord(2, 5)), 2);
+    }
+
+    #[test]
+    fn neighbors_wrap_around_the_ring() {
+        assert_eq!(coord(1, 0).distance_to(&coord(1, 5)), 1);
+        assert_eq!(coord(1, 0).distance_to(&coord(1, 3)), 2);
+        assert_eq!(coord(2, 11).distance_to(&coord(2, 0)), 1);
+        assert_eq!(coord(2, 11).distance_to(&coord(1, 5)), 1);
+        assert_eq!(coord(2, 11).distance_to(&coord(1, 0)), 1);
+        assert_eq!(coord(2, 11).distance_to(&coord(1, 4)), 2);
+        assert_eq!(coord(3, 4).distance_to(&coord(2, 3)), 1);
+        assert_eq!(coord(3, 4).distance_to(&coord(3, 5)), 1);
+    }
+}
@@ -119,8 +119,14 @@ pub enum ActionCardPlay {
     Agenda(AgendaStagePlay),
     StatusPhaseReturnStrategyCards,
     Action,
+    /// After the current player has performed an action, before they end their turn.
+    AfterAction,
     AfterActionCardIsPlayed,
     AfterStrategyCardIsPlayed,
+    /// When a player chooses a strategy card during the strategy phase.
+    StrategyCardIsChosen,
+    /// At some point during a tactical action, e.g. when a system is activated or during combat.
+    TacticalAction,
     NotImplemented,
 }
 
@@ -230,6 +236,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"At the start of an invasion"#,
+                ActionCardPlay::TacticalAction,
                 r#"During this invasion, apply -4 to the result of each Bombardment roll against planets you control."#,
                 r#"Elder Junn crossed his arms and steadied his breathing. The bombs could not reach them, not this far down. At least, that is what the soldiers had told him."#
             ),
@@ -259,6 +266,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After 1 of your ships is destroyed during a space combat"#,
+                ActionCardPlay::TacticalAction,
                 r#"Roll 2 dice. For each result equal to or greater than that ship's combat value, your opponent must choose and destroy 1 of their ships."#,
                 r#"Coughing up blood and covered in burns, Havvat collapsed against the rapidly overheating ship core. She smiled. She would be remembered."#
             ),
@@ -288,6 +296,7 @@ impl ActionCard {
                 Expansion::Base,
                 4,
                 r#"After another player's ship uses Sustain Damage to cancel a hit produced by your units or abilities"#,
+                ActionCardPlay::TacticalAction,
                 r#"Destroy that ship."#,
                 r#"(1) There it was! An opening! Neekuaq gestured wildly, a rare display for one normally quite reserved. "Fire the main battery!"(2) The Loncara Ssodu's main battery flared to life, firing a volley directly into the flickering starboard shield of the Letnev dreadnought.(3) For a moment, it looked as if the dreadnought's shield would hold, but a moment later, the ship began to come apart where the attack had pierced its hull.(4) Neekuaq watched, satisfied, as the ship was wracked by a series of explosions from within, huge armored plates and other debris hurtling off into the darkness."#
             ),
@@ -297,6 +306,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"At the start of an invasion in a system that contains 1 or more of your opponents' PDS units"#,
+                ActionCardPlay::TacticalAction,
                 r#"Your opponents' PDS units lose Planetary Shield and Space Cannon during this invasion."#,
                 r#""Ssruu has met their systems and fixed them." Ssruu dropped a handful of ripped wires and broken circuitry on the pedestal before Q'uesh Sish. "He will await his next task aboard his ship.""#
             ),
@@ -326,6 +336,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"At the start or end of a combat round"#,
+                ActionCardPlay::TacticalAction,
                 r#"Repair all of your units that have Sustain Damage in the active system."#,
                 r#""What do you mean 'It's fine!?'" Dahla said, nearly tripping over a damaged bulkhead as the ship rocked from the explosive barrage."#
             ),
@@ -335,6 +346,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After another player moves ships into a system during a tactical action"#,
+                ActionCardPlay::TacticalAction,
                 r#"Choose 1 of your space docks that is either in or adjacent to that system. That space dock uses Space Cannon 5 (x3) against ships in the active system."#,
                 r#""Arm the relay!" Mendosa bared his fangs. "The Saar will not submit!""#
             ),
@@ -344,6 +356,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"At the start of the first round of a space combat"#,
+                ActionCardPlay::TacticalAction,
                 r#"Apply +2 to the result of each of your fighters' combat rolls during this combat round."#,
                 r#"Suffi vaulted excitedly off the prototype's sleek, azure wing, landing effortlessly in its cockpit. "Let's see those damn snakes keep up with us now!""#
             ),
@@ -353,6 +366,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After your ground forces make combat rolls during a round of ground combat"#,
+                ActionCardPlay::TacticalAction,
                 r#"Reroll any number of your dice."#,
                 r#"Grinning, Jael spun towards the familiar noise of heavy boots hitting the ground. Backup had arrived."#
             ),
@@ -362,6 +376,7 @@ impl ActionCard {
                 Expansion::Base,
                 4,
                 r#"After you activate a system"#,
+                ActionCardPlay::TacticalAction,
                 r#"Apply +1 to the move value of each of your ships during this tactical action."#,
                 r#"(1) Mendosa smirked. "Don't ye fret now, girl. It sounds like ye've got somewhere ta be in a hurry, and it just se happens that I ken get ye there right quick."(2) There was a low-pitched hum throughout the station and the gigantic, bulky constructions on the hull spat blue flame. Massive ion thrusters! So this was how the Saar moved the damned things.(3) Mendosa grinned at the young Mentak pilot's astonishment. "Mobility-the Clan's greatest strength. Ye'd be amazed at how fast this thing ken go."(4) With a sound like thunder and a flash of neon blue light, the thrusters accelerated full bore, nearly knocking Suffi off her feet."#
             ),
@@ -411,6 +426,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After you activate a system"#,
+                ActionCardPlay::TacticalAction,
                 r#"Choose 1 system. During this tactical action, your ships in the chosen system can move through systems that contain other players' ships."#,
                 r#"The Barony ships seemed to defy reality, bending light around them at impossible angles - naught but inky-black contours could be seen."#
             ),
@@ -430,6 +446,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"When you gain control of a planet"#,
+                ActionCardPlay::TacticalAction,
                 r#"Replace each PDS and space dock that is on that planet with a matching unit from your reinforcements."#,
                 r#"The 1X had scarcely interfaced with the mainframe before it belonged to them completely."#
             ),
@@ -449,6 +466,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After your opponent declares a retreat during a space combat"#,
+                ActionCardPlay::TacticalAction,
                 r#"Your opponent cannot retreat during this round of space combat."#,
                 r#"The space before the fleeing ship shimmered and warped, revealing the bow of the Y'sia Yssrila bearing down on its position. There would be no escape today."#
             ),
@@ -468,6 +486,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After you activate a system"#,
+                ActionCardPlay::TacticalAction,
                 r#"During this tactical action, systems that contain alpha and beta wormholes are adjacent to each other."#,
                 r#"The ship's sensors showed that the ancient star-map had somehow led them beyond the Mahact plateau."#
             ),
@@ -487,6 +506,7 @@ impl ActionCard {
                 Expansion::Base,
                 4,
                 r#"Before you assign hits produced by another player's Space Cannon roll"#,
+                ActionCardPlay::TacticalAction,
                 r#"Cancel 1 hit."#,
                 r#"(1) Something was wrong. T'esla sensed a flicker of movement on the planet's surface, followed by a faint glint of light. Reflexively, she veered her fighter to the side - an action that saved her life.(2) The fighter's thruster boosted her just out of the cannon's firing solution, and a metal slug the size of a building rocketed past her crystalline ship.(3) T'esla breathed a sigh of relief as the massive slug disappeared into the distance. A touch slower and she would have been annihilated.(4) The flickering red warning lights on her control panel reflected in her scales as she regained her composure. "Well," she said aloud. "Zat was close.""#
             ),
@@ -506,6 +526,7 @@ impl ActionCard {
                 Expansion::Base,
                 4,
                 r#"At the start of a combat round"#,
+                ActionCardPlay::TacticalAction,
                 r#"Apply +1 to the result of each of your unit's combat rolls during this combat round."#,
                 r#"(1) Harrugh stood before his warriors, searching for the words that would express the pride that swelled within him.(2) "We have done the impossible. I am proud to call you my brothers. My equals. My betters." Harrugh's whiskers bristled with energy as he spoke.(3) "Today we turn these invaders to ash and take back what is ours!" Harrugh paused, noting the murmurs of approval rippling through the ranks.(4) The whispering gradually died down and his warriors watched Harrugh expectantly. Every muscle in his body tensed, and he thrust his gyro-spear skyward. "For Kenara!""#
             ),
@@ -515,6 +536,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After another player commits units to land on a planet you control"#,
+                ActionCardPlay::TacticalAction,
                 r#"Return the committed units to the space area."#,
                 r#"The human admiral returned to his ship, struggling to remember precisely how his meeting with the Collective had gone."#
             ),
@@ -554,6 +576,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"When another player chooses a strategy card during the strategy phase"#,
+                ActionCardPlay::StrategyCardIsChosen,
                 r#"That player must choose a different strategy card instead, if able."#,
                 r#"In the months that followed the report, the Council was quick to censure the Jol-Nar for their unethical practices."#
             ),
@@ -573,6 +596,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After another player gains control of a planet you control"#,
+                ActionCardPlay::TacticalAction,
                 r#"Exhaust 1 planet that player controls and ready 1 planet you control."#,
                 r#"Gila oversaw the reconstruction of the Hacan outpost, but neglected to inform the N'orr representative that the damage was not nearly as severe as he was led to believe."#
             ),
@@ -612,6 +636,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After you win a space combat"#,
+                ActionCardPlay::TacticalAction,
                 r#"Your opponent gives you all of their commodities."#,
                 r#"When the Coalition raiders had finally returned to their ships, scarcely a bolt remained to salvage from the destroyed vessel."#
             ),
@@ -621,6 +646,7 @@ impl ActionCard {
                 Expansion::Base,
                 4,
                 r#"Before you assign hits to your ships during a space combat"#,
+                ActionCardPlay::TacticalAction,
                 r#"Cancel up to 2 hits."#,
                 r#"(1) T'ro stared unflinching at the incoming barrage through the bridge's observation deck. The shield would hold.(2) The blast from the enemy's main cannon slammed into the space in front of the warship, splitting into two streams across the bow.(3) The ship's shields sizzled and sparked but remained active despite the heavy assault.(4) As the assault died down and it became clear that the ship sustained no major damage, T'ro clicked his mandibles in anticipation. "Return fire!""#
             ),
@@ -640,6 +666,7 @@ impl ActionCard {
                 Expansion::Base,
                 4,
                 r#"At the start of a combat round"#,
+                ActionCardPlay::TacticalAction,
                 r#"Move all of your ships from the active system into an adjacent system that does not contain another player's ships; the space combat ends in a draw. Then, place a command token from your reinforcements in that system."#,
                 r#"(1) In an instant, the Sol fleet vanished from the Barony's scanners.        (2) The Creuss fleet was gone. No trace remained of their passage.        (3) T'ro gave the order, and the entire fleet withdrew without question.        (4) "We have no choice, captain." Ciel looked nervous."We must retreat.""#
             ),
@@ -719,6 +746,7 @@ impl ActionCard {
                 Expansion::Base,
                 1,
                 r#"After you activate a system that contains 1 or more of your ships"#,
+                ActionCardPlay::TacticalAction,
                 r#"Replace one of your cruisers in that system with one of your dreadnoughts from your reinforcements."#,
                 r#""More guns?" Connor shook his head. "Not more guns. Let's try something bigger.""#
             ),
@@ -769,6 +797,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"At the start of an invasion:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Each of your non-fighter ships in the active system that do not have BOMBARDMENT gain BOMBARDMENT 6 until the end of the invasion."#,
                 r#""T-these weren't meant to be used like this. T-this is a t-terrible idea." Tai mumbled, wringing his hands. "Well 'course it is," Dart grinned, slamming his fist down on the release lock. "But we've got about sixty kilotons of 'surprise' and nothing to lose!""#
             ),
@@ -778,6 +807,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"After a player activates a system that contains 1 of your command tokens:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Return that command token to your tactic pool."#,
                 r#""That's the thing about loyalty - " Viktor mused, turning one of the pieces backward, facing its own side. "It can be bought.""#
             ),
@@ -797,6 +827,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"After another player activates a system that contains your units:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Gain 3 trade goods. Then, choose another player to gain 1 trade good."#,
                 r#"All eyes turned toward the darkening skies as the only surviving supply truck rolled through the gates. "Get these checked in and send the last shipment to our allies!" Harrugh yelled over the din of the engines."#
             ),
@@ -806,6 +837,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"After a player commits units to land on a planet you control:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Move any number of ground forces from any planet you control in the active system to any other planet you control in the active system."#,
                 r#"The L1 advance team was expecting to find the outpost abandoned. What they found - unfortunately for them - was Connor."#
             ),
@@ -825,6 +857,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"After you activate an anomaly:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Replenish your commodities."#,
                 r#""Manipulating the cosmos was a power reserved for the gods." Rowl spat at the ground as his contraption whirred to life. "Not anymore.""#
             ),
@@ -854,7 +887,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"After you perform an action:"#,
-                ActionCardPlay::AfterActionCardIsPlayed,
+                ActionCardPlay::AfterAction,
                 r#"You may perform an additional action this turn."#,
                 r#"Viktor laced his long fingers with a wicked smile. Unlenn would be proud. The tactician's reputation was well-earned; even pawns could unseat kings."#
             ),
@@ -874,6 +907,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"After you activate a system that contains another player's ships:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Place 2 command tokens from your reinforcements in your fleet pool."#,
                 r#"The Federation advance would not be halted - not today. Claire set her broadcast to play on all open channels. She began, her voice fierce and unwavering "Friends! On this day, our names shall be inscribed in the pages of legend!""#
             ),
@@ -883,6 +917,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"When one of your ships uses SUSTAIN DAMAGE during combat:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Produce 2 hits against your opponent's ships in the active system."#,
                 r#"Prit scrambled up Hesh's shoulder, gleefully clapping as the entropic shielding tore the mercenary cruisers apart."#
             ),
@@ -902,6 +937,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"After another player makes a BOMBARDMENT, SPACE CANNON, or ANTI-FIGHTER BARRAGE roll:"#,
+                ActionCardPlay::TacticalAction,
                 r#"That player rerolls all of their dice."#,
                 r#""I will not accept this from that worthless scum!" Feng growled as his enigmatic companion interfaced with the ship's nav suite. The Memoria's position flickered and shifted, and the blast flashed harmlessly by."#
             ),
@@ -911,6 +947,7 @@ impl ActionCard {
                 Expansion::Codex,
                 1,
                 r#"After you activate a system:"#,
+                ActionCardPlay::TacticalAction,
                 r#"During this movement, other players cannot use SPACE CANNON against your ships."#,
                 r#"Meian braced for combat as the fleet entered the Ordinian system, but there were no Valefar droneships waiting to meet them. The solar flare gambit had succeeded - they were completely undetected."#
             ),
@@ -920,6 +957,7 @@ impl ActionCard {
                 Expansion::Codex,
                 4,
                 r#"When 1 or more of your units use PRODUCTION:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Apply +4 to the total PRODUCTION value of your units and reduce the combined cost of the produced units by 1."#,
                 r#"(1) "This would take weeks to replace if you had run it backwards," Varish called as she rotated the massive capacitor bank. "Okay, fire it up."(2) The station vibrated as the device sputtered to life, emitting a high-pitched buzz to Varish's ears. "Micro-wormholes are cycling at 19 kilohertz," Cole read. "The entropic field tap's stable."(3) The station's production facilities spooled up to new intensities, lights flaring as the assembly line drones increased their speeds to keep up.(4) "Varish, let the Commanders know they can contact the Tetrarchy. Tell them the fighter squadron assembly will be done ahead of schedule, and under budget.""#
             ),
@@ -950,7 +988,7 @@ impl ActionCard {
                 Expansion::ProphecyOfKings,
                 1,
                 r#"When another player would perform a strategic action:"#,
-                ActionCardPlay::AfterActionCardIsPlayed,
+                ActionCardPlay::AfterStrategyCardIsPlayed,
                 r#"End that player's turn; the strategic action is not resolved and the strategy card is not exhausted."#,
                 r#"Artuno shoved the slug pistol into Huro's face as armored mercenaries stormed into the office. "I'm afraid the station's undergoing a change in management.""#
             ),
@@ -970,6 +1008,7 @@ impl ActionCard {
                 Expansion::ProphecyOfKings,
                 1,
                 r#"After another player activates a system that contains 1 or more of your structures:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Remove up to 2 of your ground forces from the game board and place them on a planet you control in the active system."#,
                 r#"Enemy fire lashed the abandoned positions on the ridgeline as Trilossa's flock slipped unseen around their flanks."#
             ),
@@ -1019,6 +1058,7 @@ impl ActionCard {
                 Expansion::ProphecyOfKings,
                 1,
                 r#"After you activate a system:"#,
+                ActionCardPlay::TacticalAction,
                 r#"During the "Movement" step of this tactical action, ignore the effects of anomalies."#,
                 r#""The field is too thick!" Sikosk gasped. Trrakan's plume flared. "Not for me, and not for Iruth." A flick of his talon, and the destroyer flipped and dove for the asteroid field."#
             ),
@@ -1038,6 +1078,7 @@ impl ActionCard {
                 Expansion::ProphecyOfKings,
                 1,
                 r#"At the start of a combat:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Spend 4 resources to research a unit upgrade technology of the same type as 1 of your units that is participating in this combat."#,
                 r#"Varish and Cole completed the final linkages, activating the field harvester. The cruiser's beams roared with renewed power, cutting through the enemy ship."#
             ),
@@ -1047,6 +1088,7 @@ impl ActionCard {
                 Expansion::ProphecyOfKings,
                 1,
                 r#"When another player discards an action card that has a component action:"#,
+                ActionCardPlay::AfterActionCardIsPlayed,
                 r#"Take that action card from the discard pile."#,
                 r#"Xuange spread their hands over the captured harvester. Mirroring their careful gestures, dark energy fields unfolded and began delicately disassembling the device."#
             ),
@@ -1056,6 +1098,7 @@ impl ActionCard {
                 Expansion::ProphecyOfKings,
                 1,
                 r#"At the start of the "Announce Retreats" step of space combat, if you are the defender:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Your opponent must announce a retreat, if able."#,
                 r#"Vuil'raith fleshships swarmed the weary defenders, sending unease and sweeping through the telepathic links. But Z'eu projected a powerful feeling of calm, and the Naalu fighters began to drive the Cabal back toward the rift."#
             ),
@@ -1085,6 +1128,7 @@ impl ActionCard {
                 Expansion::ProphecyOfKings,
                 1,
                 r#"Before you roll dice for ANTI-FIGHTER BARRAGE:"#,
+                ActionCardPlay::TacticalAction,
                 r#"Hits from this roll are produced against all ships (not just fighters)."#,
                 r#"The destroyers sprayed fire with their secondary batteries as they advanced. The Mahact dreadnought began to list, fire and atmosphere gushing from a thousand tiny wounds."#
             ),
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use ts_rs::TS;
+
+use crate::common::{expansions::Expansion, faction::Faction, game_settings::Expansions};
+
+/// A faction breakthrough from Thunder's Edge, each faction has exactly one.
+///
+/// The names and abilities of the breakthroughs aren't known yet, so a breakthrough is identified
+/// by the faction it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct Breakthrough(pub Faction);
+
+impl Breakthrough {
+    /// Iterates over the breakthroughs of every faction.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Faction::iter().map(Breakthrough)
+    }
+
+    /// The faction that this breakthrough belongs to.
+    pub fn faction(&self) -> Faction {
+        self.0
+    }
+
+    /// Is this breakthrough enabled for the given [Expansions]?
+    pub fn is_enabled_in(&self, expansions: &Expansions) -> bool {
+        expansions.is_enabled(&Expansion::ThundersEdge)
+            && expansions.is_enabled(&self.faction().expansion())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use strum::IntoEnumIterator;
+
+    use crate::common::{faction::Faction, game_settings::Expansions};
+
+    use super::Breakthrough;
+
+    #[test]
+    fn breakthroughs_need_thunders_edge_and_the_factions_expansion() {
+        let mut expansions = Expansions {
+            thunders_edge: true,
+            ..Default::default()
+        };
+        assert!(Breakthrough(Faction::Arborec).is_enabled_in(&expansions));
+        assert!(!Breakthrough(Faction::Nomad).is_enabled_in(&expansions));
+
+        expansions.prophecy_of_kings = true;
+        assert!(Breakthrough(Faction::Nomad).is_enabled_in(&expansions));
+
+        expansions.thunders_edge = false;
+        assert!(!Breakthrough(Faction::Arborec).is_enabled_in(&expansions));
+    }
+
+    #[test]
+    fn every_faction_has_one_breakthrough() {
+        let factions = Breakthrough::iter()
+            .map(|breakthrough| breakthrough.faction())
+            .collect::<Vec<_>>();
+        assert_eq!(factions, Faction::iter().collect::<Vec<_>>());
+    }
+}
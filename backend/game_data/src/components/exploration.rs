@@ -0,0 +1,303 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use ts_rs::TS;
+
+use crate::common::expansions::Expansion;
+
+use super::{planet::PlanetTrait, planet_attachment::PlanetAttachment, relic::RelicFragment};
+
+/// A card in one of the planet exploration decks (cultural, hazardous or industrial).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+#[allow(missing_docs)]
+pub enum ExplorationCard {
+    /* Cultural */
+    CulturalRelicFragment,
+    DemilitarizedZone,
+    DysonSphere,
+    Freelancers,
+    GammaWormhole,
+    MercenaryOutfit,
+    ParadiseWorld,
+    TombOfEmphidia,
+    /* Hazardous */
+    HazardousRelicFragment,
+    CoreMine,
+    Expedition,
+    LazaxSurvivors,
+    MiningWorld,
+    RichWorld,
+    VolatileFuelSource,
+    WarfareResearchFacility,
+    /* Industrial */
+    IndustrialRelicFragment,
+    AbandonedWarehouses,
+    BioticResearchFacility,
+    CyberneticResearchFacility,
+    FunctioningBase,
+    LocalFabricators,
+    PropulsionResearchFacility,
+}
+
+/// What happens when an exploration card is drawn.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum ExplorationEffect {
+    /// The card is attached to the explored planet.
+    Attachment(PlanetAttachment),
+    /// The player gains a relic fragment.
+    RelicFragment(RelicFragment),
+    /// The player gains trade goods.
+    TradeGoods(u32),
+    /// The player gains trade goods if they have a mech on the planet or remove an infantry from
+    /// it.
+    GroundForceTradeGoods(u32),
+    /// A gamma wormhole token is placed in the system of the planet and the card is purged.
+    GammaWormhole,
+    /// The player gains (or may produce) a unit, units are not tracked.
+    GainUnit,
+    /// The effect is not tracked, the player resolves it themselves.
+    Other,
+}
+
+impl ExplorationEffect {
+    /// Weather the card is kept out of the deck (attached, held by a player or purged) after it
+    /// has been resolved, rather than being discarded.
+    pub fn leaves_deck(&self) -> bool {
+        matches!(
+            self,
+            ExplorationEffect::Attachment(_)
+                | ExplorationEffect::RelicFragment(_)
+                | ExplorationEffect::GammaWormhole
+        )
+    }
+}
+
+/// All relevant information for an exploration card.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct ExplorationCardInfo {
+    /// Which card this is in regards to.
+    pub card: ExplorationCard,
+    /// The 'pretty' name of the card.
+    pub name: String,
+    /// Which expansion this card comes from.
+    pub expansion: Expansion,
+    /// Which exploration deck the card belongs to.
+    pub deck: PlanetTrait,
+    /// The number of cards that exists in a deck.
+    pub num_in_deck: usize,
+    /// What happens when the card is drawn.
+    pub effect: ExplorationEffect,
+    /// The text on the card.
+    pub text: String,
+}
+
+macro_rules! ei {
+    ($card:ident, $name:literal, $deck:ident, $num:literal, $effect:expr, $text:literal) => {
+        ExplorationCardInfo {
+            card: ExplorationCard::$card,
+            name: $name.to_string(),
+            expansion: Expansion::ProphecyOfKings,
+            deck: PlanetTrait::$deck,
+            num_in_deck: $num,
+            effect: $effect,
+            text: $text.to_string(),
+        }
+    };
+}
+
+impl ExplorationCard {
+    /// Returns the [ExplorationCardInfo] for the exploration card.
+    pub fn info(&self) -> ExplorationCardInfo {
+        use ExplorationEffect::*;
+
+        match self {
+            ExplorationCard::CulturalRelicFragment => ei!(
+                CulturalRelicFragment,
+                "Cultural Relic Fragment",
+                Cultural,
+                9,
+                RelicFragment(super::relic::RelicFragment::Cultural),
+                "ACTION: Purge 3 of your cultural relic fragments to gain 1 relic."
+            ),
+            ExplorationCard::DemilitarizedZone => ei!(
+                DemilitarizedZone,
+                "Demilitarized Zone",
+                Cultural,
+                1,
+                Attachment(PlanetAttachment::DemilitarizedZone),
+                "Attach this card to this planet's card. Then destroy all units on this planet. Players cannot land, produce or place units on this planet."
+            ),
+            ExplorationCard::DysonSphere => ei!(
+                DysonSphere,
+                "Dyson Sphere",
+                Cultural,
+                1,
+                Attachment(PlanetAttachment::DysonSphere),
+                "Attach this card to this planet's card. Its resource value is increased by 2 and its influence value is increased by 1."
+            ),
+            ExplorationCard::Freelancers => ei!(
+                Freelancers,
+                "Freelancers",
+                Cultural,
+                3,
+                GainUnit,
+                "You may produce 1 unit in this system; you may spend influence as if it were resources to produce this unit."
+            ),
+            ExplorationCard::GammaWormhole => ei!(
+                GammaWormhole,
+                "Gamma Wormhole",
+                Cultural,
+                1,
+                GammaWormhole,
+                "Place a gamma wormhole token in this system. Then, purge this card."
+            ),
+            ExplorationCard::MercenaryOutfit => ei!(
+                MercenaryOutfit,
+                "Mercenary Outfit",
+                Cultural,
+                3,
+                GainUnit,
+                "You may place 1 infantry from your reinforcements on this planet."
+            ),
+            ExplorationCard::ParadiseWorld => ei!(
+                ParadiseWorld,
+                "Paradise World",
+                Cultural,
+                1,
+                Attachment(PlanetAttachment::ParadiseWorld),
+                "Attach this card to this planet's card. Its influence value is increased by 2."
+            ),
+            ExplorationCard::TombOfEmphidia => ei!(
+                TombOfEmphidia,
+                "Tomb of Emphidia",
+                Cultural,
+                1,
+                Attachment(PlanetAttachment::TombOfEmphidia),
+                "Attach this card to this planet's card. Its influence value is increased by 1."
+            ),
+            ExplorationCard::HazardousRelicFragment => ei!(
+                HazardousRelicFragment,
+                "Hazardous Relic Fragment",
+                Hazardous,
+                7,
+                RelicFragment(super::relic::RelicFragment::Hazardous),
+                "ACTION: Purge 3 of your hazardous relic fragments to gain 1 relic."
+            ),
+            ExplorationCard::CoreMine => ei!(
+                CoreMine,
+                "Core Mine",
+                Hazardous,
+                3,
+                GroundForceTradeGoods(1),
+                "If you have at least 1 mech on this planet, or if you remove 1 infantry from this planet, gain 1 trade good."
+            ),
+            ExplorationCard::Expedition => ei!(
+                Expedition,
+                "Expedition",
+                Hazardous,
+                3,
+                Other,
+                "If you have at least 1 mech on this planet, or if you remove 1 infantry from this planet, ready this planet."
+            ),
+            ExplorationCard::LazaxSurvivors => ei!(
+                LazaxSurvivors,
+                "Lazax Survivors",
+                Hazardous,
+                1,
+                Attachment(PlanetAttachment::LasaxSurvivors),
+                "Attach this card to this planet's card. Its resource value is increased by 1 and its influence value is increased by 2."
+            ),
+            ExplorationCard::MiningWorld => ei!(
+                MiningWorld,
+                "Mining World",
+                Hazardous,
+                1,
+                Attachment(PlanetAttachment::MiningWorld),
+                "Attach this card to this planet's card. Its resource value is increased by 2."
+            ),
+            ExplorationCard::RichWorld => ei!(
+                RichWorld,
+                "Rich World",
+                Hazardous,
+                1,
+                Attachment(PlanetAttachment::RichWorld),
+                "Attach this card to this planet's card. Its resource value is increased by 1."
+            ),
+            ExplorationCard::VolatileFuelSource => ei!(
+                VolatileFuelSource,
+                "Volatile Fuel Source",
+                Hazardous,
+                3,
+                Other,
+                "If you have at least 1 mech on this planet, or if you remove 1 infantry from this planet, gain 1 command token."
+            ),
+            ExplorationCard::WarfareResearchFacility => ei!(
+                WarfareResearchFacility,
+                "Warfare Research Facility",
+                Hazardous,
+                1,
+                Attachment(PlanetAttachment::WarfareResearchFacility),
+                "Attach this card to this planet's card. This planet has a red technology specialty, if this planet already has a technology specialty, its resource and influence values are each increased by 1 instead."
+            ),
+            ExplorationCard::IndustrialRelicFragment => ei!(
+                IndustrialRelicFragment,
+                "Industrial Relic Fragment",
+                Industrial,
+                5,
+                RelicFragment(super::relic::RelicFragment::Industrial),
+                "ACTION: Purge 3 of your industrial relic fragments to gain 1 relic."
+            ),
+            ExplorationCard::AbandonedWarehouses => ei!(
+                AbandonedWarehouses,
+                "Abandoned Warehouses",
+                Industrial,
+                4,
+                Other,
+                "Gain 2 commodities, or convert up to 2 of your commodities to trade goods."
+            ),
+            ExplorationCard::BioticResearchFacility => ei!(
+                BioticResearchFacility,
+                "Biotic Research Facility",
+                Industrial,
+                1,
+                Attachment(PlanetAttachment::BioticResearchFacility),
+                "Attach this card to this planet's card. This planet has a green technology specialty, if this planet already has a technology specialty, its resource and influence values are each increased by 1 instead."
+            ),
+            ExplorationCard::CyberneticResearchFacility => ei!(
+                CyberneticResearchFacility,
+                "Cybernetic Research Facility",
+                Industrial,
+                1,
+                Attachment(PlanetAttachment::CyberneticResearchFacility),
+                "Attach this card to this planet's card. This planet has a yellow technology specialty, if this planet already has a technology specialty, its resource and influence values are each increased by 1 instead."
+            ),
+            ExplorationCard::FunctioningBase => ei!(
+                FunctioningBase,
+                "Functioning Base",
+                Industrial,
+                4,
+                Other,
+                "Gain 1 commodity, or spend 1 trade good or 1 commodity to draw 1 action card."
+            ),
+            ExplorationCard::LocalFabricators => ei!(
+                LocalFabricators,
+                "Local Fabricators",
+                Industrial,
+                4,
+                GainUnit,
+                "Gain 1 commodity, or if you have at least 1 mech on this planet, you may place 1 mech on this planet."
+            ),
+            ExplorationCard::PropulsionResearchFacility => ei!(
+                PropulsionResearchFacility,
+                "Propulsion Research Facility",
+                Industrial,
+                1,
+                Attachment(PlanetAttachment::PropulsionResearchFacility),
+                "Attach this card to this planet's card. This planet has a blue technology specialty, if this planet already has a technology specialty, its resource and influence values are each increased by 1 instead."
+            ),
+        }
+    }
+}
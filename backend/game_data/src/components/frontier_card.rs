@@ -26,14 +26,41 @@ pub enum FrontierCard {
     MinorEntropicField,
 }
 
-/// When this frontier card can be played.
+/// When this frontier card can be played, or what happens when it is drawn.
 #[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter, TS)]
 #[ts(export)]
 pub enum FrontierCardType {
     /// As an action.
     Action,
-    /// We don't handle this atm.
-    Unhandled,
+    /// The player may draw a secret objective.
+    DrawSecretObjective,
+    /// The player draws action cards.
+    DrawActionCards(u32),
+    /// The player gains a relic fragment of an unknown type.
+    RelicFragment,
+    /// The ion storm token is placed in the system.
+    IonStorm,
+    /// A gamma wormhole token is placed in the system.
+    GammaWormhole,
+    /// The card is placed in the system, making it an entropic field.
+    EntropicField,
+    /// The effect is not tracked, the player resolves it themselves.
+    Other,
+}
+
+impl FrontierCardType {
+    /// Weather the card is kept out of the deck (held by a player or placed on the board) after
+    /// it has been resolved, rather than being discarded.
+    pub fn leaves_deck(&self) -> bool {
+        matches!(
+            self,
+            FrontierCardType::Action
+                | FrontierCardType::RelicFragment
+                | FrontierCardType::IonStorm
+                | FrontierCardType::GammaWormhole
+                | FrontierCardType::EntropicField
+        )
+    }
 }
 
 /// All relevant information for a frontier card.
@@ -49,22 +76,18 @@ pub struct FrontierCardInfo {
     pub expansion: Expansion,
     /// The number of cards that exists in a deck.
     pub num_in_deck: usize,
-    /// Weather this frontier carries with it an action or not.
+    /// Weather this frontier carries with it an action, or what happens when it is drawn.
     pub frontier_type: FrontierCardType,
 }
 
 macro_rules! fi {
-    ($card:ident, $name:literal, $exp:ident, $num:literal) => {
-        fi!($card, $name, $exp, $num, Unhandled)
-    };
-
-    ($card:ident, $name:literal, $exp:ident, $num:literal, $type: ident) => {
+    ($card:ident, $name:literal, $exp:ident, $num:literal, $type:expr) => {
         FrontierCardInfo {
             card: FrontierCard::$card,
             name: $name.to_string(),
             expansion: Expansion::$exp,
             num_in_deck: $num,
-            frontier_type: FrontierCardType::$type,
+            frontier_type: $type,
         }
     };
 }
@@ -72,10 +95,16 @@ macro_rules! fi {
 impl FrontierCard {
     /// Returns the [FrontierCardInfo] for the frontier card.
     pub fn info(&self) -> FrontierCardInfo {
+        use FrontierCardType::*;
+
         match self {
-            FrontierCard::DerelictVessel => {
-                fi!(DerelictVessel, "Derelict Vessel", ProphecyOfKings, 2)
-            }
+            FrontierCard::DerelictVessel => fi!(
+                DerelictVessel,
+                "Derelict Vessel",
+                ProphecyOfKings,
+                2,
+                DrawSecretObjective
+            ),
             FrontierCard::EnigmaticDevice => {
                 fi!(
                     EnigmaticDevice,
@@ -85,28 +114,53 @@ impl FrontierCard {
                     Action
                 )
             }
-            FrontierCard::GammaRelay => fi!(GammaRelay, "Gamma Relay", ProphecyOfKings, 1),
-            FrontierCard::IonStorm => fi!(IonStorm, "Ion Storm", ProphecyOfKings, 1),
-            FrontierCard::LostCrew => fi!(LostCrew, "Lost Crew", ProphecyOfKings, 2),
+            FrontierCard::GammaRelay => {
+                fi!(GammaRelay, "Gamma Relay", ProphecyOfKings, 1, GammaWormhole)
+            }
+            FrontierCard::IonStorm => fi!(IonStorm, "Ion Storm", ProphecyOfKings, 1, IonStorm),
+            FrontierCard::LostCrew => fi!(
+                LostCrew,
+                "Lost Crew",
+                ProphecyOfKings,
+                2,
+                DrawActionCards(2)
+            ),
             FrontierCard::MerchantStation => {
-                fi!(MerchantStation, "Merchant Station", ProphecyOfKings, 2)
+                fi!(
+                    MerchantStation,
+                    "Merchant Station",
+                    ProphecyOfKings,
+                    2,
+                    Other
+                )
             }
             FrontierCard::Mirage => fi!(Mirage, "Mirage", ProphecyOfKings, 1, Action),
             FrontierCard::UnknownRelicFragment => fi!(
                 UnknownRelicFragment,
                 "Unknown Relic Fragment",
                 ProphecyOfKings,
-                3
+                3,
+                RelicFragment
             ),
-            FrontierCard::DeadWorld => fi!(DeadWorld, "Dead World", CodexIII, 1),
-            FrontierCard::EntropicField => fi!(EntropicField, "Entropic Field", CodexIII, 1),
-            FrontierCard::KeleresShip => fi!(KeleresShip, "Keleres Ship", CodexIII, 2),
-            FrontierCard::MajorEntropicField => {
-                fi!(MajorEntropicField, "Major Entropic Field", CodexIII, 1)
-            }
-            FrontierCard::MinorEntropicField => {
-                fi!(MinorEntropicField, "Minor Entropic Field", CodexIII, 1)
+            FrontierCard::DeadWorld => fi!(DeadWorld, "Dead World", CodexIII, 1, Other),
+            FrontierCard::EntropicField => {
+                fi!(EntropicField, "Entropic Field", CodexIII, 1, EntropicField)
             }
+            FrontierCard::KeleresShip => fi!(KeleresShip, "Keleres Ship", CodexIII, 2, Other),
+            FrontierCard::MajorEntropicField => fi!(
+                MajorEntropicField,
+                "Major Entropic Field",
+                CodexIII,
+                1,
+                EntropicField
+            ),
+            FrontierCard::MinorEntropicField => fi!(
+                MinorEntropicField,
+                "Minor Entropic Field",
+                CodexIII,
+                1,
+                EntropicField
+            ),
         }
     }
 }
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use ts_rs::TS;
+
+use crate::common::expansions::Expansion;
+
+/// A galactic event from Thunder's Edge, which modifies the rules for the whole game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter, TS)]
+#[ts(export)]
+#[allow(missing_docs)]
+pub enum GalacticEvent {
+    AdventOfTheWarSun,
+    AgeOfCommerce,
+    AgeOfExploration,
+    CallOfTheVoid,
+    CivilizedSociety,
+    ConventionsOfWarAbandoned,
+    CosmicPhenomenae,
+    CulturalExchangeProgram,
+    DangerousWilds,
+    HiddenAgenda,
+    MercenariesForHire,
+    MinorFactions,
+    MonumentsToTheAges,
+    RapidMobilization,
+    StellarAtomics,
+    TotalWar,
+    WeirdWormholes,
+    WildWildGalaxy,
+    ZealousOrthodoxy,
+}
+
+/// All relevant information about a galactic event.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct GalacticEventInfo {
+    /// The name of the galactic event in 'pretty' format.
+    pub name: String,
+    /// The rules text of the galactic event, empty until the cards are known.
+    pub description: String,
+    /// Which expansion the galactic event belongs to.
+    pub expansion: Expansion,
+}
+
+impl GalacticEvent {
+    /// Returns the [GalacticEventInfo] for this galactic event.
+    pub fn info(&self) -> GalacticEventInfo {
+        let name = match self {
+            GalacticEvent::AdventOfTheWarSun => "Advent of the War Sun",
+            GalacticEvent::AgeOfCommerce => "Age of Commerce",
+            GalacticEvent::AgeOfExploration => "Age of Exploration",
+            GalacticEvent::CallOfTheVoid => "Call of the Void",
+            GalacticEvent::CivilizedSociety => "Civilized Society",
+            GalacticEvent::ConventionsOfWarAbandoned => "Conventions of War Abandoned",
+            GalacticEvent::CosmicPhenomenae => "Cosmic Phenomenae",
+            GalacticEvent::CulturalExchangeProgram => "Cultural Exchange Program",
+            GalacticEvent::DangerousWilds => "Dangerous Wilds",
+            GalacticEvent::HiddenAgenda => "Hidden Agenda",
+            GalacticEvent::MercenariesForHire => "Mercenaries for Hire",
+            GalacticEvent::MinorFactions => "Minor Factions",
+            GalacticEvent::MonumentsToTheAges => "Monuments to the Ages",
+            GalacticEvent::RapidMobilization => "Rapid Mobilization",
+            GalacticEvent::StellarAtomics => "Stellar Atomics",
+            GalacticEvent::TotalWar => "Total War",
+            GalacticEvent::WeirdWormholes => "Weird Wormholes",
+            GalacticEvent::WildWildGalaxy => "Wild Wild Galaxy",
+            GalacticEvent::ZealousOrthodoxy => "Zealous Orthodoxy",
+        };
+
+        GalacticEventInfo {
+            name: name.to_string(),
+            description: String::new(),
+            expansion: Expansion::ThundersEdge,
+        }
+    }
+}
@@ -2,8 +2,14 @@
 pub mod action_card;
 /// Agendas.
 pub mod agenda;
+/// Faction breakthroughs.
+pub mod breakthrough;
+/// Exploration cards.
+pub mod exploration;
 /// Frontier cards.
 pub mod frontier_card;
+/// Galactic events.
+pub mod galactic_event;
 /// Leader cards, i.e. agents, commanders, and heroes.
 pub mod leaders;
 /// Objectives.
@@ -14,6 +20,8 @@ pub mod phase;
 pub mod planet;
 /// Planetary attachments.
 pub mod planet_attachment;
+/// Promissory notes.
+pub mod promissory_note;
 /// Relics
 pub mod relic;
 /// Strategy cards.
@@ -22,3 +30,5 @@ pub mod strategy_card;
 pub mod system;
 /// Technologies.
 pub mod tech;
+/// Units, i.e. ships, ground forces and structures.
+pub mod unit;
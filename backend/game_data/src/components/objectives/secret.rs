@@ -73,6 +73,19 @@ pub enum SecretObjective {
 
 // TODO: Look over codex updates for these.
 impl SecretObjective {
+    /// The [Phase] during which this secret objective can be scored.
+    pub fn scoring_window(&self) -> Phase {
+        match self.info().kind {
+            ObjectiveKind::Secret { phase } => phase,
+            kind => unreachable!("secret objective with non-secret kind {kind:?}"),
+        }
+    }
+
+    /// Returns true if the secret objective can be scored during the provided [Phase].
+    pub fn can_be_scored_in(&self, phase: Phase) -> bool {
+        self.scoring_window() == phase.top_level()
+    }
+
     /// Get the [ObjectiveInfo] for this secret objective.
     pub fn info(&self) -> ObjectiveInfo {
         match self {
@@ -35,6 +35,9 @@ pub enum Phase {
     /// Performing a relic action
     RelicAction,
 
+    /// Performing a technology action
+    TechAction,
+
     /// Player has finished one action turn, check if they're gonna take another one.
     EndActionTurn,
 
@@ -47,3 +50,22 @@ pub enum Phase {
     /// The Agenda phase.
     Agenda,
 }
+
+impl Phase {
+    /// Returns the top-level phase of the game that this phase is a part of, e.g. a tactical
+    /// action is a part of the [Phase::Action] phase.
+    pub fn top_level(&self) -> Phase {
+        match self {
+            Phase::StrategicAction
+            | Phase::TacticalAction
+            | Phase::ActionCardAction
+            | Phase::LeaderAction
+            | Phase::FrontierCardAction
+            | Phase::RelicAction
+            | Phase::TechAction
+            | Phase::EndActionTurn => Phase::Action,
+            Phase::Relics => Phase::Status,
+            phase => *phase,
+        }
+    }
+}
@@ -7,7 +7,7 @@ use crate::common::expansions::Expansion;
 use super::tech::TechCategory;
 
 /// A planetary trait.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
 #[ts(export)]
 #[allow(missing_docs)]
 pub enum PlanetTrait {
@@ -1080,4 +1080,22 @@ impl Planet {
             ),
         }
     }
+
+    /// Returns true if this planet is Mecatol Rex, including the Thunder's Edge variant.
+    pub fn is_mecatol_rex(&self) -> bool {
+        matches!(self, Planet::MecatolRex | Planet::MecatolRexOmega)
+    }
+
+    /// Returns true if this planet is in the Fracture, the region from Thunder's Edge that is only
+    /// in play once it has been revealed.
+    pub fn is_in_fracture(&self) -> bool {
+        matches!(
+            self,
+            Planet::Cocytus
+                | Planet::Styx
+                | Planet::Lethe
+                | Planet::Phlegethon
+                | Planet::ThundersEdge
+        )
+    }
 }
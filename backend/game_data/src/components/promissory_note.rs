@@ -0,0 +1,323 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use ts_rs::TS;
+
+use crate::common::{expansions::Expansion, faction::Faction, game_settings::Expansions};
+
+/// A promissory note.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+#[allow(missing_docs)]
+pub enum PromissoryNote {
+    /* Generic */
+    Ceasefire,
+    PoliticalSecret,
+    SupportForTheThrone,
+    TradeAgreement,
+    Alliance,
+    /* Base factions */
+    Stymie,
+    WarFunding,
+    RaghsCall,
+    FiresOfTheGashlai,
+    TradeConvoys,
+    MilitarySupport,
+    CreussIff,
+    CyberneticEnhancements,
+    PromiseOfProtection,
+    GiftOfPrescience,
+    Antivirus,
+    TekklarLegion,
+    ResearchAgreement,
+    Acquiescence,
+    PoliticalFavor,
+    GreyfireMutagen,
+    SpyNet,
+    /* PoK factions */
+    StrikeWingAmbuscade,
+    BloodPact,
+    DarkPact,
+    ScepterOfDominion,
+    BlackMarketForgery,
+    TheCavalry,
+    Terraform,
+    Crucible,
+    /* Codex I */
+    StymieOmega,
+    WarFundingOmega,
+    CyberneticEnhancementsOmega,
+    AcquiescenceOmega,
+    GreyfireMutagenOmega,
+    /* Codex III */
+    KeleresRider,
+    // TODO: Thunder's Edge faction promissory notes
+}
+
+/// What happens to a promissory note when it is received or played.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+pub enum PromissoryNoteUsage {
+    /// Placed face up in the play area of the receiver as soon as it is received.
+    PlacedOnReceive,
+    /// Placed face up in the play area when played, where it stays until it is returned.
+    PlayArea,
+    /// Returned to its owner after it has been played.
+    Return,
+    /// Attached to a planet when played, it is never returned.
+    Attach,
+}
+
+/// All relevant information for a promissory note.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct PromissoryNoteInfo {
+    /// Which promissory note this is in regards to.
+    pub note: PromissoryNote,
+    /// The 'pretty' name of the promissory note.
+    pub name: String,
+    /// The faction the promissory note belongs to, or `None` if every player has one.
+    pub faction: Option<Faction>,
+    /// Which expansion this promissory note comes from.
+    pub expansion: Expansion,
+    /// What happens to the promissory note when it is received or played.
+    pub usage: PromissoryNoteUsage,
+}
+
+macro_rules! pi {
+    ($note:ident, $name:literal, $exp:ident, $usage:ident) => {
+        PromissoryNoteInfo {
+            note: PromissoryNote::$note,
+            name: $name.to_string(),
+            faction: None,
+            expansion: Expansion::$exp,
+            usage: PromissoryNoteUsage::$usage,
+        }
+    };
+    ($note:ident, $name:literal, $exp:ident, $usage:ident, $faction:ident) => {
+        PromissoryNoteInfo {
+            note: PromissoryNote::$note,
+            name: $name.to_string(),
+            faction: Some(Faction::$faction),
+            expansion: Expansion::$exp,
+            usage: PromissoryNoteUsage::$usage,
+        }
+    };
+}
+
+impl PromissoryNote {
+    /// Is this promissory note enabled for the given [Expansions]?
+    pub fn is_enabled_in(&self, expansions: &Expansions) -> bool {
+        use PromissoryNote::*;
+
+        let info = self.info();
+        if !expansions.is_enabled(&info.expansion) {
+            return false;
+        }
+
+        // check if the faction the promissory note belongs to is enabled
+        if info
+            .faction
+            .is_some_and(|faction| !expansions.is_enabled(&faction.expansion()))
+        {
+            return false;
+        }
+
+        // check if promissory note is patched in codex 1
+
+        let removed_in_codex_1 = &[
+            Stymie,
+            WarFunding,
+            CyberneticEnhancements,
+            Acquiescence,
+            GreyfireMutagen,
+        ];
+
+        let added_in_codex_1 = &[
+            StymieOmega,
+            WarFundingOmega,
+            CyberneticEnhancementsOmega,
+            AcquiescenceOmega,
+            GreyfireMutagenOmega,
+        ];
+
+        if expansions.codex_1 && removed_in_codex_1.contains(self) {
+            return false;
+        }
+
+        if !expansions.codex_1 && added_in_codex_1.contains(self) {
+            return false;
+        }
+
+        true
+    }
+
+    /// Returns the [PromissoryNoteInfo] for the promissory note.
+    pub fn info(&self) -> PromissoryNoteInfo {
+        match self {
+            PromissoryNote::Ceasefire => pi!(Ceasefire, "Ceasefire", Base, Return),
+            PromissoryNote::PoliticalSecret => {
+                pi!(PoliticalSecret, "Political Secret", Base, Return)
+            }
+            PromissoryNote::SupportForTheThrone => pi!(
+                SupportForTheThrone,
+                "Support for the Throne",
+                Base,
+                PlacedOnReceive
+            ),
+            PromissoryNote::TradeAgreement => pi!(TradeAgreement, "Trade Agreement", Base, Return),
+            PromissoryNote::Alliance => pi!(Alliance, "Alliance", ProphecyOfKings, PlacedOnReceive),
+            PromissoryNote::Stymie => pi!(Stymie, "Stymie", Base, Return, Arborec),
+            PromissoryNote::WarFunding => {
+                pi!(WarFunding, "War Funding", Base, Return, BaronyOfLetnev)
+            }
+            PromissoryNote::RaghsCall => pi!(RaghsCall, "Ragh's Call", Base, Return, ClanOfSaar),
+            PromissoryNote::FiresOfTheGashlai => pi!(
+                FiresOfTheGashlai,
+                "Fires of the Gashlai",
+                Base,
+                Return,
+                EmbersOfMuaat
+            ),
+            PromissoryNote::TradeConvoys => pi!(
+                TradeConvoys,
+                "Trade Convoys",
+                Base,
+                PlayArea,
+                EmiratesOfHacan
+            ),
+            PromissoryNote::MilitarySupport => pi!(
+                MilitarySupport,
+                "Military Support",
+                Base,
+                Return,
+                FederationOfSol
+            ),
+            PromissoryNote::CreussIff => {
+                pi!(CreussIff, "Creuss IFF", Base, Return, GhostsOfCreuss)
+            }
+            PromissoryNote::CyberneticEnhancements => pi!(
+                CyberneticEnhancements,
+                "Cybernetic Enhancements",
+                Base,
+                Return,
+                L1Z1XMindnet
+            ),
+            PromissoryNote::PromiseOfProtection => pi!(
+                PromiseOfProtection,
+                "Promise of Protection",
+                Base,
+                PlayArea,
+                MentakCoalition
+            ),
+            PromissoryNote::GiftOfPrescience => pi!(
+                GiftOfPrescience,
+                "Gift of Prescience",
+                Base,
+                PlayArea,
+                NaaluCollective
+            ),
+            PromissoryNote::Antivirus => pi!(Antivirus, "Antivirus", Base, PlayArea, NekroVirus),
+            PromissoryNote::TekklarLegion => {
+                pi!(TekklarLegion, "Tekklar Legion", Base, Return, SardakkNorr)
+            }
+            PromissoryNote::ResearchAgreement => pi!(
+                ResearchAgreement,
+                "Research Agreement",
+                Base,
+                Return,
+                UniversitiesOfJolNar
+            ),
+            PromissoryNote::Acquiescence => pi!(Acquiescence, "Acquiescence", Base, Return, Winnu),
+            PromissoryNote::PoliticalFavor => pi!(
+                PoliticalFavor,
+                "Political Favor",
+                Base,
+                Return,
+                XxchaKingdom
+            ),
+            PromissoryNote::GreyfireMutagen => pi!(
+                GreyfireMutagen,
+                "Greyfire Mutagen",
+                Base,
+                Return,
+                YinBrotherhood
+            ),
+            PromissoryNote::SpyNet => pi!(SpyNet, "Spy Net", Base, Return, YssarilTribes),
+            PromissoryNote::StrikeWingAmbuscade => pi!(
+                StrikeWingAmbuscade,
+                "Strike Wing Ambuscade",
+                ProphecyOfKings,
+                Return,
+                ArgentFlight
+            ),
+            PromissoryNote::BloodPact => {
+                pi!(BloodPact, "Blood Pact", ProphecyOfKings, PlayArea, Empyrean)
+            }
+            PromissoryNote::DarkPact => {
+                pi!(DarkPact, "Dark Pact", ProphecyOfKings, PlayArea, Empyrean)
+            }
+            PromissoryNote::ScepterOfDominion => pi!(
+                ScepterOfDominion,
+                "Scepter of Dominion",
+                ProphecyOfKings,
+                Return,
+                MahactGeneSorcerers
+            ),
+            PromissoryNote::BlackMarketForgery => pi!(
+                BlackMarketForgery,
+                "Black Market Forgery",
+                ProphecyOfKings,
+                Return,
+                NaazRokhaAlliance
+            ),
+            PromissoryNote::TheCavalry => {
+                pi!(TheCavalry, "The Cavalry", ProphecyOfKings, Return, Nomad)
+            }
+            PromissoryNote::Terraform => {
+                pi!(Terraform, "Terraform", ProphecyOfKings, Attach, TitansOfUl)
+            }
+            PromissoryNote::Crucible => {
+                pi!(
+                    Crucible,
+                    "Crucible",
+                    ProphecyOfKings,
+                    Return,
+                    VuilRaithCabal
+                )
+            }
+            PromissoryNote::StymieOmega => pi!(StymieOmega, "Stymie Ω", Codex, Return, Arborec),
+            PromissoryNote::WarFundingOmega => pi!(
+                WarFundingOmega,
+                "War Funding Ω",
+                Codex,
+                Return,
+                BaronyOfLetnev
+            ),
+            PromissoryNote::CyberneticEnhancementsOmega => pi!(
+                CyberneticEnhancementsOmega,
+                "Cybernetic Enhancements Ω",
+                Codex,
+                Return,
+                L1Z1XMindnet
+            ),
+            PromissoryNote::AcquiescenceOmega => {
+                pi!(AcquiescenceOmega, "Acquiescence Ω", Codex, Return, Winnu)
+            }
+            PromissoryNote::GreyfireMutagenOmega => pi!(
+                GreyfireMutagenOmega,
+                "Greyfire Mutagen Ω",
+                Codex,
+                Return,
+                YinBrotherhood
+            ),
+            PromissoryNote::KeleresRider => pi!(
+                KeleresRider,
+                "Keleres Rider",
+                CodexIII,
+                Return,
+                CouncilKeleres
+            ),
+        }
+    }
+}
@@ -25,6 +25,20 @@ pub enum Relic {
     NanoForge,
 }
 
+/// A relic fragment, gained when exploring planets.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+pub enum RelicFragment {
+    /// Found when exploring cultural planets.
+    Cultural,
+    /// Found when exploring hazardous planets.
+    Hazardous,
+    /// Found when exploring industrial planets.
+    Industrial,
+    /// Found in frontier exploration, can be used as a fragment of any type.
+    Unknown,
+}
+
 /// When this relic can be used.
 #[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter, TS)]
 #[ts(export)]
@@ -37,10 +51,32 @@ pub enum RelicPlay {
     Possession,
     /// Can be played after a tactical action has been performed.
     AfterTactical,
+    /// Can be played during a tactical action.
+    TacticalAction,
+    /// Can be played when researching a technology.
+    Research,
+    /// Can be played when spending a token from the strategy pool.
+    SpendStrategyToken,
+    /// Is relevant during combat.
+    Combat,
     /// We don't handle this atm.
     Unhandled,
 }
 
+/// What happens to the relic when it is used.
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+pub enum RelicUsage {
+    /// The relic is purged when used.
+    Purge,
+    /// The relic is exhausted when used and readied again during the status phase.
+    Exhaust,
+    /// The relic is attached to a planet when used.
+    Attach,
+    /// The relic is never used up, its effect applies while it is in the players play area.
+    Passive,
+}
+
 /// All relevant information for a relic card.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
 #[ts(export)]
@@ -53,21 +89,20 @@ pub struct RelicInfo {
     pub expansion: Expansion,
     /// When the relic can be played / is relevant.
     pub play: RelicPlay,
+    /// What happens to the relic when it is used.
+    pub usage: RelicUsage,
     /// A description of what the relic is about.
     pub description: String,
 }
 
 macro_rules! ri {
-    ($card:ident, $name:literal, $exp:ident) => {
-        ri!($card, $name, $exp, Unhandled)
-    };
-
-    ($card:ident, $name:literal, $exp:ident, $play:ident) => {
+    ($card:ident, $name:literal, $exp:ident, $play:ident, $usage:ident) => {
         RelicInfo {
             card: Relic::$card,
             name: $name.to_string(),
             expansion: Expansion::$exp,
             play: RelicPlay::$play,
+            usage: RelicUsage::$usage,
             description: include_str!(concat!("./description/", stringify!($card))).to_string(),
         }
     };
@@ -77,41 +112,67 @@ impl Relic {
     /// Returns the [RelicInfo] for the frontier card.
     pub fn info(&self) -> RelicInfo {
         match self {
-            Relic::DominusOrb => ri!(DominusOrb, "Dominus Orb", ProphecyOfKings),
-            Relic::MawOfWorlds => ri!(MawOfWorlds, "Maw of Worlds", ProphecyOfKings, Agenda),
-            Relic::ScepterOfEmelpar => ri!(ScepterOfEmelpar, "Scepter of Emelpar", ProphecyOfKings),
+            Relic::DominusOrb => ri!(
+                DominusOrb,
+                "Dominus Orb",
+                ProphecyOfKings,
+                TacticalAction,
+                Purge
+            ),
+            Relic::MawOfWorlds => ri!(MawOfWorlds, "Maw of Worlds", ProphecyOfKings, Agenda, Purge),
+            Relic::ScepterOfEmelpar => ri!(
+                ScepterOfEmelpar,
+                "Scepter of Emelpar",
+                ProphecyOfKings,
+                SpendStrategyToken,
+                Exhaust
+            ),
             Relic::ShardOfTheThrone => ri!(
                 ShardOfTheThrone,
                 "Shard of the Throne",
                 ProphecyOfKings,
-                Possession
+                Possession,
+                Passive
             ),
             Relic::StellarConverter => ri!(
                 StellarConverter,
                 "Stellar Converter",
                 ProphecyOfKings,
-                Action
+                Action,
+                Purge
             ),
-            Relic::TheCodex => ri!(TheCodex, "The Codex", ProphecyOfKings, Action),
+            Relic::TheCodex => ri!(TheCodex, "The Codex", ProphecyOfKings, Action, Purge),
             Relic::TheCrownOfEmphidia => ri!(
                 TheCrownOfEmphidia,
                 "The Crown of Emphidia",
                 ProphecyOfKings,
-                AfterTactical
+                AfterTactical,
+                Exhaust
             ),
             Relic::TheCrownOfThalnos => ri!(
                 TheCrownOfThalnos,
                 "The Crown of Thalnos",
                 ProphecyOfKings,
-                Unhandled
+                Combat,
+                Passive
+            ),
+            Relic::TheObsidian => ri!(
+                TheObsidian,
+                "The Obsidian",
+                ProphecyOfKings,
+                Possession,
+                Passive
+            ),
+            Relic::TheProphetsTears => ri!(
+                TheProphetsTears,
+                "The Prophet's Tears",
+                ProphecyOfKings,
+                Research,
+                Exhaust
             ),
-            Relic::TheObsidian => ri!(TheObsidian, "The Obsidian", ProphecyOfKings),
-            Relic::TheProphetsTears => {
-                ri!(TheProphetsTears, "The Prophet's Tears", ProphecyOfKings)
-            }
-            Relic::DynamisCore => ri!(DynamisCore, "Dynamis Core", CodexII, Action),
-            Relic::JrXs4550 => ri!(JrXs4550, "JR-XS455-0", CodexII, Action),
-            Relic::NanoForge => ri!(NanoForge, "Nano-Forge", CodexII, Action),
+            Relic::DynamisCore => ri!(DynamisCore, "Dynamis Core", CodexII, Action, Purge),
+            Relic::JrXs4550 => ri!(JrXs4550, "JR-XS455-0", CodexII, Action, Exhaust),
+            Relic::NanoForge => ri!(NanoForge, "Nano-Forge", CodexII, Action, Attach),
         }
     }
 }
@@ -778,5 +778,36 @@ pub fn systems() -> HashMap<SystemId, System> {
             vec![WormHoleType::Epsilon],
             Expansion::ThundersEdge
         ),
+        /* The Fracture */
+        s!(
+            "F1",
+            SystemType::Normal,
+            vec![Planet::Cocytus],
+            Expansion::ThundersEdge
+        ),
+        s!(
+            "F2",
+            SystemType::Normal,
+            vec![Planet::Styx],
+            Expansion::ThundersEdge
+        ),
+        s!(
+            "F3",
+            SystemType::Normal,
+            vec![Planet::Lethe],
+            Expansion::ThundersEdge
+        ),
+        s!(
+            "F4",
+            SystemType::Normal,
+            vec![Planet::Phlegethon],
+            Expansion::ThundersEdge
+        ),
+        s!(
+            "F5",
+            SystemType::Normal,
+            vec![Planet::ThundersEdge],
+            Expansion::ThundersEdge
+        ),
     ])
 }
@@ -7,6 +7,8 @@ use crate::{
     enum_map::EnumMap,
 };
 
+use super::unit::{Unit, UnitStats};
+
 /// What category the tech belongs to.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, TS)]
 #[ts(export)]
@@ -36,6 +38,26 @@ pub enum TechOrigin {
     Faction(Faction),
 }
 
+/// How a technology with an exhaust ability is used.
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum TechPlay {
+    /// Exhausted as a component action during the action phase.
+    Action,
+    /// Exhausted at its own timing, e.g. "at the end of your turn".
+    Ability,
+}
+
+/// The Nekro Virus' "Valefar Assimilator" unit upgrades, which gain the text of another faction's
+/// unit upgrade that an assimilator token is placed on.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter, TS)]
+#[ts(export)]
+#[allow(missing_docs)]
+pub enum ValefarAssimilator {
+    X,
+    Y,
+}
+
 /// Technologies in the game.
 #[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter, TS)]
 #[ts(export)]
@@ -156,6 +178,14 @@ pub struct TechInfo {
     pub expansion: Expansion,
     /// The effects of the technology. Each element corresponds to an effect of the technology.
     pub effects: Vec<String>,
+    /// How the technology is exhausted, or `None` if it has no exhaust ability.
+    pub play: Option<TechPlay>,
+    /// The generic unit upgrade that this faction unit upgrade replaces, if any.
+    pub replaces: Option<Technology>,
+    /// The unit that this unit upgrade upgrades, if any.
+    pub unit: Option<Unit>,
+    /// The stats of the unit after researching this unit upgrade, if known.
+    pub unit_stats: Option<UnitStats>,
 }
 
 macro_rules! t {
@@ -167,6 +197,10 @@ macro_rules! t {
             requirements: $reqs,
             expansion: $expansion,
             effects: Vec::new(),
+            play: None,
+            replaces: None,
+            unit: None,
+            unit_stats: None,
         }
     };
     ($name: expr, $t:expr, $orig:expr, $reqs: expr, $expansion: expr, $effects: expr,) => {
@@ -177,6 +211,10 @@ macro_rules! t {
             requirements: $reqs,
             expansion: $expansion,
             effects: $effects.into_iter().map(|s| s.to_string()).collect(),
+            play: None,
+            replaces: None,
+            unit: None,
+            unit_stats: None,
         }
     };
 }
@@ -192,9 +230,10 @@ macro_rules! tr {
 
 impl Technology {
     /// Is this tech enabled for the given [Expansions]?
+    ///
+    /// Technologies revised in Codex I are replaced by their omega version when it is enabled, so
+    /// exactly one version of each revised technology is enabled.
     pub fn is_enabled_in(&self, expansions: &Expansions) -> bool {
-        use Technology::*;
-
         // check if the expansion that adds this tech is enabled.
         let faction_source = self.info().expansion;
         if !expansions.is_enabled(&faction_source) {
@@ -202,40 +241,116 @@ impl Technology {
         }
 
         // check if tech is patched in codex 1
+        let is_removed_in_codex_1 = self.codex_1_revision().is_some();
+        !(expansions.codex_1 && is_removed_in_codex_1)
+    }
 
-        let removed_in_codex_1 = &[
-            MagenDefenceGrid,
-            X89BacterialWeapon,
-            MagmusReactor,
-            WormholeGenerator,
-            YinSpinner,
-        ];
+    /// The omega version of this technology that replaces it in Codex I, if it was revised.
+    pub fn codex_1_revision(&self) -> Option<Technology> {
+        use Technology::*;
 
-        let added_in_codex_1 = &[
-            MagenDefenceGridOmega,
-            X89BacterialWeaponOmega,
-            MagmusReactorOmega,
-            WormholeGeneratorOmega,
-            YinSpinnerOmega,
-        ];
+        match self {
+            MagenDefenceGrid => Some(MagenDefenceGridOmega),
+            X89BacterialWeapon => Some(X89BacterialWeaponOmega),
+            MagmusReactor => Some(MagmusReactorOmega),
+            WormholeGenerator => Some(WormholeGeneratorOmega),
+            YinSpinner => Some(YinSpinnerOmega),
+            _ => None,
+        }
+    }
 
-        let is_removed_in_codex_1 = removed_in_codex_1.contains(self);
-        let is_added_in_codex_1 = added_in_codex_1.contains(self);
+    /// The version of this technology that is in use with the given [Expansions], i.e. the omega
+    /// version of revised technologies if Codex I is enabled.
+    pub fn version_in(&self, expansions: &Expansions) -> Technology {
+        match self.codex_1_revision() {
+            Some(revision) if expansions.codex_1 => revision,
+            _ => self.clone(),
+        }
+    }
 
-        if expansions.codex_1 && is_removed_in_codex_1 {
-            return false;
+    /// How the technology is exhausted, or `None` if it has no exhaust ability.
+    pub fn play(&self) -> Option<TechPlay> {
+        use Technology::*;
+
+        match self {
+            X89BacterialWeapon
+            | SlingRelay
+            | ProductionBiomes
+            | MageonImplants
+            | WormholeGeneratorOmega
+            | LazaxGateFolding
+            | Vortex => Some(TechPlay::Action),
+            BioStims
+            | GravitonLaserSystem
+            | PredictiveIntelligence
+            | TransitDiodes
+            | AiDevelopmentAlgorithm
+            | MagenDefenceGrid
+            | SelfAssemblyRoutines
+            | InstinctTraining
+            | GeneticRecombination
+            | SpacialConduitCylinder
+            | TemporalCommandSuite
+            | InheritanceSystems
+            | HegemonicTradePolicy
+            | NullificationField
+            | Supercharge => Some(TechPlay::Ability),
+            _ => None,
         }
+    }
 
-        if !expansions.codex_1 && is_added_in_codex_1 {
-            return false;
+    /// The generic unit upgrade that this faction unit upgrade replaces, a player can't own both.
+    pub fn replaces(&self) -> Option<Technology> {
+        use Technology::*;
+
+        match self {
+            SpecOpsII | LetaniWarriorII | CrimsonLegionnaireII => Some(InfantryII),
+            SuperDreadnoughtII | ExotriremeII => Some(DreadnoughtII),
+            AdvancedCarrierII => Some(CarrierII),
+            SaturnEngineII => Some(CruiserII),
+            FloatingFactoryII | DimensionalTearII => Some(SpaceDockII),
+            PrototypeWarSunII => Some(WarSun),
+            StrikeWingAlphaII => Some(DestroyerII),
+            HybridCrystalFighterII => Some(FighterII),
+            HelTitanII => Some(PdsII),
+            _ => None,
         }
+    }
 
-        true
+    /// The unit that this unit upgrade upgrades (or, for the war sun, unlocks).
+    pub fn unit(&self) -> Option<Unit> {
+        use Technology::*;
+
+        match self {
+            InfantryII => Some(Unit::Infantry),
+            DreadnoughtII => Some(Unit::Dreadnought),
+            CarrierII => Some(Unit::Carrier),
+            CruiserII => Some(Unit::Cruiser),
+            SpaceDockII => Some(Unit::SpaceDock),
+            WarSun => Some(Unit::WarSun),
+            DestroyerII => Some(Unit::Destroyer),
+            FighterII => Some(Unit::Fighter),
+            PdsII => Some(Unit::Pds),
+            MemoriaII => Some(Unit::Flagship),
+            _ => self.replaces().and_then(|tech| tech.unit()),
+        }
+    }
+
+    /// The stats of the unit after researching this unit upgrade.
+    ///
+    /// Faction unit upgrades have their own stats, which are not tracked (yet).
+    pub fn unit_stats(&self) -> Option<UnitStats> {
+        let unit = self.unit()?;
+        match self {
+            Technology::WarSun => unit.stats(),
+            _ if unit.upgrade().as_ref() == Some(self) => unit.upgraded_stats(),
+            _ => None,
+        }
     }
 
     /// Returns the [TechInfo] for this technology.
     pub fn info(&self) -> TechInfo {
-        match self {
+        let info = match self {
             Technology::NeuralMotivator => t!(
                 "Neural Motivator",
                 TechType::Category(TechCategory::Biotic),
@@ -426,7 +541,7 @@ impl Technology {
                 TechType::Category(TechCategory::Warfare),
                 TechOrigin::Base,
                 tr!([(TechCategory::Warfare, 1)]),
-                Expansion::Base,
+                Expansion::Codex,
                 &["At the start of ground combat on a planet that contains 1 or more of your structures, you may produce 1 hit and assign it to 1 of your opponent's ground forces."],
             ),
             Technology::SelfAssemblyRoutines => t!(
@@ -921,7 +1036,7 @@ impl Technology {
                 &["When 1 of your units uses Sustain Damage, cancel 2 hits instead of 1."],
             ),
             Technology::MagmusReactor => t!(
-                "Magmus Reactor/ Magmus Reactor Ω",
+                "Magmus Reactor",
                 TechType::Category(TechCategory::Warfare),
                 TechOrigin::Faction(Faction::EmbersOfMuaat),
                 tr!([(TechCategory::Warfare, 2)]),
@@ -936,7 +1051,7 @@ impl Technology {
                 TechType::Category(TechCategory::Warfare),
                 TechOrigin::Faction(Faction::EmbersOfMuaat),
                 tr!([(TechCategory::Warfare, 2)]),
-                Expansion::Base,
+                Expansion::Codex,
                 &[
                     "Your ships can move into supernovas.",
                     "Each supernova that contains 1 or more of your units gains the PRODUCTION 5 ability as if it were 1 of your units.",
@@ -950,6 +1065,42 @@ impl Technology {
                 Expansion::Base,
                 &["After making combat rolls during a round of ground combat, if your opponent produced 1 or more hits, you produce 1 additional hit."],
             ),
+        };
+
+        TechInfo {
+            play: self.play(),
+            replaces: self.replaces(),
+            unit: self.unit(),
+            unit_stats: self.unit_stats(),
+            ..info
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use strum::IntoEnumIterator;
+
+    use crate::common::game_settings::Expansions;
+
+    use super::Technology;
+
+    #[test]
+    fn exactly_one_version_of_each_revised_technology_is_enabled() {
+        for codex_1 in [false, true] {
+            let expansions = Expansions {
+                prophecy_of_kings: true,
+                codex_1,
+                ..Default::default()
+            };
+            for tech in Technology::iter() {
+                let Some(omega) = tech.codex_1_revision() else {
+                    continue;
+                };
+                assert_eq!(tech.is_enabled_in(&expansions), !codex_1, "{tech:?}");
+                assert_eq!(omega.is_enabled_in(&expansions), codex_1, "{omega:?}");
+                assert!(tech.version_in(&expansions).is_enabled_in(&expansions));
+            }
         }
     }
 }
@@ -0,0 +1,378 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use ts_rs::TS;
+
+use crate::common::{expansions::Expansion, game_settings::Expansions};
+
+use super::tech::Technology;
+
+/// A unit type that players place on the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter, TS)]
+#[ts(export)]
+#[allow(missing_docs)]
+pub enum Unit {
+    // Ships
+    Flagship,
+    WarSun,
+    Dreadnought,
+    Carrier,
+    Cruiser,
+    Destroyer,
+    Fighter,
+    // Ground forces
+    Mech,
+    Infantry,
+    // Structures
+    Pds,
+    SpaceDock,
+}
+
+/// What kind of unit a [Unit] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[allow(missing_docs)]
+pub enum UnitKind {
+    Ship,
+    GroundForce,
+    Structure,
+}
+
+/// A dice roll, e.g. "9 (x2)" is a roll of two dice that hit on 9 or higher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct Roll {
+    /// The value each die has to roll for a hit.
+    pub hit: u32,
+    /// The number of dice rolled.
+    pub dice: u32,
+}
+
+impl Roll {
+    /// A roll of a single die.
+    pub fn single(hit: u32) -> Self {
+        Self { hit, dice: 1 }
+    }
+
+    /// A roll of several dice.
+    pub fn multiple(hit: u32, dice: u32) -> Self {
+        Self { hit, dice }
+    }
+}
+
+/// A unit ability printed on a unit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum UnitAbility {
+    /// Rolled against fighters before the first round of space combat.
+    AntiFighterBarrage(Roll),
+    /// Rolled against ground forces on a planet before it is invaded.
+    Bombardment(Roll),
+    /// Rolled against ships that move into the system.
+    SpaceCannon {
+        /// The roll of the ability.
+        roll: Roll,
+        /// If the ability can also be used against ships in adjacent systems.
+        adjacent: bool,
+    },
+    /// The planet can't be targeted by bombardment.
+    PlanetaryShield,
+}
+
+/// How many units a unit with production can produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum Production {
+    /// A fixed number of units.
+    Fixed(u32),
+    /// The resource value of the planet the unit is on, plus the given value.
+    PlanetResourcesPlus(u32),
+}
+
+/// The printed statistics of a unit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UnitStats {
+    /// The resource cost of producing the unit, `None` for units that can't be produced.
+    pub cost: Option<u32>,
+    /// How many units are produced when paying the cost.
+    pub units_per_cost: u32,
+    /// The combat roll of the unit.
+    pub combat: Option<Roll>,
+    /// The movement value of the unit.
+    pub movement: Option<u32>,
+    /// The capacity of the unit.
+    pub capacity: Option<u32>,
+    /// The production ability of the unit.
+    pub production: Option<Production>,
+    /// If the unit has sustain damage.
+    pub sustain_damage: bool,
+    /// The other unit abilities of the unit.
+    pub abilities: Vec<UnitAbility>,
+}
+
+impl UnitStats {
+    /// Stats of a ship.
+    fn ship(cost: u32, combat: Roll, movement: u32, capacity: Option<u32>) -> Self {
+        Self {
+            cost: Some(cost),
+            units_per_cost: 1,
+            combat: Some(combat),
+            movement: Some(movement),
+            capacity,
+            production: None,
+            sustain_damage: false,
+            abilities: Vec::new(),
+        }
+    }
+
+    /// Stats of a flagship, all flagships cost 8 and have sustain damage.
+    pub fn flagship(combat: Roll, movement: u32, capacity: u32) -> Self {
+        Self::ship(8, combat, movement, Some(capacity)).with_sustain_damage()
+    }
+
+    /// Stats of a mech, all mechs cost 2, hit on 6 and have sustain damage.
+    pub fn mech() -> Self {
+        Self::ground_force(2, 1, 6).with_sustain_damage()
+    }
+
+    /// Stats of a ground force.
+    fn ground_force(cost: u32, units_per_cost: u32, combat: u32) -> Self {
+        Self {
+            cost: Some(cost),
+            units_per_cost,
+            combat: Some(Roll::single(combat)),
+            movement: None,
+            capacity: None,
+            production: None,
+            sustain_damage: false,
+            abilities: Vec::new(),
+        }
+    }
+
+    /// Stats of a structure.
+    fn structure() -> Self {
+        Self {
+            cost: None,
+            units_per_cost: 1,
+            combat: None,
+            movement: None,
+            capacity: None,
+            production: None,
+            sustain_damage: false,
+            abilities: Vec::new(),
+        }
+    }
+
+    /// Adds sustain damage to the unit.
+    fn with_sustain_damage(self) -> Self {
+        Self {
+            sustain_damage: true,
+            ..self
+        }
+    }
+
+    /// Adds a unit ability to the unit.
+    pub fn with_ability(mut self, ability: UnitAbility) -> Self {
+        self.abilities.push(ability);
+        self
+    }
+
+    /// Sets the capacity of the unit.
+    fn with_capacity(self, capacity: u32) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..self
+        }
+    }
+
+    /// Sets the production of the unit.
+    pub fn with_production(self, production: Production) -> Self {
+        Self {
+            production: Some(production),
+            ..self
+        }
+    }
+}
+
+/// All relevant information about a unit.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UnitInfo {
+    /// The name of the unit in 'pretty' format.
+    pub name: String,
+    /// What kind of unit this is.
+    pub kind: UnitKind,
+    /// Which expansion the unit belongs to.
+    pub expansion: Expansion,
+    /// The stats of the unit, `None` if every faction has their own version of the unit.
+    pub stats: Option<UnitStats>,
+    /// The technology that upgrades the unit, if any.
+    pub upgrade: Option<Technology>,
+    /// The stats of the unit after researching its upgrade.
+    pub upgraded_stats: Option<UnitStats>,
+}
+
+/// All relevant information about a faction specific unit, i.e. a flagship or a mech.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct FactionUnitInfo {
+    /// The name of the unit in 'pretty' format.
+    pub name: String,
+    /// Which unit this is.
+    pub unit: Unit,
+    /// The stats of the unit.
+    pub stats: UnitStats,
+}
+
+impl Unit {
+    /// Returns what kind of unit this is.
+    pub fn kind(&self) -> UnitKind {
+        match self {
+            Unit::Flagship
+            | Unit::WarSun
+            | Unit::Dreadnought
+            | Unit::Carrier
+            | Unit::Cruiser
+            | Unit::Destroyer
+            | Unit::Fighter => UnitKind::Ship,
+            Unit::Mech | Unit::Infantry => UnitKind::GroundForce,
+            Unit::Pds | Unit::SpaceDock => UnitKind::Structure,
+        }
+    }
+
+    /// Is this unit enabled for the given [Expansions]?
+    pub fn is_enabled_in(&self, expansions: &Expansions) -> bool {
+        expansions.is_enabled(&self.info().expansion)
+    }
+
+    /// The printed stats of the unit, `None` for faction specific units.
+    pub fn stats(&self) -> Option<UnitStats> {
+        Some(match self {
+            Unit::Flagship | Unit::Mech => return None,
+            Unit::WarSun => UnitStats::ship(12, Roll::multiple(3, 3), 2, Some(6))
+                .with_sustain_damage()
+                .with_ability(UnitAbility::Bombardment(Roll::multiple(3, 3))),
+            Unit::Dreadnought => UnitStats::ship(4, Roll::single(5), 1, Some(1))
+                .with_sustain_damage()
+                .with_ability(UnitAbility::Bombardment(Roll::single(5))),
+            Unit::Carrier => UnitStats::ship(3, Roll::single(9), 1, Some(4)),
+            Unit::Cruiser => UnitStats::ship(2, Roll::single(7), 2, None),
+            Unit::Destroyer => UnitStats::ship(1, Roll::single(9), 2, None)
+                .with_ability(UnitAbility::AntiFighterBarrage(Roll::multiple(9, 2))),
+            Unit::Fighter => UnitStats {
+                units_per_cost: 2,
+                movement: None,
+                ..UnitStats::ship(1, Roll::single(9), 0, None)
+            },
+            Unit::Infantry => UnitStats::ground_force(1, 2, 8),
+            Unit::Pds => UnitStats::structure()
+                .with_ability(UnitAbility::PlanetaryShield)
+                .with_ability(UnitAbility::SpaceCannon {
+                    roll: Roll::single(6),
+                    adjacent: false,
+                }),
+            Unit::SpaceDock => UnitStats::structure()
+                .with_capacity(3)
+                .with_production(Production::PlanetResourcesPlus(2)),
+        })
+    }
+
+    /// The printed stats of the unit after researching its upgrade.
+    pub fn upgraded_stats(&self) -> Option<UnitStats> {
+        Some(match self {
+            Unit::Flagship | Unit::Mech | Unit::WarSun => return None,
+            Unit::Dreadnought => UnitStats::ship(4, Roll::single(5), 2, Some(1))
+                .with_sustain_damage()
+                .with_ability(UnitAbility::Bombardment(Roll::single(5))),
+            Unit::Carrier => UnitStats::ship(3, Roll::single(9), 2, Some(6)),
+            Unit::Cruiser => UnitStats::ship(2, Roll::single(6), 3, Some(1)),
+            Unit::Destroyer => UnitStats::ship(1, Roll::single(8), 2, None)
+                .with_ability(UnitAbility::AntiFighterBarrage(Roll::multiple(6, 3))),
+            Unit::Fighter => UnitStats {
+                units_per_cost: 2,
+                ..UnitStats::ship(1, Roll::single(8), 2, None)
+            },
+            Unit::Infantry => UnitStats::ground_force(1, 2, 7),
+            Unit::Pds => UnitStats::structure()
+                .with_ability(UnitAbility::PlanetaryShield)
+                .with_ability(UnitAbility::SpaceCannon {
+                    roll: Roll::single(5),
+                    adjacent: true,
+                }),
+            Unit::SpaceDock => UnitStats::structure()
+                .with_capacity(5)
+                .with_production(Production::PlanetResourcesPlus(4)),
+        })
+    }
+
+    /// The generic technology that upgrades the unit, if any.
+    pub fn upgrade(&self) -> Option<Technology> {
+        Some(match self {
+            Unit::Flagship | Unit::Mech | Unit::WarSun => return None,
+            Unit::Dreadnought => Technology::DreadnoughtII,
+            Unit::Carrier => Technology::CarrierII,
+            Unit::Cruiser => Technology::CruiserII,
+            Unit::Destroyer => Technology::DestroyerII,
+            Unit::Fighter => Technology::FighterII,
+            Unit::Infantry => Technology::InfantryII,
+            Unit::Pds => Technology::PdsII,
+            Unit::SpaceDock => Technology::SpaceDockII,
+        })
+    }
+
+    /// Returns the [UnitInfo] for this unit.
+    pub fn info(&self) -> UnitInfo {
+        let (name, expansion) = match self {
+            Unit::Flagship => ("Flagship", Expansion::Base),
+            Unit::WarSun => ("War Sun", Expansion::Base),
+            Unit::Dreadnought => ("Dreadnought", Expansion::Base),
+            Unit::Carrier => ("Carrier", Expansion::Base),
+            Unit::Cruiser => ("Cruiser", Expansion::Base),
+            Unit::Destroyer => ("Destroyer", Expansion::Base),
+            Unit::Fighter => ("Fighter", Expansion::Base),
+            Unit::Mech => ("Mech", Expansion::ProphecyOfKings),
+            Unit::Infantry => ("Infantry", Expansion::Base),
+            Unit::Pds => ("PDS", Expansion::Base),
+            Unit::SpaceDock => ("Space Dock", Expansion::Base),
+        };
+
+        UnitInfo {
+            name: name.to_string(),
+            kind: self.kind(),
+            expansion,
+            stats: self.stats(),
+            upgrade: self.upgrade(),
+            upgraded_stats: self.upgraded_stats(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Roll, Unit};
+
+    #[test]
+    fn upgrades_improve_the_printed_stats() {
+        let carrier = Unit::Carrier.stats().unwrap();
+        let carrier_ii = Unit::Carrier.upgraded_stats().unwrap();
+        assert_eq!((carrier.movement, carrier.capacity), (Some(1), Some(4)));
+        assert_eq!(
+            (carrier_ii.movement, carrier_ii.capacity),
+            (Some(2), Some(6))
+        );
+
+        let infantry_ii = Unit::Infantry.upgraded_stats().unwrap();
+        assert_eq!(infantry_ii.combat, Some(Roll::single(7)));
+        assert_eq!(infantry_ii.units_per_cost, 2);
+    }
+
+    #[test]
+    fn faction_units_have_no_generic_stats() {
+        assert_eq!(Unit::Flagship.stats(), None);
+        assert_eq!(Unit::Mech.upgraded_stats(), None);
+        assert_eq!(Unit::WarSun.upgrade(), None);
+    }
+}
@@ -3,18 +3,27 @@ use strum::IntoEnumIterator;
 use ts_rs::TS;
 
 use ti_helper_game_data::{
-    common::{color::Color, faction::Faction, game_settings::Expansions},
+    common::{
+        color::Color,
+        faction::{Faction, FactionSetup},
+        game_settings::Expansions,
+    },
     components::{
         action_card::{ActionCard, ActionCardInfo},
         agenda::{Agenda, AgendaInfo},
+        breakthrough::Breakthrough,
+        exploration::{ExplorationCard, ExplorationCardInfo},
         frontier_card::{FrontierCard, FrontierCardInfo},
+        galactic_event::{GalacticEvent, GalacticEventInfo},
         leaders::{Leader, LeaderInfo},
         objectives::{public::PublicObjective, secret::SecretObjective, Objective, ObjectiveInfo},
         planet::{Planet, PlanetInfo},
         planet_attachment::{PlanetAttachment, PlanetAttachmentInfo},
+        promissory_note::{PromissoryNote, PromissoryNoteInfo},
         relic::{Relic, RelicInfo},
         system::{systems, System, SystemId},
         tech::{TechInfo, Technology},
+        unit::{FactionUnitInfo, Unit, UnitInfo},
     },
     enum_map::EnumMap,
 };
@@ -54,8 +63,18 @@ pub struct GameOptions {
     leaders_by_faction: EnumMap<Faction, Vec<Leader>>,
     /// What frontier cards exists in the game.
     frontier_cards: EnumMap<FrontierCard, FrontierCardInfo>,
+    /// What exploration cards exists in the game.
+    exploration_cards: EnumMap<ExplorationCard, ExplorationCardInfo>,
     /// What relics exists in the game.
     relics: EnumMap<Relic, RelicInfo>,
+    /// What promissory notes exists in the game.
+    promissory_notes: EnumMap<PromissoryNote, PromissoryNoteInfo>,
+    /// What faction breakthroughs exists in the game.
+    breakthroughs: Vec<Breakthrough>,
+    /// What galactic events exists in the game.
+    galactic_events: EnumMap<GalacticEvent, GalacticEventInfo>,
+    /// What units exists in the game.
+    units: EnumMap<Unit, UnitInfo>,
 }
 
 impl GameOptions {
@@ -74,6 +93,12 @@ impl GameOptions {
                 .map(|faction| FactionResponse {
                     faction,
                     name: faction.name(),
+                    setup: faction.setup(),
+                    flagship: faction.flagship(),
+                    mech: faction
+                        .mech()
+                        .filter(|_| Unit::Mech.is_enabled_in(expansions)),
+                    commodities: faction.commodities(),
                 })
                 .collect::<Vec<FactionResponse>>(),
             colors: Color::iter().collect(),
@@ -123,10 +148,29 @@ impl GameOptions {
                 .map(|f| (f.clone(), f.info()))
                 .filter(|(_, card)| expansions.is_enabled(&card.expansion))
                 .collect(),
+            exploration_cards: ExplorationCard::iter()
+                .map(|card| (card, card.info()))
+                .filter(|(_, card)| expansions.is_enabled(&card.expansion))
+                .collect(),
             relics: Relic::iter()
                 .map(|relic| (relic.clone(), relic.info()))
                 .filter(|(_, relic)| expansions.is_enabled(&relic.expansion))
                 .collect(),
+            promissory_notes: PromissoryNote::iter()
+                .filter(|note| note.is_enabled_in(expansions))
+                .map(|note| (note, note.info()))
+                .collect(),
+            breakthroughs: Breakthrough::iter()
+                .filter(|breakthrough| breakthrough.is_enabled_in(expansions))
+                .collect(),
+            galactic_events: GalacticEvent::iter()
+                .map(|event| (event, event.info()))
+                .filter(|(_, event)| expansions.is_enabled(&event.expansion))
+                .collect(),
+            units: Unit::iter()
+                .filter(|unit| unit.is_enabled_in(expansions))
+                .map(|unit| (unit, unit.info()))
+                .collect(),
         }
     }
 }
@@ -140,4 +184,12 @@ pub struct FactionResponse {
     faction: Faction,
     /// The name of the faction in 'pretty' format.
     name: String,
+    /// The faction specific choices made during setup.
+    setup: FactionSetup,
+    /// The flagship of the faction, if known.
+    flagship: Option<FactionUnitInfo>,
+    /// The mech of the faction, if known.
+    mech: Option<FactionUnitInfo>,
+    /// The commodity value of the faction, if known.
+    commodities: Option<u32>,
 }
@@ -17,6 +17,7 @@ use ti_helper_game_data::{
         planet::{Planet, PlanetTrait},
         planet_attachment::PlanetAttachment,
         strategy_card::StrategyCard,
+        tech::TechCategory,
     },
 };
 
@@ -95,6 +96,9 @@ pub struct VoteState {
     /// Player-cast votes.
     pub player_votes: HashMap<PlayerId, Option<Vote>>,
 
+    /// Players that are not allowed to vote on this agenda, e.g. due to "Political Secret".
+    pub blocked_voters: HashSet<PlayerId>,
+
     /// Votes tallied on a per-outcome basis.
     ///
     /// Calculated by calling [VoteState::tally_votes].
@@ -190,6 +194,7 @@ impl VoteState {
             elect: info.elect,
             candidates,
             player_votes: Default::default(),
+            blocked_voters: Default::default(),
             outcomes_by_votes: Default::default(),
             expected_outcome: None,
         })
@@ -250,7 +255,11 @@ impl Vote {
     }
 }
 
-fn get_planet_traits(planet: &Planet, attachments: &HashSet<PlanetAttachment>) -> Vec<PlanetTrait> {
+/// The planet traits of the planet, including traits added by its attachments.
+pub(crate) fn get_planet_traits(
+    planet: &Planet,
+    attachments: &HashSet<PlanetAttachment>,
+) -> Vec<PlanetTrait> {
     let mut ts = vec![];
 
     for t in planet.info().planet_traits.into_iter() {
@@ -264,3 +273,21 @@ fn get_planet_traits(planet: &Planet, attachments: &HashSet<PlanetAttachment>) -
 
     ts
 }
+
+/// The technology specialties of the planet, including the specialty added by its attachments.
+///
+/// An attachment only adds a specialty to a planet that doesn't already have one.
+pub(crate) fn get_planet_tech_specialties(
+    planet: &Planet,
+    attachments: &HashSet<PlanetAttachment>,
+) -> Vec<TechCategory> {
+    let specialties = planet.info().tech_specialities;
+    if !specialties.is_empty() {
+        return specialties;
+    }
+
+    attachments
+        .iter()
+        .filter_map(|attachment| attachment.info().tech_specialty)
+        .collect()
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Create a random number generator seeded with the provided timestamp.
+///
+/// Events are always re-applied with the timestamp they were originally applied with (e.g. on
+/// undo), which means that anything drawn using this generator will be the same every time the
+/// game state is rebuilt. [ChaCha8Rng] is used as its output is stable across versions.
+pub fn seeded_rng(timestamp: DateTime<Utc>) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(timestamp.timestamp_micros() as u64)
+}
+
+/// Remove and return a random card from `cards`, returns [None] if there are no cards left.
+pub fn draw_random<T>(cards: &mut Vec<T>, rng: &mut impl Rng) -> Option<T> {
+    if cards.is_empty() {
+        return None;
+    }
+
+    let index = rng.gen_range(0..cards.len());
+    Some(cards.remove(index))
+}
+
+/// A deck of cards managed by the server, with a draw pile and a discard pile.
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct Deck<T> {
+    /// The cards left to draw.
+    pub draw_pile: Vec<T>,
+    /// The cards that have been discarded.
+    pub discard_pile: Vec<T>,
+}
+
+impl<T> Deck<T> {
+    /// Create a new deck with all `cards` in the draw pile.
+    pub fn new(cards: Vec<T>) -> Self {
+        Self {
+            draw_pile: cards,
+            discard_pile: Vec::new(),
+        }
+    }
+
+    /// Draw a random card from the deck, if the draw pile is empty the discard pile is shuffled
+    /// into it first. Returns [None] if there are no cards left in either pile.
+    pub fn draw(&mut self, rng: &mut impl Rng) -> Option<T> {
+        if self.draw_pile.is_empty() {
+            self.draw_pile.append(&mut self.discard_pile);
+        }
+
+        draw_random(&mut self.draw_pile, rng)
+    }
+
+    /// Put the card in the discard pile.
+    pub fn discard(&mut self, card: T) {
+        self.discard_pile.push(card);
+    }
+}
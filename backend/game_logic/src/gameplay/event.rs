@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use super::{game_state::StrategicSecondaryProgress, player::NewPlayer};
+use super::{
+    game_state::{IonStormSide, StrategicSecondaryProgress, UnitLocation},
+    player::NewPlayer,
+};
 
 use ti_helper_game_data::{
     common::{
@@ -9,16 +12,21 @@ use ti_helper_game_data::{
     components::{
         action_card::ActionCard,
         agenda::{Agenda, AgendaElect},
+        breakthrough::Breakthrough,
         frontier_card::FrontierCard,
-        leaders::Leader,
+        galactic_event::GalacticEvent,
+        leaders::{Commander, Hero, Leader},
         objectives::{public::PublicObjective, secret::SecretObjective, Objective},
-        planet::Planet,
+        planet::{Planet, PlanetTrait},
         planet_attachment::PlanetAttachment,
-        relic::Relic,
+        promissory_note::PromissoryNote,
+        relic::{Relic, RelicFragment},
         strategy_card::StrategyCard,
         system::SystemId,
-        tech::Technology,
+        tech::{Technology, ValefarAssimilator},
+        unit::Unit,
     },
+    enum_map::EnumMap,
 };
 
 /// An event in the game.
@@ -36,6 +44,31 @@ pub enum Event {
         max_points: u32,
         /// The data imported from milty.
         milty_data: Box<MiltyData>,
+        /// If technology prerequisites should be validated, not set for games imported before
+        /// prerequisites were validated.
+        #[serde(default)]
+        validate_prerequisites: bool,
+        /// If secret objective scoring windows should be validated, not set for games imported
+        /// before scoring windows were validated.
+        #[serde(default)]
+        validate_scoring_windows: bool,
+        /// If units on the board should be tracked, not set for games imported before units were
+        /// tracked.
+        #[serde(default)]
+        track_units: bool,
+        /// If the action phase turn order should be validated, not set for games imported before
+        /// the turn order was validated.
+        #[serde(default)]
+        validate_turn_order: bool,
+        /// If action card payloads should be validated, not set for games imported before action
+        /// card payloads were validated.
+        #[serde(default)]
+        validate_action_card_payloads: bool,
+    },
+    /// Select which galactic events are in play this game.
+    SetGalacticEvents {
+        /// The galactic events that are in play.
+        events: Vec<GalacticEvent>,
     },
     /// Add a new player to the game.
     AddPlayer {
@@ -104,14 +137,35 @@ pub enum Event {
     TacticalActionBegin {
         /// What player is taking the action.
         player: PlayerId,
+        /// The system that is activated, required if units are tracked.
+        #[serde(default)]
+        system: Option<SystemId>,
     },
 
     /// Take a planet during a tactical action.
+    #[serde(rename_all = "camelCase")]
     TacticalActionTakePlanet {
         /// Which player takes the planet.
         player: PlayerId,
         /// Which planet is taken.
         planet: Planet,
+        /// Weather the planet is explored once it has been taken, see [Event::ExplorePlanet].
+        #[serde(default)]
+        explore: bool,
+        /// Which deck to draw from when exploring, only required if the planet has several traits.
+        #[serde(default)]
+        planet_trait: Option<PlanetTrait>,
+    },
+
+    /// Spend 6 influence to remove the custodians token from Mecatol Rex during a tactical
+    /// action, gaining the custodians point.
+    TacticalActionRemoveCustodians {
+        /// The player that removes the custodians token.
+        player: PlayerId,
+        /// The planets that are exhausted to pay the influence.
+        planets: Vec<Planet>,
+        /// The trade goods that are spent to pay the influence.
+        trade_goods: u32,
     },
 
     /// Attach an attachment to a newly taken planet.
@@ -124,12 +178,112 @@ pub enum Event {
         attachment: PlanetAttachment,
     },
 
+    /// Explore a planet that was taken during this tactical action, drawing a card from the
+    /// exploration deck matching the planet's trait.
+    #[serde(rename_all = "camelCase")]
+    ExplorePlanet {
+        /// The player that explores the planet.
+        player: PlayerId,
+        /// The planet being explored.
+        planet: Planet,
+        /// Which deck to draw from, only required if the planet has several traits.
+        #[serde(default)]
+        planet_trait: Option<PlanetTrait>,
+    },
+
+    /// Explore a frontier token in the activated system, drawing a card from the frontier deck.
+    #[serde(rename_all = "camelCase")]
+    ExploreFrontier {
+        /// The player that explores the frontier token.
+        player: PlayerId,
+        /// The system that contains the frontier token.
+        system: SystemId,
+        /// Which side up the ion storm token is placed if the Ion Storm is drawn, alpha if not
+        /// provided.
+        #[serde(default)]
+        ion_storm_side: Option<IonStormSide>,
+    },
+
+    /// Flip the ion storm token, e.g. after a ship has moved through it.
+    FlipIonStorm {
+        /// The player that flips the token.
+        player: PlayerId,
+    },
+
+    /// Move units into the space area of the activated system during a tactical action.
+    MoveUnits {
+        /// The player that moves the units.
+        player: PlayerId,
+        /// Where the units are moved from.
+        from: UnitLocation,
+        /// The units that are moved.
+        units: EnumMap<Unit, u32>,
+    },
+
+    /// Land ground forces from the space area of the activated system on one of its planets.
+    LandUnits {
+        /// The player that lands the units.
+        player: PlayerId,
+        /// The planet the units land on.
+        planet: Planet,
+        /// The units that land.
+        units: EnumMap<Unit, u32>,
+    },
+
+    /// Produce units, either during a tactical action or with the "Warfare" strategy card, or
+    /// place structures with the "Construction" strategy card.
+    ProduceUnits {
+        /// The player that produces the units.
+        player: PlayerId,
+        /// Where the units are placed.
+        location: UnitLocation,
+        /// The units that are produced.
+        units: EnumMap<Unit, u32>,
+    },
+
+    /// Place units on the board without producing them, either the starting units of a faction
+    /// whose starting units aren't known, or a unit placed by an exploration card such as
+    /// "Mercenary Outfit".
+    PlaceUnits {
+        /// The player that gets the units.
+        player: PlayerId,
+        /// Where the units are placed.
+        location: UnitLocation,
+        /// The units that are placed.
+        units: EnumMap<Unit, u32>,
+    },
+
+    /// Remove units from the activated system, e.g. when they are destroyed.
+    RemoveUnits {
+        /// The player that owns the units.
+        player: PlayerId,
+        /// Where the units are removed from.
+        location: UnitLocation,
+        /// The units that are removed.
+        units: EnumMap<Unit, u32>,
+    },
+
     /// End a tactical action.
     TacticalActionCommit {
         /// The player that has taken the action.
         player: PlayerId,
     },
 
+    /// Record the outcome of a combat between two players.
+    ///
+    /// Only the loser's units are destroyed, the units the winner lost during the combat are
+    /// removed with [Event::RemoveUnits].
+    CombatResult {
+        /// The player that won the combat.
+        winner: PlayerId,
+        /// The player that lost the combat.
+        loser: PlayerId,
+        /// Where the combat took place, the units of the loser there are destroyed. Required
+        /// when units are tracked.
+        #[serde(default)]
+        location: Option<UnitLocation>,
+    },
+
     /// Begin playing a strategy card.
     StrategicActionBegin {
         /// The player that plays the card.
@@ -175,6 +329,18 @@ pub enum Event {
         data: Option<ActionCardAction>,
     },
 
+    /// Play an action card in response to something happening in the game, e.g. "Sabotage" when
+    /// another player plays an action card.
+    PlayActionCard {
+        /// Which player is playing the card.
+        player: PlayerId,
+        /// Which card is being played.
+        card: ActionCard,
+        /// The effect of the card, for the response cards that change tracked state.
+        #[serde(default)]
+        data: Option<ActionCardAction>,
+    },
+
     /// Bergin playing a leader.
     LeaderActionBegin {
         /// The player that is taking the action.
@@ -191,6 +357,76 @@ pub enum Event {
 
         /// The leader that is being played.
         leader: Leader,
+
+        /// Any choices required by the leader ability.
+        #[serde(default)]
+        data: Option<LeaderAction>,
+    },
+
+    /// Use a leader ability that isn't an action, exhausting the agent or purging the hero.
+    UseLeader {
+        /// The player that uses the leader.
+        player: PlayerId,
+        /// The leader that is used.
+        leader: Leader,
+    },
+
+    /// Unlock a commander after the player has fulfilled its unlock condition.
+    UnlockCommander {
+        /// The player that unlocks the commander.
+        player: PlayerId,
+        /// The commander that is unlocked.
+        commander: Commander,
+    },
+
+    /// Unlock a faction breakthrough after the player has fulfilled its unlock condition.
+    UnlockBreakthrough {
+        /// The player that unlocks the breakthrough.
+        player: PlayerId,
+        /// The breakthrough that is unlocked.
+        breakthrough: Breakthrough,
+    },
+
+    /// Exhaust an unlocked faction breakthrough to use its ability.
+    ExhaustBreakthrough {
+        /// The player that exhausts the breakthrough.
+        player: PlayerId,
+        /// The breakthrough that is exhausted.
+        breakthrough: Breakthrough,
+    },
+
+    /// Give a promissory note to another player, notes such as Alliance and Support for the Throne
+    /// are placed in the receivers play area immediately.
+    GivePromissoryNote {
+        /// The player that owns the promissory note.
+        player: PlayerId,
+        /// The player that receives the promissory note.
+        receiver: PlayerId,
+        /// The promissory note that is given.
+        note: PromissoryNote,
+    },
+
+    /// Play a promissory note that the player holds, resolving any tracked effects.
+    PlayPromissoryNote {
+        /// The player that plays the promissory note.
+        player: PlayerId,
+        /// The player that owns the promissory note.
+        owner: PlayerId,
+        /// The promissory note that is played.
+        note: PromissoryNote,
+        /// The planet the promissory note is attached to, if any.
+        #[serde(default)]
+        planet: Option<Planet>,
+    },
+
+    /// Return a promissory note that a player holds to the player that owns it.
+    ReturnPromissoryNote {
+        /// The player that currently holds the promissory note.
+        player: PlayerId,
+        /// The player that owns the promissory note.
+        owner: PlayerId,
+        /// The promissory note that is returned.
+        note: PromissoryNote,
     },
 
     /// Begin playing a frontier card.
@@ -217,6 +453,22 @@ pub enum Event {
         relic: Relic,
     },
 
+    /// Gain a relic fragment, e.g. from exploring a planet.
+    GainRelicFragment {
+        /// The player who gains the relic fragment.
+        player: PlayerId,
+        /// The type of relic fragment gained.
+        fragment: RelicFragment,
+    },
+
+    /// Purge 3 relic fragments of the same type to draw a relic from the relic deck.
+    PurgeRelicFragments {
+        /// The player who purges the relic fragments.
+        player: PlayerId,
+        /// The relic fragments being purged.
+        fragments: Vec<RelicFragment>,
+    },
+
     /// Begin playing a relic action.
     RelicActionBegin {
         /// The player who plays the relic.
@@ -233,6 +485,73 @@ pub enum Event {
         data: Option<RelicAction>,
     },
 
+    /// Use a relic outside of a relic action, exhausting or purging it.
+    UseRelic {
+        /// The player who uses the relic.
+        player: PlayerId,
+        /// The relic being used.
+        relic: Relic,
+        /// Additional information required to use the relic.
+        data: Option<RelicAction>,
+    },
+
+    /// Begin a technology action.
+    TechActionBegin {
+        /// The player that is taking the action.
+        player: PlayerId,
+        /// The technology that is exhausted for the action.
+        tech: Technology,
+    },
+
+    /// Finish a technology action, exhausting the technology.
+    TechActionCommit {
+        /// The player that is taking the action.
+        player: PlayerId,
+        /// Additional information about what occurred during the action.
+        data: Option<TechAction>,
+    },
+
+    /// Set how many prerequisites a player may ignore for the next technology they research, used
+    /// for rulings that aren't covered by the game rules.
+    IgnorePrerequisites {
+        /// The player who may ignore prerequisites.
+        player: PlayerId,
+        /// The number of prerequisites that may be ignored.
+        count: u32,
+    },
+
+    /// Exhaust a technology for an ability that isn't an action, e.g. "Bio-Stims".
+    ExhaustTech {
+        /// The player who exhausts the technology.
+        player: PlayerId,
+        /// The technology being exhausted.
+        tech: Technology,
+    },
+
+    /// The Nekro Virus copies a technology owned by a player they won a combat against or took a
+    /// planet from using "Technological Singularity".
+    TechnologicalSingularity {
+        /// The Nekro Virus player.
+        player: PlayerId,
+        /// The player whose technology is copied.
+        from: PlayerId,
+        /// The technology that is copied.
+        tech: Technology,
+    },
+
+    /// The Nekro Virus places a "Valefar Assimilator" token on another player's faction unit
+    /// upgrade instead of copying a technology.
+    PlaceValefarAssimilator {
+        /// The Nekro Virus player.
+        player: PlayerId,
+        /// The player who owns the unit upgrade.
+        from: PlayerId,
+        /// The assimilator token being placed.
+        assimilator: ValefarAssimilator,
+        /// The faction unit upgrade that the token is placed on.
+        tech: Technology,
+    },
+
     /// End turn
     EndTurn {
         /// The player that ends their turn.
@@ -352,6 +671,36 @@ pub enum Event {
         value: i8,
     },
 
+    /// A player draws action cards outside of the status phase.
+    DrawActionCards {
+        /// The player that draws action cards.
+        player: PlayerId,
+        /// The number of action cards drawn.
+        count: u32,
+    },
+
+    /// A player draws a secret objective they were allowed to draw, e.g. from "Impersonation".
+    DrawSecretObjective {
+        /// The player that draws the secret objective.
+        player: PlayerId,
+    },
+
+    /// A player discards action cards from their hand.
+    DiscardActionCards {
+        /// The player that discards action cards.
+        player: PlayerId,
+        /// The number of action cards discarded.
+        count: u32,
+    },
+
+    /// Set the number of trade goods a player has.
+    SetTradeGoods {
+        /// The player whose trade goods are set.
+        player: PlayerId,
+        /// The new number of trade goods.
+        value: u32,
+    },
+
     /// Increment the number of points received from the imperial strategy card for a player with the given value.
     AddImperial {
         /// The player that will receive the extra imperial points.
@@ -467,6 +816,9 @@ pub enum Event {
         attachment: PlanetAttachment,
     },
 
+    /// Reveal the Fracture from Thunder's Edge, bringing it into play.
+    RevealFracture,
+
     /// Begin adding an agenda outside of the normal agenda phase flow.
     AddAgendaBegin {
         /// The agenda that was played.
@@ -614,21 +966,104 @@ pub enum ActionCardAction {
         /// The tech to gain.
         tech: Technology,
     },
+    MiningInitiative {
+        /// The planet whose resource value is gained as trade goods.
+        planet: Planet,
+    },
+    Uprising {
+        /// The non-home planet controlled by another player that is exhausted.
+        planet: Planet,
+    },
+    RepealLaw {
+        /// The law that is discarded.
+        law: Agenda,
+    },
+    Spy {
+        /// The player that gives away a random action card.
+        player: PlayerId,
+    },
+    SeizeArtifact {
+        /// The player that gives away a relic fragment.
+        player: PlayerId,
+        /// The relic fragment that is taken.
+        fragment: RelicFragment,
+    },
+    #[serde(rename_all = "camelCase")]
+    ArchaeologicalExpedition {
+        /// Which exploration deck to reveal cards from.
+        planet_trait: PlanetTrait,
+    },
+    Insubordination {
+        /// The player whose tactic pool a token is removed from.
+        player: PlayerId,
+    },
+    UnexpectedAction {
+        /// The system the command token is removed from.
+        system: SystemId,
+    },
+    GhostSquad {
+        /// The planet the ground forces are moved from.
+        from: Planet,
+        /// The planet the ground forces are moved to.
+        to: Planet,
+        /// The ground forces that are moved.
+        units: EnumMap<Unit, u32>,
+    },
 }
 
 /// Returns weather the [ActionCardAction] is for the provided [ActionCard].
-pub fn action_matches_action_card(action: &Option<ActionCardAction>, card: &ActionCard) -> bool {
-    match card {
-        ActionCard::FocusedResearch => {
-            matches!(action, Some(ActionCardAction::FocusedResearch { .. }))
-        }
-        ActionCard::DivertFunding => {
-            matches!(action, Some(ActionCardAction::DivertFunding { .. }))
-        }
-        ActionCard::Plagiarize => {
-            matches!(action, Some(ActionCardAction::Plagiarize { .. }))
-        }
-        _ => action.is_none(),
+///
+/// Cards that change state which is tracked require their matching payload, every other card is
+/// committed without one. Unless `validate_payloads` is set, cards that were played before they
+/// had a payload may still be committed without one, so that those games can be replayed.
+pub fn action_matches_action_card(
+    action: &Option<ActionCardAction>,
+    card: &ActionCard,
+    validate_payloads: bool,
+) -> bool {
+    use ActionCardAction as A;
+
+    match (card, action) {
+        (ActionCard::FocusedResearch, Some(A::FocusedResearch { .. }))
+        | (ActionCard::DivertFunding, Some(A::DivertFunding { .. }))
+        | (ActionCard::Plagiarize, Some(A::Plagiarize { .. }))
+        | (ActionCard::MiningInitiative, Some(A::MiningInitiative { .. }))
+        | (ActionCard::Uprising, Some(A::Uprising { .. }))
+        | (ActionCard::RepealLaw, Some(A::RepealLaw { .. }))
+        | (ActionCard::Spy, Some(A::Spy { .. }))
+        | (ActionCard::SeizeArtifact, Some(A::SeizeArtifact { .. }))
+        | (ActionCard::ArchaeologicalExpedition, Some(A::ArchaeologicalExpedition { .. }))
+        | (ActionCard::Insubordination, Some(A::Insubordination { .. }))
+        | (ActionCard::UnexpectedAction, Some(A::UnexpectedAction { .. }))
+        | (ActionCard::GhostSquad, Some(A::GhostSquad { .. })) => true,
+        (
+            ActionCard::MiningInitiative
+            | ActionCard::Uprising
+            | ActionCard::RepealLaw
+            | ActionCard::Spy
+            | ActionCard::SeizeArtifact
+            | ActionCard::ArchaeologicalExpedition
+            | ActionCard::Insubordination
+            | ActionCard::UnexpectedAction
+            | ActionCard::GhostSquad,
+            None,
+        ) => !validate_payloads,
+        (
+            ActionCard::FocusedResearch
+            | ActionCard::DivertFunding
+            | ActionCard::Plagiarize
+            | ActionCard::MiningInitiative
+            | ActionCard::Uprising
+            | ActionCard::RepealLaw
+            | ActionCard::Spy
+            | ActionCard::SeizeArtifact
+            | ActionCard::ArchaeologicalExpedition
+            | ActionCard::Insubordination
+            | ActionCard::UnexpectedAction
+            | ActionCard::GhostSquad,
+            _,
+        ) => false,
+        (_, action) => action.is_none(),
     }
 }
 
@@ -664,12 +1099,114 @@ pub fn action_matches_frontier_card(
     }
 }
 
+/// The choices made for specific leader abilities.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum LeaderAction {
+    RinTheMastersLegacy {
+        /// Map from the technologies that are replaced to the technologies replacing them.
+        replacements: EnumMap<Technology, Technology>,
+    },
+    UnitDsgnFlayesh {
+        /// The planet that was chosen.
+        planet: Planet,
+        /// The technology gained, matching the specialty of the planet.
+        tech: Technology,
+    },
+    #[serde(rename_all = "camelCase")]
+    XxekirGrom {
+        /// The law to discard, if any.
+        discarded_law: Option<Agenda>,
+    },
+    DannelOfTheTenthCxIII {
+        /// The (at most 3) non-home planets that were successfully invaded.
+        planets: Vec<Planet>,
+    },
+    AiroShirAur {
+        /// The system whose space area the units are moved from.
+        from: SystemId,
+        /// The adjacent system containing another player's ships that the units are moved to.
+        to: SystemId,
+    },
+}
+
+/// Returns weather the [LeaderAction] is for the provided [Leader].
+pub fn action_matches_leader(action: &Option<LeaderAction>, leader: &Leader) -> bool {
+    match leader {
+        Leader::Hero(Hero::RinTheMastersLegacy) => {
+            matches!(action, Some(LeaderAction::RinTheMastersLegacy { .. }))
+        }
+        Leader::Hero(Hero::UnitDsgnFlayesh) => {
+            matches!(action, Some(LeaderAction::UnitDsgnFlayesh { .. }))
+        }
+        Leader::Hero(Hero::XxekirGrom) => {
+            matches!(action, Some(LeaderAction::XxekirGrom { .. }))
+        }
+        Leader::Hero(Hero::DannelOfTheTenthCxIII) => {
+            matches!(action, Some(LeaderAction::DannelOfTheTenthCxIII { .. }))
+        }
+        Leader::Hero(Hero::AiroShirAur) => {
+            matches!(action, Some(LeaderAction::AiroShirAur { .. }))
+        }
+        _ => action.is_none(),
+    }
+}
+
 /// The actions taken for specific frontier cards.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[allow(missing_docs)]
 pub enum RelicAction {
-    StellarConverter { planet: Planet },
-    NanoForge { planet: Planet },
+    StellarConverter {
+        planet: Planet,
+    },
+    NanoForge {
+        planet: Planet,
+    },
+    TheCodex {
+        /// The action cards (at most 3) taken from the discard pile.
+        cards: Vec<ActionCard>,
+    },
+    #[serde(rename_all = "camelCase")]
+    JrXs4550 {
+        /// The chosen player.
+        player: PlayerId,
+        /// Weather the chosen player spent 3 resources to place a structure, otherwise they gain 1 trade good.
+        placed_structure: bool,
+    },
+    DynamisCore {
+        /// The commodity value of the players faction, only needed when it isn't known.
+        commodities: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    TheProphetsTears {
+        /// Weather the player draws an action card rather than ignoring a prerequisite.
+        draw_action_card: bool,
+    },
+}
+
+/// The actions taken for specific technologies.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum TechAction {
+    ProductionBiomes {
+        /// The other player that gains 2 trade goods.
+        player: PlayerId,
+    },
+    MageonImplants {
+        /// The player whose action card is taken.
+        player: PlayerId,
+    },
+}
+
+/// Returns weather the [TechAction] is for the provided [Technology].
+pub fn action_matches_tech(action: &Option<TechAction>, tech: &Technology) -> bool {
+    match tech {
+        Technology::ProductionBiomes => {
+            matches!(action, Some(TechAction::ProductionBiomes { .. }))
+        }
+        Technology::MageonImplants => matches!(action, Some(TechAction::MageonImplants { .. })),
+        _ => action.is_none(),
+    }
 }
 
 /// Returns weather the [RelicAction] is for the provided [Relic].
@@ -677,6 +1214,10 @@ pub fn action_matches_relic(action: &Option<RelicAction>, relic: &Relic) -> bool
     match relic {
         Relic::StellarConverter => matches!(action, Some(RelicAction::StellarConverter { .. })),
         Relic::NanoForge => matches!(action, Some(RelicAction::NanoForge { .. })),
+        Relic::TheCodex => matches!(action, Some(RelicAction::TheCodex { .. })),
+        Relic::JrXs4550 => matches!(action, Some(RelicAction::JrXs4550 { .. })),
+        Relic::TheProphetsTears => matches!(action, Some(RelicAction::TheProphetsTears { .. })),
+        Relic::DynamisCore => matches!(action, None | Some(RelicAction::DynamisCore { .. })),
         _ => action.is_none(),
     }
 }
@@ -60,3 +60,37 @@ impl Game {
         self.current = Arc::new(state);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use chrono::{DateTime, Utc};
+
+    use super::{Event, Game};
+
+    #[test]
+    fn demo_games_replay() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../demo_games_backup");
+        let mut replayed = 0;
+        for entry in fs::read_dir(dir).expect("Failed to read demo games dir") {
+            let path = entry.expect("Failed to read dir entry").path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+
+            let json = fs::read_to_string(&path).expect("Failed to read demo game");
+            let events: Vec<(Event, DateTime<Utc>)> = serde_json::from_str(&json)
+                .unwrap_or_else(|e| panic!("Failed to deserialize {path:?}: {e}"));
+
+            let mut game = Game::default();
+            for (i, (event, timestamp)) in events.into_iter().enumerate() {
+                if let Err(e) = game.apply_or_err(event.clone(), timestamp) {
+                    panic!("{path:?}: failed to apply event #{i} {event:?}: {e}");
+                }
+            }
+            replayed += 1;
+        }
+        assert!(replayed > 0, "No demo games were replayed");
+    }
+}
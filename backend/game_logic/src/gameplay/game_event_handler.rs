@@ -2,17 +2,19 @@ use std::{borrow::BorrowMut, collections::HashSet};
 
 use chrono::{DateTime, Utc};
 use eyre::{bail, ensure, Context, ContextCompat, OptionExt, Result};
-use strum::IntoEnumIterator;
 
 use crate::gameplay::{
-    agenda::{AgendaRound, Vote, VoteState},
+    agenda::{get_planet_tech_specialties, get_planet_traits, AgendaRound, Vote, VoteState},
+    deck::{draw_random, seeded_rng},
     event::{
-        action_matches_action_card, action_matches_relic, FrontierCardAction, RelicAction,
-        StrategicPrimaryAction, StrategicSecondaryAction,
+        action_matches_action_card, action_matches_leader, action_matches_relic,
+        action_matches_tech, FrontierCardAction, LeaderAction, RelicAction, StrategicPrimaryAction,
+        StrategicSecondaryAction, TechAction,
     },
     game_state::{
-        ActionCardProgress, ActionPhaseProgress, AgendaOverrideState, FrontierCardProgress,
-        LeaderProgress, RelicProgress, StrategicPrimaryProgress, StrategicProgress,
+        ActionCardEffect, ActionCardProgress, ActionPhaseProgress, AgendaOverrideState,
+        FrontierCardProgress, LeaderProgress, PlayedActionCard, RelicProgress,
+        StrategicPrimaryProgress, StrategicProgress, TechProgress,
     },
 };
 
@@ -20,31 +22,44 @@ use super::{
     color_assignment::assign_colors,
     error::GameError,
     event::{action_matches_frontier_card, ActionCardAction, Event},
-    game_state::{GameState, MapData, MiltyInformation, TacticalProgress},
-    player::NewPlayer,
+    game_state::{
+        GameState, IonStorm, IonStormSide, MapData, MiltyInformation, TacticalProgress,
+        UnitLocation,
+    },
+    player::{BreakthroughState, HeldPromissoryNote, LeaderState, NewPlayer},
 };
 
 use ti_helper_game_data::{
     common::{
-        faction::Faction, game_settings::GameSettings, milty_data::MiltyPlayer, player_id::PlayerId,
+        expansions::Expansion,
+        faction::{Faction, StartingTechPool},
+        game_settings::GameSettings,
+        milty_data::MiltyPlayer,
+        player_id::PlayerId,
     },
     components::{
         action_card::{ActionCard, ActionCardPlay},
         agenda::{AgendaElectKind, AgendaKind},
+        exploration::{ExplorationCard, ExplorationEffect},
         frontier_card::{FrontierCard, FrontierCardType},
-        leaders::LeaderAbilityKind,
-        objectives::{Objective, ObjectiveKind},
+        leaders::{Hero, Leader, LeaderAbilityKind},
+        objectives::{secret::SecretObjective, Objective, ObjectiveKind},
         phase::Phase,
-        planet::Planet,
+        planet::{Planet, PlanetTrait},
         planet_attachment::PlanetAttachment,
-        relic::{Relic, RelicPlay},
+        promissory_note::{PromissoryNote, PromissoryNoteUsage},
+        relic::{Relic, RelicFragment, RelicPlay},
         strategy_card::StrategyCard,
         system::{systems, System, SystemType},
-        tech::{TechOrigin, TechType, Technology},
+        tech::{TechOrigin, TechPlay, TechType, Technology},
+        unit::{Production, Unit, UnitKind},
     },
+    enum_map::EnumMap,
 };
 
 const MIN_PLAYER_COUNT: usize = 3;
+/// The influence spent to remove the custodians token from Mecatol Rex.
+const CUSTODIANS_INFLUENCE: u32 = 6;
 
 /// Update the game_state with the provided event, using the timestamp for time-keeping purposes.
 pub fn update_game_state(
@@ -65,27 +80,59 @@ fn try_update_game_state(
     event: Event,
     timestamp: DateTime<Utc>,
 ) -> Result<(), GameError> {
+    if !game_state.game_settings.validate_turn_order {
+        take_legacy_action_turn(game_state, &event);
+    }
+
     match event {
         Event::SetSettings { settings } => {
             game_state.assert_phase(Phase::Creation)?;
+            for event in &settings.galactic_events {
+                ensure!(
+                    settings.expansions.is_enabled(&event.info().expansion),
+                    "Galactic event {event:?} is not enabled"
+                );
+            }
             game_state.game_settings = settings;
         }
+        Event::SetGalacticEvents { events } => {
+            game_state.assert_phase(Phase::Creation)?;
+            for event in &events {
+                game_state.assert_expansion(&event.info().expansion)?;
+            }
+            ensure!(
+                events.iter().collect::<HashSet<_>>().len() == events.len(),
+                "Galactic events must be unique"
+            );
+            game_state.game_settings.galactic_events = events;
+        }
         Event::ImportFromMilty {
             max_points,
             milty_data,
+            validate_prerequisites,
+            validate_scoring_windows,
+            track_units,
+            validate_turn_order,
+            validate_action_card_payloads,
         } => {
             game_state.assert_phase(Phase::Creation)?;
 
             game_state.game_settings = GameSettings {
                 max_points,
                 expansions: milty_data.expansions.clone(),
+                validate_prerequisites,
+                validate_scoring_windows,
+                track_units,
+                validate_turn_order,
+                validate_action_card_payloads,
+                galactic_events: Vec::new(),
             };
             game_state.map_data = MapData {
                 milty_information: Some(MiltyInformation {
                     hex_map: milty_data.hex_map,
                     mirage_system: None,
                 }),
-                stellar_converter_destroyed_planets: vec![],
+                ..Default::default()
             };
 
             let mut players: Vec<&MiltyPlayer> = milty_data.players.values().collect();
@@ -212,6 +259,13 @@ fn try_update_game_state(
         }
         Event::SetupTheTribunii { player, faction } => {
             game_state.assert_phase(Phase::Setup)?;
+            ensure!(
+                !game_state
+                    .players
+                    .iter()
+                    .any(|(id, p)| id != &player && p.faction == faction),
+                "The faction is already played by another player"
+            );
             let Some(player) = game_state.players.get_mut(&player) else {
                 bail!("Player doesn't exist!")
             };
@@ -221,24 +275,19 @@ fn try_update_game_state(
                 "Player already has planets selected!"
             );
 
+            let available_factions = player.faction.setup().home_system_choices;
             ensure!(
-                player.faction == Faction::CouncilKeleres,
-                "The Tribunii can only be performed by The Council Keleres"
+                !available_factions.is_empty(),
+                "Players faction does not pick its starting planets"
             );
 
-            let available_factions = [
-                Faction::MentakCoalition,
-                Faction::XxchaKingdom,
-                Faction::ArgentFlight,
-            ];
-
             ensure!(
                 available_factions.contains(&faction),
                 "Selected faction must be one of [{available_factions:?}]"
             );
 
             // TODO: Also give the player the correct agent
-            for planet in faction.get_starting_planets().into_iter() {
+            for planet in faction.home_system_planets().into_iter() {
                 player.planets.insert(planet, HashSet::new());
             }
         }
@@ -252,62 +301,41 @@ fn try_update_game_state(
                 "Player has already performed all necessary initialization"
             );
 
-            let (allowed_techs, possible_techs) = match game_state
-                .players
-                .get(&player)
-                .expect("Player doesn't exist?")
-                .faction
-            {
-                Faction::Winnu => (
-                    1,
-                    Technology::iter()
-                        .filter(|t| t.info().requirements.is_empty())
-                        .filter(|t| t.info().origin == TechOrigin::Base)
-                        .collect(),
-                ),
-                Faction::ArgentFlight => (
-                    2,
-                    vec![
-                        Technology::NeuralMotivator,
-                        Technology::SarweenTools,
-                        Technology::PlasmaScoring,
-                    ],
-                ),
-                Faction::CouncilKeleres => (
-                    2,
-                    game_state
-                        .players
-                        .values()
-                        .filter(|p| p.faction != Faction::CouncilKeleres)
-                        .flat_map(|p| p.technologies.iter())
-                        .filter(|t| t.info().origin == TechOrigin::Base)
-                        .cloned()
-                        .collect::<Vec<Technology>>(),
-                ),
-                _ => bail!("Players faction has no technology setup to perform"),
+            let possible_techs = game_state.starting_tech_options(&player)?;
+            let validate = game_state.game_settings.validate_prerequisites;
+            let Some(player) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist!")
+            };
+            let Some(choice) = player.faction.setup().techs else {
+                bail!("Players faction has no technology setup to perform");
             };
 
             ensure!(
-                technologies.len() == allowed_techs,
+                technologies.len() == choice.count,
                 "Invalid amount of technologies selected for faction"
             );
 
+            ensure!(
+                technologies.iter().collect::<HashSet<_>>().len() == technologies.len(),
+                "The same technology can't be selected twice"
+            );
+
             ensure!(
                 technologies.iter().all(|t| possible_techs.contains(t)),
                 "Invalid technology selection for players faction"
             );
 
-            let Some(player) = game_state.players.get_mut(&player) else {
-                bail!("Player doesn't exist!")
-            };
-
             ensure!(
                 player.technologies.is_empty(),
                 "Player already has technologies?"
             );
 
             for tech in technologies.into_iter() {
-                player.technologies.insert(tech);
+                if choice.pool == StartingTechPool::Research {
+                    player.research_tech(tech, validate)?;
+                } else {
+                    player.technologies.insert(tech);
+                }
             }
         }
         Event::SetupSpeaker { player } => {
@@ -378,6 +406,10 @@ fn try_update_game_state(
                 format!("All initialization has not yet been completed for players {uninitialized_players:?}")
             );
 
+            if game_state.game_settings.track_units {
+                place_starting_units(game_state)?;
+            }
+
             game_state.change_phase(Phase::Strategy, timestamp)?;
         }
         Event::TakeStrategyCard { player, card } => {
@@ -410,28 +442,46 @@ fn try_update_game_state(
             }
             game_state.change_phase(Phase::Action, timestamp)?;
         }
-        Event::TacticalActionBegin { player } => {
+        Event::TacticalActionBegin { player, system } => {
             game_state.assert_phase(Phase::Action)?;
             game_state.assert_player_turn(&player)?;
+            if game_state.game_settings.track_units {
+                let Some(system) = &system else {
+                    bail!("The activated system is required when units are tracked");
+                };
+                let command_tokens = &mut game_state
+                    .map_data
+                    .systems
+                    .entry(system.clone())
+                    .or_default()
+                    .command_tokens;
+                ensure!(
+                    !command_tokens.contains(&player),
+                    "{player} already has a command token in {system:?}"
+                );
+                command_tokens.insert(player);
+            }
+
             game_state.phase = Phase::TacticalAction;
             game_state.action_progress = Some(ActionPhaseProgress::Tactical(TacticalProgress {
-                activated_system: None,
+                activated_system: system,
                 taken_planets: Default::default(),
                 planet_attachments: Default::default(),
+                removed_custodians: false,
+                explored_planets: Default::default(),
+                explored_frontier: None,
             }));
         }
-        Event::TacticalActionTakePlanet { player, planet } => {
+        Event::TacticalActionRemoveCustodians {
+            player,
+            planets,
+            trade_goods,
+        } => {
             game_state.assert_phase(Phase::TacticalAction)?;
             game_state.assert_player_turn(&player)?;
-            if planet == Planet::Mirage {
-                game_state.assert_expansion(&FrontierCard::Mirage.info().expansion)?;
-            } else {
-                game_state.assert_expansion(&System::for_planet(&planet)?.expansion)?;
-            }
-
             ensure!(
-                game_state.action_progress.is_some(),
-                "Must have action progress to perform take planet action."
+                game_state.score.custodians.is_none(),
+                "The custodians token has already been removed"
             );
 
             let Some(ActionPhaseProgress::Tactical(tactical)) = &mut game_state.action_progress
@@ -441,68 +491,161 @@ fn try_update_game_state(
                     game_state.action_progress
                 );
             };
-
-            let Some(current_player_id) = &game_state.current_player else {
-                bail!("no current player");
+            let Some(system) = &tactical.activated_system else {
+                bail!("The custodians token can only be removed when Mecatol Rex is activated");
             };
+            let mecatol_systems = [Planet::MecatolRex, Planet::MecatolRexOmega]
+                .iter()
+                .map(|planet| System::for_planet(planet).map(|s| s.id))
+                .collect::<Result<Vec<_>, _>>()?;
+            ensure!(
+                mecatol_systems.contains(system),
+                "The custodians token can only be removed when Mecatol Rex is activated"
+            );
 
-            let (current_owner, attachments) = game_state
-                .players
-                .iter_mut()
-                .find_map(|(id, player)| {
-                    if let Some(attachments) = player.planets.remove(&planet) {
-                        return Some((Some(id.clone()), attachments));
-                    }
-                    None
-                })
-                .unwrap_or((None, HashSet::new()));
+            // Planet exhaustion isn't tracked, the exhausted planets are only recorded in the event.
+            let Some(current_player) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist!");
+            };
+            ensure!(
+                planets.iter().collect::<HashSet<_>>().len() == planets.len(),
+                "A planet can only be exhausted once"
+            );
+            for planet in &planets {
+                ensure!(
+                    current_player.planets.contains_key(planet),
+                    "Player does not control {planet:?}"
+                );
+            }
+            ensure!(
+                current_player.trade_goods >= trade_goods,
+                "Player does not have {trade_goods} trade goods"
+            );
+            let influence = planets
+                .iter()
+                .map(|planet| current_player.planet_influence(planet))
+                .sum::<u32>()
+                + trade_goods;
+            ensure!(
+                influence >= CUSTODIANS_INFLUENCE,
+                "Removing the custodians token costs {CUSTODIANS_INFLUENCE} influence, got {influence}"
+            );
+            current_player.trade_goods -= trade_goods;
 
+            tactical.removed_custodians = true;
+            game_state.score.custodians = Some(player);
+        }
+        Event::TacticalActionTakePlanet {
+            player,
+            planet,
+            explore,
+            planet_trait,
+        } => {
+            game_state.assert_phase(Phase::TacticalAction)?;
+            game_state.assert_player_turn(&player)?;
+            if planet == Planet::Mirage {
+                game_state.assert_expansion(&FrontierCard::Mirage.info().expansion)?;
+            } else {
+                game_state.assert_expansion(&System::for_planet(&planet)?.expansion)?;
+            }
             ensure!(
-                planet != Planet::Mirage || current_owner.is_some(),
-                "Mirage must be spawned using the frontier card action"
+                !planet.is_in_fracture() || game_state.map_data.fracture_in_play,
+                "{planet:?} is in the Fracture, which has not been revealed"
             );
 
-            let shard_of_the_throne = if let Some(current_owner) = current_owner.as_ref() {
-                let Some(p) = game_state.players.get_mut(current_owner) else {
-                    bail!("Player doesn't exist {current_owner:?} (THIS IS A BUG!)");
-                };
+            ensure!(
+                matches!(
+                    game_state.action_progress,
+                    Some(ActionPhaseProgress::Tactical(..))
+                ),
+                "Invalid game state, expected tactical action, got {:?}",
+                game_state.action_progress
+            );
 
-                let legendary_attachment = attachments.iter().any(|a| a.info().set_legendary);
-                if planet.info().is_legendary || legendary_attachment {
-                    p.relics.remove(&Relic::ShardOfTheThrone)
-                } else {
-                    false
-                }
+            let planet_system = if planet == Planet::Mirage {
+                None
             } else {
-                false
+                Some(System::for_planet(&planet)?)
             };
+            let track_units = game_state.game_settings.track_units;
+            if track_units {
+                capture_planet(game_state, &player, &planet)?;
+            }
+
+            let previous_owner = game_state.take_planet(&player, planet.clone())?;
 
-            let Some(current_player) = game_state.players.get_mut(current_player_id) else {
-                bail!("invalid game state, expected current player (id: {current_player_id:?}) to be in the players map")
+            let Some(ActionPhaseProgress::Tactical(tactical)) = &mut game_state.action_progress
+            else {
+                bail!(
+                    "Invalid game state, was just tactical action, is now {:?}. This is a bug!",
+                    game_state.action_progress
+                );
             };
+            if !track_units {
+                tactical.activated_system = planet_system.map(|s| s.id);
+            }
+            tactical
+                .taken_planets
+                .insert(planet.clone(), previous_owner);
 
-            if shard_of_the_throne {
-                current_player.relics.insert(Relic::ShardOfTheThrone);
-                game_state.score.shard_of_the_throne = Some(current_player_id.clone());
+            if explore {
+                game_state.assert_expansion(&Expansion::ProphecyOfKings)?;
+                explore_planet(game_state, planet, planet_trait, timestamp)?;
+            }
+        }
+        Event::CombatResult {
+            winner,
+            loser,
+            location,
+        } => {
+            ensure!(
+                winner != loser,
+                "A player can't win a combat against themselves"
+            );
+            for p in [&winner, &loser] {
+                ensure!(
+                    game_state.players.contains_key(p),
+                    "Player doesn't exist '{p}'"
+                );
             }
 
-            let planet_system = if planet == Planet::Mirage {
-                None
-            } else {
-                Some(System::for_planet(&planet)?.id)
-            };
+            if game_state.game_settings.track_units {
+                let Some(location) = location else {
+                    bail!("The combat location is required when units are tracked");
+                };
+                game_state.assert_phase(Phase::TacticalAction)?;
+                ensure!(
+                    game_state.map_data.system_of(&location)? == game_state.activated_system()?,
+                    "Combat can only take place in the activated system"
+                );
+                // The loser's ships are destroyed in space combat and their ground forces in
+                // ground combat, including any ground forces carried by the destroyed ships. The
+                // winner's casualties aren't part of the result, they are removed with
+                // RemoveUnits.
+                let destroyed = game_state.map_data.units_at(&loser, &location)?;
+                let destroyed = destroyed
+                    .iter()
+                    .filter(|(unit, _)| unit.kind() != UnitKind::Structure)
+                    .map(|(unit, count)| (*unit, *count))
+                    .collect::<EnumMap<_, _>>();
+                ensure!(
+                    !destroyed.is_empty(),
+                    "{loser} has no units that can fight at {location:?}"
+                );
+                game_state
+                    .map_data
+                    .remove_units(&loser, &location, &destroyed)?;
+            }
 
-            current_player.planets.insert(planet.clone(), attachments);
+            game_state
+                .score
+                .transfer_shard_of_the_throne(&loser, &winner);
 
-            // Give the current player Custodians if he is the first to take Mecatol Rex
-            if let Planet::MecatolRex = planet {
-                if game_state.score.custodians.is_none() {
-                    game_state.score.custodians = Some(current_player_id.clone());
-                }
+            let most_points = game_state.score.player_points.values().max().copied();
+            if game_state.score.player_points.get(&loser).copied() == most_points {
+                game_state
+                    .add_secret_objective_opportunity(&winner, SecretObjective::SparkARebellion);
             }
-
-            tactical.activated_system = planet_system;
-            tactical.taken_planets.insert(planet, current_owner);
         }
         Event::TacticalActionAttachPlanetAttachment {
             player,
@@ -569,128 +712,436 @@ fn try_update_game_state(
                 .planet_attachments
                 .insert(planet, actual_attachment);
         }
-        Event::TacticalActionCommit { player } => {
+        Event::ExplorePlanet {
+            player,
+            planet,
+            planet_trait,
+        } => {
             game_state.assert_phase(Phase::TacticalAction)?;
             game_state.assert_player_turn(&player)?;
-            game_state.action_progress = None;
-            game_state.phase = Phase::EndActionTurn;
+            game_state.assert_expansion(&Expansion::ProphecyOfKings)?;
+
+            explore_planet(game_state, planet, planet_trait, timestamp)?;
         }
-        Event::StrategicActionBegin { player, card } => {
-            game_state.assert_phase(Phase::Action)?;
+        Event::ExploreFrontier {
+            player,
+            system,
+            ion_storm_side,
+        } => {
+            game_state.assert_phase(Phase::TacticalAction)?;
             game_state.assert_player_turn(&player)?;
+            game_state.assert_expansion(&Expansion::ProphecyOfKings)?;
+
+            let Some(frontier_system) = systems().remove(&system) else {
+                bail!("System doesn't exist '{system}'");
+            };
+            game_state.assert_expansion(&frontier_system.expansion)?;
             ensure!(
-                game_state.strategy_card_holders.get(&card) == Some(&player),
-                "current player must hold the strategy card",
-            );
-            ensure!(
-                !game_state.spent_strategy_cards.contains(&card),
-                "strategy card can't already be used",
-            );
-            ensure!(
-                game_state.action_progress.is_none(),
-                "we are already performing an action phase action",
+                frontier_system.planets.is_empty()
+                    && !matches!(frontier_system.system_type, SystemType::Hyperlane),
+                "{system} is not a frontier system"
             );
-            game_state.phase = Phase::StrategicAction;
-
-            let player_faction = game_state
-                .players
-                .get(&player)
-                .wrap_err("Expected player to exist")?
-                .faction;
-
-            game_state.action_progress = Some(ActionPhaseProgress::Strategic(StrategicProgress {
-                card,
-                primary: StrategicPrimaryProgress::default_for_card_and_faction(
-                    card,
-                    player_faction,
-                ),
-                other_players: Default::default(),
-            }));
-            game_state.spent_strategy_cards.insert(card);
-        }
-        Event::StrategicActionPrimary { player, action } => {
-            game_state.assert_phase(Phase::StrategicAction)?;
-            game_state.assert_player_turn(&player)?;
             ensure!(
-                game_state.action_progress.is_some(),
-                "Invalid game state: expected action_progress not to be None"
+                !game_state.map_data.explored_frontiers.contains(&system),
+                "The frontier token in {system} has already been explored"
             );
 
-            game_state.assert_action_expansion(&action)?;
+            {
+                let Some(ActionPhaseProgress::Tactical(tactical)) = &game_state.action_progress
+                else {
+                    bail!(
+                        "Invalid game state, expected tactical action, got {:?}",
+                        game_state.action_progress
+                    );
+                };
+                ensure!(
+                    tactical.explored_frontier.is_none(),
+                    "A frontier token has already been explored this turn!"
+                );
+                ensure!(
+                    tactical.activated_system.is_none()
+                        || tactical.activated_system.as_ref() == Some(&system),
+                    "Can only explore a frontier token in the activated system"
+                );
+            }
 
-            let progress = game_state.action_progress.as_mut().unwrap();
-            let progress = match progress {
-                ActionPhaseProgress::Strategic(p) => p,
-                _ => bail!("Invalid action phase progress '{:?}'", progress),
+            let Some(card) = game_state.draw_frontier_card(&mut seeded_rng(timestamp)) else {
+                bail!("No cards left in the frontier deck");
             };
 
-            ensure!(
-                progress.primary.is_none(),
-                "Primary action has already been performed"
-            );
-
-            match (progress.card, action) {
-                (StrategyCard::Technology, StrategicPrimaryAction::Technology { tech, extra }) => {
-                    /* Set the progress */
-                    progress.primary = Some(StrategicPrimaryProgress::Technology {
-                        tech: Some(tech.clone()),
-                        extra: extra.clone(),
-                    });
-
-                    /* Give the tech(s) to the current player */
-                    let current_player = game_state.get_current_player()?;
+            game_state.map_data.explored_frontiers.push(system.clone());
+            let frontier_type = card.info().frontier_type;
+            if !frontier_type.leaves_deck() {
+                let Some(deck) = &mut game_state.frontier_deck else {
+                    bail!("Drew a card from a frontier deck that doesn't exist, this is a bug!");
+                };
+                deck.discard(card.clone());
+            }
 
-                    current_player.research_tech(tech.clone())?;
-                    if let Some(t) = extra.clone() {
-                        current_player.research_tech(t.clone())?;
-                    }
+            match &frontier_type {
+                FrontierCardType::DrawActionCards(count) => {
+                    game_state.get_current_player()?.gain_action_cards(*count);
                 }
-                (StrategyCard::Politics, StrategicPrimaryAction::Politics { new_speaker }) => {
-                    progress.primary = Some(StrategicPrimaryProgress::Politics {
-                        new_speaker: new_speaker.clone(),
-                    });
-
-                    game_state.speaker = Some(new_speaker);
+                FrontierCardType::RelicFragment => {
+                    *game_state
+                        .get_current_player()?
+                        .relic_fragments
+                        .entry(RelicFragment::Unknown)
+                        .or_default() += 1;
                 }
-                (StrategyCard::Imperial, StrategicPrimaryAction::Imperial { score_objective }) => {
-                    if let Some(objective) = score_objective.clone() {
-                        let Some(players) =
-                            game_state.score.revealed_objectives.get_mut(&objective)
-                        else {
-                            bail!("objective {objective:?} has not been revealed");
-                        };
-
-                        if !players.insert(player.clone()) {
-                            bail!("player {player:?} has already scored {objective:?}");
-                        }
-                    }
-
-                    progress.primary = Some(StrategicPrimaryProgress::Imperial {
-                        objective: score_objective,
+                FrontierCardType::IonStorm => {
+                    game_state.map_data.ion_storm = Some(IonStorm {
+                        system: system.clone(),
+                        side: ion_storm_side.unwrap_or(IonStormSide::Alpha),
                     });
-
-                    let current_player = game_state.get_current_player()?;
-                    if current_player.planets.contains_key(&Planet::MecatolRex) {
-                        let imperial_points = game_state.score.imperial.entry(player).or_default();
-                        *imperial_points = imperial_points.saturating_add(1);
-                    }
                 }
-                (card, action) => {
-                    bail!("Mismatch between progress card {card:?} and action {action:?}")
+                FrontierCardType::GammaWormhole => {
+                    game_state.map_data.gamma_wormholes.push(system.clone());
+                }
+                FrontierCardType::EntropicField => {
+                    game_state
+                        .map_data
+                        .entropic_fields
+                        .insert(system.clone(), card.clone());
+                }
+                FrontierCardType::DrawSecretObjective => {
+                    game_state.get_current_player()?.secret_objectives_to_draw += 1;
+                }
+                FrontierCardType::Action | FrontierCardType::Other => {
+                    /* Handled below or by the player */
                 }
             }
-        }
-        Event::StrategicActionSecondary { player, action } => {
-            game_state.assert_phase(Phase::StrategicAction)?;
-            let current_player = game_state.current_player()?;
-            ensure!(
-                player != current_player,
-                "current player can't perform the secondary on a strategy card",
-            );
-
-            game_state.assert_secondary_action_expansion(&action)?;
 
-            let Some(action_progress) = &mut game_state.action_progress else {
+            let Some(ActionPhaseProgress::Tactical(tactical)) = &mut game_state.action_progress
+            else {
+                bail!(
+                    "Invalid game state, was just tactical action, is now {:?}. This is a bug!",
+                    game_state.action_progress
+                );
+            };
+            tactical.activated_system = Some(system);
+            tactical.explored_frontier = Some(card);
+        }
+        Event::FlipIonStorm { player } => {
+            game_state.assert_phase(Phase::TacticalAction)?;
+            game_state.assert_player_turn(&player)?;
+            let Some(ion_storm) = &mut game_state.map_data.ion_storm else {
+                bail!("The ion storm token has not been placed on the board");
+            };
+            ion_storm.side = ion_storm.side.flipped();
+        }
+        Event::MoveUnits {
+            player,
+            from,
+            units,
+        } => {
+            game_state.assert_phase(Phase::TacticalAction)?;
+            game_state.assert_player_turn(&player)?;
+            game_state.assert_units_tracked()?;
+            let system = game_state.activated_system()?;
+            let from_system = game_state.map_data.system_of(&from)?;
+            ensure!(
+                from_system != system,
+                "Units can only be moved into the activated system"
+            );
+            ensure!(
+                !game_state
+                    .map_data
+                    .systems
+                    .get(&from_system)
+                    .is_some_and(|s| s.command_tokens.contains(&player)),
+                "Units can't move out of a system that contains the players command token"
+            );
+            ensure!(
+                units.keys().all(|unit| unit.kind() != UnitKind::Structure),
+                "Structures can't be moved"
+            );
+
+            game_state.map_data.remove_units(&player, &from, &units)?;
+            game_state
+                .map_data
+                .add_units(&player, &UnitLocation::Space(system), &units)?;
+        }
+        Event::LandUnits {
+            player,
+            planet,
+            units,
+        } => {
+            game_state.assert_phase(Phase::TacticalAction)?;
+            game_state.assert_player_turn(&player)?;
+            game_state.assert_units_tracked()?;
+            let system = game_state.activated_system()?;
+            let location = UnitLocation::Planet(planet);
+            ensure!(
+                game_state.map_data.system_of(&location)? == system,
+                "Units can only land on planets in the activated system"
+            );
+            ensure!(
+                units
+                    .keys()
+                    .all(|unit| unit.kind() == UnitKind::GroundForce),
+                "Only ground forces can land on planets"
+            );
+
+            game_state
+                .map_data
+                .remove_units(&player, &UnitLocation::Space(system), &units)?;
+            game_state.map_data.add_units(&player, &location, &units)?;
+        }
+        Event::ProduceUnits {
+            player,
+            location,
+            units,
+        } => {
+            game_state.assert_units_tracked()?;
+            ensure!(
+                game_state.players.contains_key(&player),
+                "Player doesn't exist '{player}'"
+            );
+            for unit in units.keys() {
+                game_state.assert_expansion(&unit.info().expansion)?;
+            }
+
+            let system = game_state.map_data.system_of(&location)?;
+            match (&game_state.phase, &game_state.action_progress) {
+                (Phase::TacticalAction, _) => {
+                    game_state.assert_player_turn(&player)?;
+                    ensure!(
+                        system == game_state.activated_system()?,
+                        "Units can only be produced in the activated system"
+                    );
+                    assert_can_produce(game_state, &player, &system, &units)?;
+                }
+                (Phase::StrategicAction, Some(ActionPhaseProgress::Strategic(progress))) => {
+                    match progress.card {
+                        StrategyCard::Warfare => {
+                            ensure!(
+                                system == game_state.players[&player].home_system()?.id,
+                                "Units can only be produced in the home system with the Warfare \
+                                strategy card"
+                            );
+                            assert_can_produce(game_state, &player, &system, &units)?;
+                        }
+                        StrategyCard::Construction => {
+                            ensure!(
+                                units.keys().all(|unit| unit.kind() == UnitKind::Structure),
+                                "Only structures can be placed with the Construction strategy card"
+                            );
+                            let UnitLocation::Planet(planet) = &location else {
+                                bail!("Structures can't be placed in space");
+                            };
+                            ensure!(
+                                game_state.players[&player].planets.contains_key(planet),
+                                "Structures can only be placed on planets the player controls"
+                            );
+                        }
+                        card => bail!("Units can't be produced with the {card:?} strategy card"),
+                    }
+                }
+                (phase, _) => bail!("Units can't be produced during {phase:?}"),
+            }
+
+            game_state.map_data.add_units(&player, &location, &units)?;
+        }
+        Event::PlaceUnits {
+            player,
+            location,
+            units,
+        } => {
+            game_state.assert_units_tracked()?;
+            let Some(faction) = game_state.players.get(&player).map(|p| p.faction) else {
+                bail!("Player doesn't exist '{player}'");
+            };
+            for unit in units.keys() {
+                game_state.assert_expansion(&unit.info().expansion)?;
+            }
+            if game_state.phase == Phase::Setup {
+                ensure!(
+                    faction.setup().starting_units.is_none(),
+                    "The starting units of {faction:?} are placed when the game starts"
+                );
+            } else {
+                // Outside of setup, units are only placed by exploring a planet with a card that
+                // places a unit on it.
+                game_state.assert_phase(Phase::TacticalAction)?;
+                game_state.assert_player_turn(&player)?;
+                let UnitLocation::Planet(planet) = &location else {
+                    bail!("Units can only be placed on a planet explored during this action");
+                };
+                let Some(ActionPhaseProgress::Tactical(tactical)) = &game_state.action_progress
+                else {
+                    bail!(
+                        "Invalid game state, expected tactical action, got {:?}",
+                        game_state.action_progress
+                    );
+                };
+                let placed = match tactical.explored_planets.get(planet) {
+                    Some(ExplorationCard::MercenaryOutfit) => Unit::Infantry,
+                    Some(ExplorationCard::LocalFabricators) => {
+                        let present = game_state.map_data.units_at(&player, &location)?;
+                        ensure!(
+                            present.contains_key(&Unit::Mech),
+                            "Local Fabricators only places a mech on a planet with a mech"
+                        );
+                        Unit::Mech
+                    }
+                    _ => bail!(
+                        "Units can only be placed on a planet explored with a card that places \
+                        units"
+                    ),
+                };
+                ensure!(
+                    units == [(placed, 1)].into_iter().collect(),
+                    "The exploration card places 1 {placed:?}"
+                );
+            }
+
+            game_state.map_data.add_units(&player, &location, &units)?;
+        }
+        Event::RemoveUnits {
+            player,
+            location,
+            units,
+        } => {
+            game_state.assert_phase(Phase::TacticalAction)?;
+            game_state.assert_units_tracked()?;
+            ensure!(
+                game_state.players.contains_key(&player),
+                "Player doesn't exist '{player}'"
+            );
+            ensure!(
+                game_state.map_data.system_of(&location)? == game_state.activated_system()?,
+                "Units can only be removed from the activated system"
+            );
+
+            game_state
+                .map_data
+                .remove_units(&player, &location, &units)?;
+        }
+        Event::TacticalActionCommit { player } => {
+            game_state.assert_phase(Phase::TacticalAction)?;
+            game_state.assert_player_turn(&player)?;
+            game_state.action_progress = None;
+            game_state.phase = Phase::EndActionTurn;
+        }
+        Event::StrategicActionBegin { player, card } => {
+            game_state.assert_phase(Phase::Action)?;
+            game_state.assert_player_turn(&player)?;
+            ensure!(
+                game_state.strategy_card_holders.get(&card) == Some(&player),
+                "current player must hold the strategy card",
+            );
+            ensure!(
+                !game_state.spent_strategy_cards.contains(&card),
+                "strategy card can't already be used",
+            );
+            ensure!(
+                game_state.action_progress.is_none(),
+                "we are already performing an action phase action",
+            );
+            game_state.phase = Phase::StrategicAction;
+
+            let player_faction = game_state
+                .players
+                .get(&player)
+                .wrap_err("Expected player to exist")?
+                .faction;
+
+            game_state.action_progress = Some(ActionPhaseProgress::Strategic(StrategicProgress {
+                card,
+                primary: StrategicPrimaryProgress::default_for_card_and_faction(
+                    card,
+                    player_faction,
+                ),
+                other_players: Default::default(),
+            }));
+            game_state.spent_strategy_cards.insert(card);
+        }
+        Event::StrategicActionPrimary { player, action } => {
+            game_state.assert_phase(Phase::StrategicAction)?;
+            game_state.assert_player_turn(&player)?;
+            ensure!(
+                game_state.action_progress.is_some(),
+                "Invalid game state: expected action_progress not to be None"
+            );
+
+            game_state.assert_action_expansion(&action)?;
+
+            let progress = game_state.action_progress.as_mut().unwrap();
+            let progress = match progress {
+                ActionPhaseProgress::Strategic(p) => p,
+                _ => bail!("Invalid action phase progress '{:?}'", progress),
+            };
+
+            ensure!(
+                progress.primary.is_none(),
+                "Primary action has already been performed"
+            );
+
+            match (progress.card, action) {
+                (StrategyCard::Technology, StrategicPrimaryAction::Technology { tech, extra }) => {
+                    /* Set the progress */
+                    progress.primary = Some(StrategicPrimaryProgress::Technology {
+                        tech: Some(tech.clone()),
+                        extra: extra.clone(),
+                    });
+
+                    /* Give the tech(s) to the current player */
+                    let validate = game_state.game_settings.validate_prerequisites;
+                    let current_player = game_state.get_current_player()?;
+
+                    current_player.research_tech(tech.clone(), validate)?;
+                    if let Some(t) = extra.clone() {
+                        current_player.research_tech(t.clone(), validate)?;
+                    }
+                }
+                (StrategyCard::Politics, StrategicPrimaryAction::Politics { new_speaker }) => {
+                    progress.primary = Some(StrategicPrimaryProgress::Politics {
+                        new_speaker: new_speaker.clone(),
+                    });
+
+                    game_state.speaker = Some(new_speaker);
+                    game_state.get_current_player()?.gain_action_cards(2);
+                }
+                (StrategyCard::Imperial, StrategicPrimaryAction::Imperial { score_objective }) => {
+                    if let Some(objective) = score_objective.clone() {
+                        let Some(players) =
+                            game_state.score.revealed_objectives.get_mut(&objective)
+                        else {
+                            bail!("objective {objective:?} has not been revealed");
+                        };
+
+                        if !players.insert(player.clone()) {
+                            bail!("player {player:?} has already scored {objective:?}");
+                        }
+                    }
+
+                    progress.primary = Some(StrategicPrimaryProgress::Imperial {
+                        objective: score_objective,
+                    });
+
+                    let current_player = game_state.get_current_player()?;
+                    if current_player.planets.keys().any(Planet::is_mecatol_rex) {
+                        let imperial_points = game_state.score.imperial.entry(player).or_default();
+                        *imperial_points = imperial_points.saturating_add(1);
+                    }
+                }
+                (card, action) => {
+                    bail!("Mismatch between progress card {card:?} and action {action:?}")
+                }
+            }
+        }
+        Event::StrategicActionSecondary { player, action } => {
+            game_state.assert_phase(Phase::StrategicAction)?;
+            let current_player = game_state.current_player()?;
+            ensure!(
+                player != current_player,
+                "current player can't perform the secondary on a strategy card",
+            );
+
+            game_state.assert_secondary_action_expansion(&action)?;
+
+            let Some(action_progress) = &mut game_state.action_progress else {
                 bail!("no strategic action in progress");
             };
 
@@ -702,20 +1153,26 @@ fn try_update_game_state(
                     );
                     match action.clone() {
                         StrategicSecondaryAction::Technology { tech } => {
+                            let validate = game_state.game_settings.validate_prerequisites;
                             let player = game_state.players.get_mut(&player).unwrap();
-                            player.research_tech(tech)?;
+                            player.research_tech(tech, validate)?;
                         }
                         StrategicSecondaryAction::TechnologyJolNar {
                             first_tech,
                             second_tech,
                         } => {
+                            let validate = game_state.game_settings.validate_prerequisites;
                             let player = game_state.players.get_mut(&player).unwrap();
                             ensure!(player.faction == Faction::UniversitiesOfJolNar, "Only the universities of Jol-Nar can use the 'Brilliant' ability to perform the primary tech action as a secondary");
-                            player.research_tech(first_tech)?;
+                            player.research_tech(first_tech, validate)?;
                             if let Some(t) = second_tech {
-                                player.research_tech(t)?;
+                                player.research_tech(t, validate)?;
                             }
                         }
+                        StrategicSecondaryAction::Politics => {
+                            let player = game_state.players.get_mut(&player).unwrap();
+                            player.gain_action_cards(2);
+                        }
                         _ => {}
                     }
 
@@ -767,36 +1224,56 @@ fn try_update_game_state(
                 _ => {}
             }
 
+            game_state.get_current_player()?.discard_action_cards(1);
             game_state.action_progress =
-                Some(ActionPhaseProgress::ActionCard(ActionCardProgress { card }));
+                Some(ActionPhaseProgress::ActionCard(ActionCardProgress {
+                    card: card.clone(),
+                }));
             game_state.phase = Phase::ActionCardAction;
+            game_state.played_action_cards.push(PlayedActionCard {
+                player,
+                card,
+                round: game_state.round,
+                phase: game_state.phase,
+                cancelled: false,
+                resolved: false,
+                effect: None,
+            });
         }
         Event::ActionCardActionCommit { player, data } => {
             game_state.assert_phase(Phase::ActionCardAction)?;
             game_state.assert_player_turn(&player)?;
 
-            if let Some(ActionPhaseProgress::ActionCard(progress)) = &game_state.action_progress {
-                ensure!(
-                    action_matches_action_card(&data, &progress.card),
-                    "Trying to perform an action that does not match the selected action card."
-                );
-            } else {
+            let Some(ActionPhaseProgress::ActionCard(progress)) = &game_state.action_progress
+            else {
                 bail!("Not currently performing an action card action");
             };
+            ensure!(
+                action_matches_action_card(
+                    &data,
+                    &progress.card,
+                    game_state.game_settings.validate_action_card_payloads
+                ),
+                "Trying to perform an action that does not match the selected action card."
+            );
+            let card = progress.card.clone();
+            let effect = ActionCardEffect::record(game_state);
 
             if let Some(data) = data {
                 match data {
                     ActionCardAction::FocusedResearch { tech } => {
-                        game_state.assert_expansion(&tech.info().expansion)?;
+                        game_state.assert_tech_enabled(&tech)?;
+                        let validate = game_state.game_settings.validate_prerequisites;
                         let current_player = game_state.get_current_player()?;
-                        current_player.research_tech(tech.clone())?;
+                        current_player.research_tech(tech.clone(), validate)?;
                     }
                     ActionCardAction::DivertFunding {
                         remove_tech,
                         take_tech,
                     } => {
-                        game_state.assert_expansion(&take_tech.info().expansion)?;
+                        game_state.assert_tech_enabled(&take_tech)?;
 
+                        let validate = game_state.game_settings.validate_prerequisites;
                         let current_player = game_state.get_current_player()?;
                         ensure!(
                             current_player.has_tech(&remove_tech),
@@ -817,8 +1294,8 @@ fn try_update_game_state(
                         );
 
                         if take_tech != remove_tech {
-                            current_player.research_tech(take_tech.clone())?;
-                            current_player.technologies.remove(&remove_tech);
+                            current_player.research_tech(take_tech.clone(), validate)?;
+                            current_player.remove_tech(&remove_tech);
                         }
                     }
                     ActionCardAction::Plagiarize { tech } => {
@@ -828,58 +1305,681 @@ fn try_update_game_state(
                             "Unable to take tech {tech:?} for this action card"
                         );
 
-                        let current_player = game_state.get_current_player()?;
-                        current_player.take_tech(tech)?;
+                        let current_player = game_state.get_current_player()?;
+                        current_player.take_tech(tech)?;
+                    }
+                    ActionCardAction::MiningInitiative { planet } => {
+                        let current_player = game_state.get_current_player()?;
+                        ensure!(
+                            current_player.planets.contains_key(&planet),
+                            "Player doesn't control {planet:?}"
+                        );
+                        current_player.trade_goods += planet.info().resources;
+                    }
+                    ActionCardAction::Uprising { planet } => {
+                        ensure!(
+                            !matches!(
+                                System::for_planet(&planet)?.system_type,
+                                SystemType::HomeSystem(_)
+                            ),
+                            "Cannot exhaust a home planet"
+                        );
+                        ensure!(
+                            game_state
+                                .players
+                                .iter()
+                                .any(|(id, p)| id != &player && p.planets.contains_key(&planet)),
+                            "{planet:?} is not controlled by another player"
+                        );
+
+                        let current_player = game_state.get_current_player()?;
+                        current_player.trade_goods += planet.info().resources;
+                    }
+                    ActionCardAction::RepealLaw { law } => {
+                        ensure!(
+                            game_state.laws.contains_key(&law),
+                            "Unable to repeal law that has not been enacted"
+                        );
+
+                        game_state.score.handle_law_repealed(&law);
+                        game_state.laws.remove(&law);
+                    }
+                    ActionCardAction::Spy { player: target } => {
+                        ensure!(target != player, "Cannot spy on yourself");
+                        let Some(target) = game_state.players.get_mut(&target) else {
+                            bail!("Player doesn't exist");
+                        };
+                        target.discard_action_cards(1);
+
+                        game_state.get_current_player()?.gain_action_cards(1);
+                    }
+                    ActionCardAction::SeizeArtifact {
+                        player: target,
+                        fragment,
+                    } => {
+                        ensure!(target != player, "Cannot seize an artifact from yourself");
+                        let Some(target) = game_state.players.get_mut(&target) else {
+                            bail!("Player doesn't exist");
+                        };
+                        let count = target.relic_fragments.entry(fragment).or_default();
+                        ensure!(*count > 0, "Player doesn't have a {fragment:?} fragment");
+                        *count -= 1;
+
+                        let current_player = game_state.get_current_player()?;
+                        *current_player.relic_fragments.entry(fragment).or_default() += 1;
+                    }
+                    ActionCardAction::Insubordination { player: target } => {
+                        ensure!(target != player, "Cannot target yourself");
+                        ensure!(
+                            game_state.players.contains_key(&target),
+                            "Player doesn't exist '{target}'"
+                        );
+                        // Command token pools aren't tracked, only the target is recorded.
+                    }
+                    ActionCardAction::UnexpectedAction { system } => {
+                        game_state.assert_units_tracked()?;
+                        ensure!(
+                            game_state
+                                .map_data
+                                .systems
+                                .get_mut(&system)
+                                .is_some_and(|units| units.command_tokens.remove(&player)),
+                            "Player doesn't have a command token in {system}"
+                        );
+                    }
+                    ActionCardAction::GhostSquad { .. } => {
+                        bail!("Ghost Squad is played in response to a tactical action")
+                    }
+                    ActionCardAction::ArchaeologicalExpedition { planet_trait } => {
+                        let current_player = game_state.get_current_player()?;
+                        ensure!(
+                            current_player.planets.iter().any(|(planet, attachments)| {
+                                get_planet_traits(planet, attachments).contains(&planet_trait)
+                            }),
+                            "Player doesn't control a planet with the trait {planet_trait:?}"
+                        );
+
+                        let mut rng = seeded_rng(timestamp);
+                        for _ in 0..3 {
+                            let Some(card) =
+                                game_state.draw_exploration_card(&planet_trait, &mut rng)
+                            else {
+                                break;
+                            };
+
+                            match card.info().effect {
+                                ExplorationEffect::RelicFragment(fragment) => {
+                                    let current_player = game_state.get_current_player()?;
+                                    *current_player.relic_fragments.entry(fragment).or_default() +=
+                                        1;
+                                }
+                                _ => {
+                                    let Some(deck) =
+                                        game_state.exploration_decks.get_mut(&planet_trait)
+                                    else {
+                                        bail!("Drew a card from an exploration deck that doesn't exist, this is a bug!");
+                                    };
+                                    deck.discard(card);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if card == ActionCard::IndustrialInitiative {
+                let current_player = game_state.get_current_player()?;
+                let industrial_planets = current_player
+                    .planets
+                    .iter()
+                    .filter(|(planet, attachments)| {
+                        get_planet_traits(planet, attachments).contains(&PlanetTrait::Industrial)
+                    })
+                    .count();
+                current_player.trade_goods += industrial_planets as u32;
+            }
+
+            if card == ActionCard::Impersonation {
+                game_state.get_current_player()?.secret_objectives_to_draw += 1;
+            }
+
+            if let Some(played) = game_state.played_action_cards.last_mut() {
+                played.resolved = true;
+                played.effect = Some(effect);
+            }
+            game_state.action_progress = None;
+            game_state.phase = Phase::EndActionTurn;
+        }
+        Event::PlayActionCard { player, card, data } => {
+            game_state.assert_expansion(&card.info().expansion)?;
+            game_state.assert_action_card_window(&player, &card.info().play)?;
+            ensure!(
+                card.info().play != ActionCardPlay::Action
+                    && action_matches_action_card(
+                        &data,
+                        &card,
+                        game_state.game_settings.validate_action_card_payloads
+                    ),
+                "Trying to play an action card with an action that does not match it"
+            );
+
+            if card == ActionCard::Sabotage {
+                ensure!(
+                    game_state
+                        .last_played_action_card()
+                        .is_some_and(|played| played.card != ActionCard::Sabotage),
+                    "Sabotage can't cancel another Sabotage"
+                );
+            }
+
+            // Cancel first, undoing the cancelled card must not undo the discard of "Sabotage".
+            if card == ActionCard::Sabotage {
+                cancel_last_action_card(game_state)?;
+            }
+
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            p.discard_action_cards(1);
+
+            let effect = data.as_ref().map(|_| ActionCardEffect::record(game_state));
+            if let Some(ActionCardAction::GhostSquad { from, to, units }) = data {
+                game_state.assert_units_tracked()?;
+                let system = game_state.activated_system()?;
+                for planet in [&from, &to] {
+                    ensure!(
+                        game_state
+                            .map_data
+                            .system_of(&UnitLocation::Planet(planet.clone()))?
+                            == system,
+                        "{planet:?} is not in the active system"
+                    );
+                    ensure!(
+                        game_state.players[&player].planets.contains_key(planet),
+                        "Player doesn't control {planet:?}"
+                    );
+                }
+                ensure!(
+                    units
+                        .keys()
+                        .all(|unit| unit.kind() == UnitKind::GroundForce),
+                    "Only ground forces can be moved"
+                );
+
+                game_state
+                    .map_data
+                    .remove_units(&player, &UnitLocation::Planet(from), &units)?;
+                game_state
+                    .map_data
+                    .add_units(&player, &UnitLocation::Planet(to), &units)?;
+            }
+
+            game_state.played_action_cards.push(PlayedActionCard {
+                player,
+                card,
+                round: game_state.round,
+                phase: game_state.phase,
+                cancelled: false,
+                resolved: false,
+                effect,
+            });
+        }
+        Event::LeaderActionBegin { player, leader } => {
+            game_state.assert_phase(Phase::Action)?;
+            game_state.assert_player_turn(&player)?;
+            ensure!(
+                leader.info().ability_kind() == LeaderAbilityKind::Action,
+                "{}'s ability can't be played as an action",
+                leader.info().name(),
+            );
+            ensure!(
+                game_state
+                    .available_leaders
+                    .get(&player)
+                    .is_some_and(|leaders| leaders.contains(&leader)),
+                "{} is not available to {player}",
+                leader.info().name(),
+            );
+            ensure!(
+                game_state.action_progress.is_none(),
+                "Invalid game state, action_progress not None during Action Phase"
+            );
+
+            // TODO: the following leaders may need more special handling:
+            //   ConservatorProcyon - explore frontier tokens (yes, plural...)
+            //   MathisMathinus - perform a strategic action (WHAT)
+            //   ZeuCxIII - any one player gets to take a tactical acton (WTF)
+            //   HeshAndPrit - do <=2 secondary strategy actions (NO! GOD NO!)
+            game_state.action_progress =
+                Some(ActionPhaseProgress::Leader(LeaderProgress::new(leader)));
+            game_state.phase = Phase::LeaderAction;
+        }
+        Event::LeaderActionCommit {
+            player,
+            leader,
+            data,
+        } => {
+            game_state.assert_phase(Phase::LeaderAction)?;
+            game_state.assert_player_turn(&player)?;
+            let Some(ActionPhaseProgress::Leader(progress)) = &game_state.action_progress else {
+                bail!("action_progress not set to Leader, this is a bug");
+            };
+            let previous_leader = progress.leader();
+            if leader != previous_leader {
+                bail!("invalid leader {leader:?}, expected {previous_leader:?}");
+            }
+
+            ensure!(
+                action_matches_leader(&data, &leader),
+                "Data provided doesn't match the leader being played."
+            );
+
+            if let Some(data) = data {
+                match data {
+                    LeaderAction::RinTheMastersLegacy { replacements } => {
+                        let expansions = game_state.game_settings.expansions.clone();
+                        let p = game_state.get_current_player()?;
+                        ensure!(
+                            replacements.values().collect::<HashSet<_>>().len()
+                                == replacements.len(),
+                            "Can't replace several technologies with the same technology"
+                        );
+                        for (old, new) in replacements.iter() {
+                            let (old_info, new_info) = (old.info(), new.info());
+                            ensure!(p.has_tech(old), "Player doesn't own {old:?}");
+                            ensure!(
+                                old_info.tech_type != TechType::UnitUpgrade,
+                                "Unit upgrades can't be replaced"
+                            );
+                            ensure!(
+                                new_info.tech_type == old_info.tech_type,
+                                "{new:?} is not the same color as {old:?}"
+                            );
+                            ensure!(
+                                new_info.origin == TechOrigin::Base
+                                    && new.is_enabled_in(&expansions),
+                                "{new:?} is not in the technology deck"
+                            );
+                            ensure!(
+                                !p.has_tech(new) || replacements.contains_key(new),
+                                "Player already owns {new:?}"
+                            );
+                        }
+                        for old in replacements.keys() {
+                            p.remove_tech(old);
+                        }
+                        for new in replacements.values() {
+                            p.take_tech(new.clone())?;
+                        }
+                    }
+                    LeaderAction::UnitDsgnFlayesh { planet, tech } => {
+                        let planet_info = planet.info();
+                        let owner = game_state
+                            .players
+                            .values()
+                            .find(|p| p.planets.contains_key(&planet));
+                        let trade_goods = match owner {
+                            Some(owner) => {
+                                owner.planet_resources(&planet) + owner.planet_influence(&planet)
+                            }
+                            None => planet_info.resources + planet_info.influence,
+                        };
+                        let no_attachments = HashSet::new();
+                        let attachments = owner
+                            .and_then(|p| p.planets.get(&planet))
+                            .unwrap_or(&no_attachments);
+                        let specialties = get_planet_tech_specialties(&planet, attachments);
+                        let TechType::Category(category) = tech.info().tech_type else {
+                            bail!("{tech:?} doesn't match the specialty of {planet:?}");
+                        };
+                        ensure!(
+                            specialties.contains(&category),
+                            "{tech:?} doesn't match the specialty of {planet:?}"
+                        );
+                        ensure!(
+                            tech.info().origin == TechOrigin::Base
+                                && tech.is_enabled_in(&game_state.game_settings.expansions),
+                            "{tech:?} is not in the technology deck"
+                        );
+
+                        if game_state.game_settings.track_units {
+                            let location = UnitLocation::Planet(planet.clone());
+                            let system = game_state.map_data.system_of(&location)?;
+                            ensure!(
+                                game_state
+                                    .map_data
+                                    .systems
+                                    .get(&system)
+                                    .is_some_and(|units| units.contains_units_of(&player)),
+                                "{player} has no units in the system of {planet:?}"
+                            );
+                            let others = game_state
+                                .players
+                                .keys()
+                                .filter(|other| **other != player)
+                                .cloned()
+                                .collect::<Vec<_>>();
+                            for other in others {
+                                let units = game_state.map_data.units_at(&other, &location)?;
+                                if !units.is_empty() {
+                                    game_state
+                                        .map_data
+                                        .remove_units(&other, &location, &units)?;
+                                }
+                            }
+                        }
+
+                        let p = game_state.get_current_player()?;
+                        p.trade_goods += trade_goods;
+                        p.take_tech(tech)?;
+                    }
+                    LeaderAction::XxekirGrom { discarded_law } => {
+                        if let Some(law) = discarded_law {
+                            ensure!(
+                                game_state.laws.contains_key(&law),
+                                "Unable to discard law that has not been enacted"
+                            );
+                            game_state.score.handle_law_repealed(&law);
+                            game_state.laws.remove(&law);
+                        }
+                    }
+                    LeaderAction::DannelOfTheTenthCxIII { planets } => {
+                        ensure!(planets.len() <= 3, "Can invade at most 3 planets");
+                        ensure!(
+                            planets.iter().collect::<HashSet<_>>().len() == planets.len(),
+                            "Can't invade the same planet several times"
+                        );
+                        for planet in planets {
+                            ensure!(
+                                planet == Planet::Mirage
+                                    || !matches!(
+                                        System::for_planet(&planet)?.system_type,
+                                        SystemType::HomeSystem(..)
+                                    ),
+                                "{planet:?} is in a home system"
+                            );
+                            ensure!(
+                                !game_state
+                                    .get_current_player()?
+                                    .planets
+                                    .contains_key(&planet),
+                                "Player already controls {planet:?}"
+                            );
+                            game_state.take_planet(&player, planet)?;
+                        }
+                    }
+                    LeaderAction::AiroShirAur { from, to } => {
+                        ensure!(from != to, "Units must be moved to another system");
+                        ensure!(
+                            game_state.map_data.are_adjacent(&from, &to) != Some(false),
+                            "{to:?} is not adjacent to {from:?}"
+                        );
+                        if game_state.game_settings.track_units {
+                            let from = UnitLocation::Space(from);
+                            let to = UnitLocation::Space(to);
+                            ensure!(
+                                game_state.players.keys().any(|other| {
+                                    *other != player
+                                        && game_state.map_data.units_at(other, &to).is_ok_and(
+                                            |units| {
+                                                units
+                                                    .keys()
+                                                    .any(|unit| unit.kind() == UnitKind::Ship)
+                                            },
+                                        )
+                                }),
+                                "{to:?} doesn't contain another player's ships"
+                            );
+                            let units = game_state.map_data.units_at(&player, &from)?;
+                            ensure!(!units.is_empty(), "{player} has no units in {from:?}");
+                            game_state.map_data.remove_units(&player, &from, &units)?;
+                            game_state.map_data.add_units(&player, &to, &units)?;
+                        }
+                    }
+                }
+            }
+
+            match leader {
+                Leader::Hero(Hero::UlTheProgenitor) => {
+                    let p = game_state.get_current_player()?;
+                    let Some(attachments) = p.planets.get_mut(&Planet::Elysium) else {
+                        bail!("Player doesn't control Elysium");
+                    };
+                    attachments.insert(PlanetAttachment::UITheProgenitor);
+                }
+                Leader::Hero(Hero::HeshAndPrit) => {
+                    let mut relic_deck = game_state.relic_deck();
+                    if let Some(relic) = draw_random(&mut relic_deck, &mut seeded_rng(timestamp)) {
+                        game_state.gain_relic(&player, relic)?;
                     }
                 }
+                Leader::Hero(Hero::HarkaLeeds) => {
+                    game_state.get_current_player()?.gain_action_cards(3);
+                }
+                _ => { /* Has no tracked effect, or was handled above */ }
             }
 
+            game_state.get_current_player()?.use_leader(&leader)?;
+
             game_state.action_progress = None;
             game_state.phase = Phase::EndActionTurn;
         }
-        Event::LeaderActionBegin { player, leader } => {
-            game_state.assert_phase(Phase::Action)?;
-            game_state.assert_player_turn(&player)?;
+        Event::UseLeader { player, leader } => {
             ensure!(
-                leader.info().ability_kind() == LeaderAbilityKind::Action,
-                "{}'s ability can't be played as an action",
+                leader.info().ability_kind() != LeaderAbilityKind::Action,
+                "{}'s ability is an action, use LeaderActionBegin instead",
                 leader.info().name(),
             );
 
-            // TODO: the following leaders may need special handling:
-            //   XxekirGrom - discard law from play (sure, absolutely)
-            //   UlTheProgenitor - attach to planet (mhm)
-            //   UnitDsgnFlayesh - take a planet (ok, easy enough)
-            //   DannelOfTheTenthCxIII - take several planets (ehm, what)
-            //   RinTheMastersLegacy - switch out techs (oh god)
-            //   ConservatorProcyon - explore frontier tokens (yes, plural...)
-            //   MathisMathinus - perform a strategic action (WHAT)
-            //   ZeuCxIII - any one player gets to take a tactical acton (WTF)
-            //   HeshAndPrit - draw relic and do <=2 secondary strategy actions (NO! GOD NO!)
-            //
-            // TODO: when implementing this, add a LeaderAction phase
-            //game_state.action_progress =
-            //    Some(ActionPhaseProgress::Leader(LeaderProgress::Nothing {
-            //        leader,
-            //    }));
-            //game_state.phase = Phase::LeaderAction;
-            game_state.phase = Phase::EndActionTurn
-        }
-        Event::LeaderActionCommit { player, leader } => {
-            game_state.assert_phase(Phase::LeaderAction)?;
-            game_state.assert_player_turn(&player)?;
-            let Some(ActionPhaseProgress::Leader(LeaderProgress::Nothing {
-                leader: previous_leader,
-            })) = &game_state.action_progress
-            else {
-                bail!("action_progress not set to Leader, this is a bug");
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist '{player}'");
             };
-            if &leader != previous_leader {
-                bail!("invalid leader {leader:?}, expected {previous_leader:?}");
+            p.use_leader(&leader)?;
+        }
+        Event::UnlockCommander { player, commander } => {
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist '{player}'");
+            };
+            let leader = Leader::Commander(commander);
+            let Some(state) = p.leaders.get_mut(&leader) else {
+                bail!("Player doesn't have the commander {commander:?}");
+            };
+            ensure!(
+                *state == LeaderState::Locked,
+                "Commander {commander:?} is already unlocked"
+            );
+            *state = LeaderState::Unlocked;
+        }
+        Event::UnlockBreakthrough {
+            player,
+            breakthrough,
+        } => {
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist '{player}'");
+            };
+            let Some(state) = p.breakthroughs.get_mut(&breakthrough) else {
+                bail!("Player doesn't have the breakthrough {breakthrough:?}");
+            };
+            ensure!(
+                *state == BreakthroughState::Locked,
+                "Breakthrough {breakthrough:?} is already unlocked"
+            );
+            *state = BreakthroughState::Unlocked;
+        }
+        Event::ExhaustBreakthrough {
+            player,
+            breakthrough,
+        } => {
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist '{player}'");
+            };
+            let Some(state) = p.breakthroughs.get_mut(&breakthrough) else {
+                bail!("Player doesn't have the breakthrough {breakthrough:?}");
+            };
+            ensure!(
+                *state == BreakthroughState::Unlocked,
+                "Breakthrough {breakthrough:?} is not ready, it is {state:?}"
+            );
+            *state = BreakthroughState::Exhausted;
+        }
+        Event::GivePromissoryNote {
+            player,
+            receiver,
+            note,
+        } => {
+            let info = note.info();
+            ensure!(
+                note.is_enabled_in(&game_state.game_settings.expansions),
+                "{} is not enabled in this game",
+                info.name
+            );
+            ensure!(
+                player != receiver,
+                "{player} can't give a promissory note to themselves"
+            );
+            let Some(p) = game_state.players.get(&player) else {
+                bail!("Player doesn't exist '{player}'");
+            };
+            if let Some(faction) = info.faction {
+                ensure!(
+                    p.faction == faction,
+                    "{} belongs to {faction:?}, not {player}",
+                    info.name
+                );
             }
+            ensure!(
+                !game_state
+                    .players
+                    .values()
+                    .any(|p| p.holds_promissory_note(note, &player)),
+                "{player}'s {} is already held by another player",
+                info.name
+            );
+            let Some(p) = game_state.players.get_mut(&receiver) else {
+                bail!("Player doesn't exist '{receiver}'");
+            };
+            let in_play_area = info.usage == PromissoryNoteUsage::PlacedOnReceive;
+            p.promissory_notes.push(HeldPromissoryNote {
+                note,
+                owner: player.clone(),
+                in_play_area,
+            });
 
-            game_state.action_progress = None;
-            game_state.phase = Phase::EndActionTurn;
+            if note == PromissoryNote::SupportForTheThrone {
+                game_state
+                    .score
+                    .support_for_the_throne
+                    .insert(player, receiver);
+            }
+        }
+        Event::PlayPromissoryNote {
+            player,
+            owner,
+            note,
+            planet,
+        } => {
+            let info = note.info();
+            ensure!(
+                planet.is_some() == (info.usage == PromissoryNoteUsage::Attach),
+                "A planet must be provided exactly when playing a promissory note that is attached"
+            );
+            let Some(p) = game_state.players.get(&player) else {
+                bail!("Player doesn't exist '{player}'");
+            };
+            ensure!(
+                p.holds_promissory_note(note, &owner),
+                "{player} doesn't hold {owner}'s {}",
+                info.name
+            );
+            ensure!(
+                !p.has_promissory_note_in_play_area(note, &owner),
+                "{} is already in {player}'s play area",
+                info.name
+            );
+
+            match note {
+                PromissoryNote::PoliticalSecret => {
+                    game_state.assert_phase(Phase::Agenda)?;
+                    let Some(vote) = game_state.agenda.as_mut().and_then(|a| a.vote.as_mut())
+                    else {
+                        bail!("Political Secret can only be played once an agenda is revealed");
+                    };
+                    vote.player_votes.remove(&owner);
+                    vote.blocked_voters.insert(owner.clone());
+                    vote.tally_votes();
+                }
+                PromissoryNote::GiftOfPrescience => {
+                    game_state.assert_phase(Phase::Strategy)?;
+                    game_state.naalu_telepathy = Some(player.clone());
+                }
+                PromissoryNote::Terraform => {
+                    let Some(planet) = planet else {
+                        bail!("Terraform must be attached to a planet");
+                    };
+                    ensure!(
+                        !planet.is_mecatol_rex(),
+                        "Terraform can't be attached to Mecatol Rex"
+                    );
+                    ensure!(
+                        !matches!(
+                            System::for_planet(&planet)?.system_type,
+                            SystemType::HomeSystem(..)
+                        ),
+                        "Terraform can't be attached to a home planet"
+                    );
+                    let Some(attachments) = game_state
+                        .players
+                        .get_mut(&player)
+                        .and_then(|p| p.planets.get_mut(&planet))
+                    else {
+                        bail!("{player} doesn't control {planet:?}");
+                    };
+                    attachments.insert(PlanetAttachment::Terraform);
+                }
+                _ => {}
+            }
+
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist '{player}'");
+            };
+            match info.usage {
+                PromissoryNoteUsage::PlacedOnReceive => {
+                    bail!("{} is placed in the play area when received", info.name)
+                }
+                PromissoryNoteUsage::PlayArea => {
+                    p.promissory_notes
+                        .iter_mut()
+                        .filter(|held| held.note == note && held.owner == owner)
+                        .for_each(|held| held.in_play_area = true);
+                }
+                PromissoryNoteUsage::Return | PromissoryNoteUsage::Attach => {
+                    p.take_promissory_note(note, &owner)?;
+                }
+            }
+        }
+        Event::ReturnPromissoryNote {
+            player,
+            owner,
+            note,
+        } => {
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist '{player}'");
+            };
+            p.take_promissory_note(note, &owner)?;
+
+            match note {
+                PromissoryNote::SupportForTheThrone
+                    if game_state.score.support_for_the_throne.get(&owner) == Some(&player) =>
+                {
+                    game_state.score.support_for_the_throne.remove(&owner);
+                }
+                PromissoryNote::GiftOfPrescience
+                    if game_state.naalu_telepathy.as_ref() == Some(&player) =>
+                {
+                    game_state.naalu_telepathy = None;
+                }
+                _ => {}
+            }
         }
         Event::FrontierCardActionBegin { player, card } => {
             game_state.assert_phase(Phase::Action)?;
@@ -921,10 +2021,11 @@ fn try_update_game_state(
             if let Some(progress) = data {
                 match progress {
                     FrontierCardAction::EnigmaticDevice { tech } => {
-                        game_state.assert_expansion(&tech.info().expansion)?;
+                        game_state.assert_tech_enabled(&tech)?;
 
+                        let validate = game_state.game_settings.validate_prerequisites;
                         let current_player = game_state.get_current_player()?;
-                        current_player.research_tech(tech.clone())?;
+                        current_player.research_tech(tech.clone(), validate)?;
                     }
                     FrontierCardAction::Mirage { system, attachment } => {
                         ensure!(
@@ -970,20 +2071,40 @@ fn try_update_game_state(
                     .any(|p| p.relics.contains(&relic)),
                 "Relic {relic:?} is already owned by another player."
             );
+            ensure!(
+                !game_state
+                    .players
+                    .values()
+                    .any(|p| p.purged_relics.contains(&relic)),
+                "Relic {relic:?} has been purged."
+            );
 
-            match relic {
-                Relic::ShardOfTheThrone => {
-                    ensure!(
-                        game_state.score.shard_of_the_throne.is_none(),
-                        "Shard of the Throne is already assigned to a player? (This is a bug)"
-                    );
-                    game_state.score.shard_of_the_throne = Some(player);
-                }
-                _ => { /* Has no relevant effect at this point */ }
-            }
+            game_state.gain_relic(&player, relic)?;
+            game_state.phase = Phase::EndActionTurn;
+        }
+        Event::GainRelicFragment { player, fragment } => {
+            game_state.assert_expansion(&Expansion::ProphecyOfKings)?;
+
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            *p.relic_fragments.entry(fragment).or_default() += 1;
+        }
+        Event::PurgeRelicFragments { player, fragments } => {
+            game_state.assert_phase(Phase::Action)?;
+            game_state.assert_player_turn(&player)?;
+
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            p.purge_relic_fragments(&fragments)?;
 
-            let player = game_state.get_current_player()?;
-            player.relics.insert(relic);
+            let mut relic_deck = game_state.relic_deck();
+            let Some(relic) = draw_random(&mut relic_deck, &mut seeded_rng(timestamp)) else {
+                bail!("The relic deck is empty");
+            };
+
+            game_state.gain_relic(&player, relic)?;
             game_state.phase = Phase::EndActionTurn;
         }
         Event::RelicActionBegin { player, relic } => {
@@ -1007,6 +2128,12 @@ fn try_update_game_state(
                     .contains(&relic),
                 "Player doesn't own the relic"
             );
+            ensure!(
+                !game_state.players[&player]
+                    .exhausted_relics
+                    .contains(&relic),
+                "Relic {relic:?} is exhausted"
+            );
 
             game_state.action_progress = Some(ActionPhaseProgress::Relic(RelicProgress { relic }));
             game_state.phase = Phase::RelicAction;
@@ -1038,11 +2165,12 @@ fn try_update_game_state(
                 "Data provided doesn't match the card being played."
             );
 
+            let mut supplied_commodities = None;
             if let Some(data) = data {
                 match data {
                     RelicAction::StellarConverter { planet } => {
                         ensure!(
-                            planet != Planet::MecatolRex,
+                            !planet.is_mecatol_rex(),
                             "Cannot use stellar converter on mecatol rex"
                         );
                         ensure!(
@@ -1108,18 +2236,267 @@ fn try_update_game_state(
                         );
                         attachments.insert(PlanetAttachment::NanoForge);
                     }
+                    RelicAction::TheCodex { cards } => {
+                        ensure!(
+                            cards.len() <= 3,
+                            "Can take at most 3 action cards using The Codex"
+                        );
+                        for card in &cards {
+                            game_state.assert_expansion(&card.info().expansion)?;
+                        }
+
+                        let Some(player) = game_state.players.get_mut(&player) else {
+                            bail!("Player doesn't exist?")
+                        };
+                        player.gain_action_cards(cards.len() as u32);
+                    }
+                    RelicAction::JrXs4550 {
+                        player: chosen_player,
+                        placed_structure,
+                    } => {
+                        let Some(chosen_player) = game_state.players.get_mut(&chosen_player) else {
+                            bail!("Chosen player doesn't exist")
+                        };
+
+                        if !placed_structure {
+                            chosen_player.trade_goods += 1;
+                        }
+                    }
+                    RelicAction::DynamisCore { commodities } => {
+                        let faction = game_state.players[&player].faction;
+                        if let Some(known) = faction.commodities() {
+                            ensure!(
+                                commodities == known,
+                                "The commodity value of {faction:?} is {known}"
+                            );
+                        }
+                        supplied_commodities = Some(commodities);
+                    }
+                    RelicAction::TheProphetsTears { .. } => {
+                        bail!("The Prophet's Tears is not played as an action")
+                    }
                 }
             }
 
-            game_state
+            let relic = progress.relic.clone();
+            let player = game_state
                 .players
                 .get_mut(&player)
-                .ok_or_eyre("Player no longer exists? (This is a bug)")?
-                .relics
-                .remove(&progress.relic);
+                .ok_or_eyre("Player no longer exists? (This is a bug)")?;
+
+            if relic == Relic::DynamisCore {
+                let Some(commodities) = supplied_commodities.or(player.faction.commodities())
+                else {
+                    bail!(
+                        "Commodity value of {:?} is unknown and must be provided",
+                        player.faction
+                    )
+                };
+                player.trade_goods += commodities + 2;
+            }
+
+            player.use_relic(&relic)?;
+
+            game_state.action_progress = None;
+            game_state.phase = Phase::EndActionTurn;
+        }
+        Event::UseRelic {
+            player,
+            relic,
+            data,
+        } => {
+            game_state.assert_expansion(&relic.info().expansion)?;
+            ensure!(
+                action_matches_relic(&data, &relic),
+                "Data provided doesn't match the relic being used."
+            );
+
+            match relic.info().play {
+                RelicPlay::TacticalAction => {
+                    game_state.assert_phase(Phase::TacticalAction)?;
+                    game_state.assert_player_turn(&player)?;
+                }
+                RelicPlay::AfterTactical => {
+                    game_state.assert_phase(Phase::EndActionTurn)?;
+                    game_state.assert_player_turn(&player)?;
+                }
+                RelicPlay::Research | RelicPlay::SpendStrategyToken => {
+                    /* Can be used whenever the player researches / spends a token */
+                }
+                play => bail!("Relic {relic:?} ({play:?}) can't be used outside of its own flow"),
+            }
+
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+
+            p.use_relic(&relic)?;
+
+            match data {
+                Some(RelicAction::TheProphetsTears {
+                    draw_action_card: true,
+                }) => {
+                    p.gain_action_cards(1);
+                }
+                Some(RelicAction::TheProphetsTears {
+                    draw_action_card: false,
+                }) => {
+                    p.ignored_prerequisites += 1;
+                }
+                _ => {}
+            }
+        }
+        Event::TechActionBegin { player, tech } => {
+            game_state.assert_phase(Phase::Action)?;
+            game_state.assert_player_turn(&player)?;
+            game_state.assert_expansion(&tech.info().expansion)?;
+            ensure!(
+                tech.info().play == Some(TechPlay::Action),
+                "{} can't be exhausted as an action",
+                tech.info().name
+            );
+            ensure!(
+                game_state.action_progress.is_none(),
+                "Invalid game state, action_progress not None during Action Phase"
+            );
+
+            let current_player = game_state.get_current_player()?;
+            ensure!(
+                current_player.has_tech(&tech),
+                "Player doesn't have technology {tech:?}"
+            );
+            ensure!(
+                !current_player.exhausted_technologies.contains(&tech),
+                "Technology {tech:?} is exhausted"
+            );
+
+            game_state.action_progress = Some(ActionPhaseProgress::Tech(TechProgress { tech }));
+            game_state.phase = Phase::TechAction;
+        }
+        Event::TechActionCommit { player, data } => {
+            game_state.assert_phase(Phase::TechAction)?;
+            game_state.assert_player_turn(&player)?;
+
+            let Some(ActionPhaseProgress::Tech(progress)) = game_state.action_progress.as_ref()
+            else {
+                bail!(
+                    "Invalid state, expected tech progress, got {:?}",
+                    game_state.action_progress
+                )
+            };
+            ensure!(
+                action_matches_tech(&data, &progress.tech),
+                "Data provided doesn't match the technology being exhausted."
+            );
+            let tech = progress.tech.clone();
+
+            if let Some(data) = data {
+                match data {
+                    TechAction::ProductionBiomes { player: other } => {
+                        ensure!(other != player, "Must choose another player");
+                        let Some(other) = game_state.players.get_mut(&other) else {
+                            bail!("Player doesn't exist");
+                        };
+                        other.trade_goods += 2;
+
+                        game_state.get_current_player()?.trade_goods += 4;
+                    }
+                    TechAction::MageonImplants { player: other } => {
+                        ensure!(other != player, "Must choose another player");
+                        let Some(other) = game_state.players.get_mut(&other) else {
+                            bail!("Player doesn't exist");
+                        };
+                        other.discard_action_cards(1);
+
+                        game_state.get_current_player()?.gain_action_cards(1);
+                    }
+                }
+            }
+
+            game_state.get_current_player()?.exhaust_tech(&tech)?;
+
+            game_state.action_progress = None;
+            game_state.phase = Phase::EndActionTurn;
+        }
+        Event::ExhaustTech { player, tech } => {
+            game_state.assert_expansion(&tech.info().expansion)?;
+            ensure!(
+                tech.info().play == Some(TechPlay::Ability),
+                "{} can only be exhausted as an action",
+                tech.info().name
+            );
+
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            p.exhaust_tech(&tech)?;
+
+            match tech {
+                Technology::AiDevelopmentAlgorithm => p.ignored_prerequisites += 1,
+                Technology::InheritanceSystems => p.ignored_prerequisites = u32::MAX,
+                _ => {}
+            }
+        }
+        Event::IgnorePrerequisites { player, count } => {
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            p.ignored_prerequisites = count;
+        }
+        Event::TechnologicalSingularity { player, from, tech } => {
+            ensure!(player != from, "Cannot copy a technology from yourself");
+            let Some(other) = game_state.players.get(&from) else {
+                bail!("Player doesn't exist");
+            };
+            ensure!(
+                other.has_tech(&tech),
+                "Player {from:?} doesn't have technology {tech:?}"
+            );
+
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            ensure!(
+                p.faction == Faction::NekroVirus,
+                "Only the Nekro Virus can use 'Technological Singularity'"
+            );
+            p.take_tech(tech)?;
+        }
+        Event::PlaceValefarAssimilator {
+            player,
+            from,
+            assimilator,
+            tech,
+        } => {
+            ensure!(player != from, "Cannot assimilate your own technology");
+            let Some(other) = game_state.players.get(&from) else {
+                bail!("Player doesn't exist");
+            };
+            ensure!(
+                other.has_tech(&tech),
+                "Player {from:?} doesn't have technology {tech:?}"
+            );
+            let info = tech.info();
+            ensure!(
+                matches!(info.origin, TechOrigin::Faction(..))
+                    && info.tech_type == TechType::UnitUpgrade,
+                "Valefar Assimilators can only be placed on faction unit upgrades"
+            );
 
-            game_state.action_progress = None;
-            game_state.phase = Phase::EndActionTurn;
+            let Some(p) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            ensure!(
+                p.faction == Faction::NekroVirus,
+                "Only the Nekro Virus has Valefar Assimilators"
+            );
+            ensure!(
+                !p.valefar_assimilators
+                    .iter()
+                    .any(|(a, t)| *a != assimilator && *t == tech),
+                "{tech:?} already has an assimilator token"
+            );
+            p.valefar_assimilators.insert(assimilator, tech);
         }
         Event::TakeAnotherTurn { player } => {
             game_state.assert_phase(Phase::EndActionTurn)?;
@@ -1149,8 +2526,14 @@ fn try_update_game_state(
                 "player must use all strategy cards before passing"
             );
 
-            game_state.passed_players.insert(player);
+            game_state.passed_players.insert(player.clone());
             game_state.advance_turn(timestamp)?;
+
+            // The last player to pass ends the action phase.
+            if game_state.phase == Phase::Status {
+                game_state
+                    .add_secret_objective_opportunity(&player, SecretObjective::ProveEndurance);
+            }
         }
         /* Status phase events */
         Event::ScorePublicObjective { player, objective } => {
@@ -1281,6 +2664,16 @@ fn try_update_game_state(
             );
 
             // Reset state
+            for player in game_state.players.values_mut() {
+                player.gain_action_cards(player.status_phase_action_card_draws());
+                player.exhausted_relics.clear();
+                player.exhausted_technologies.clear();
+                player.ready_agents();
+                player.ready_breakthroughs();
+            }
+            for system in game_state.map_data.systems.values_mut() {
+                system.command_tokens.clear();
+            }
             game_state.strategy_card_holders = Default::default();
             game_state.passed_players = Default::default();
             game_state.spent_strategy_cards = Default::default();
@@ -1324,6 +2717,8 @@ fn try_update_game_state(
             );
 
             p.relics.remove(&Relic::TheCrownOfEmphidia);
+            p.exhausted_relics.remove(&Relic::TheCrownOfEmphidia);
+            p.purged_relics.insert(Relic::TheCrownOfEmphidia);
             game_state.score.crown_of_emphidia = Some(player);
         }
         /* Maw of Worlds relic phase */
@@ -1331,7 +2726,7 @@ fn try_update_game_state(
             game_state.assert_phase(Phase::Relics)?;
 
             game_state.assert_expansion(&Relic::MawOfWorlds.info().expansion)?;
-            game_state.assert_expansion(&tech.info().expansion)?;
+            game_state.assert_tech_enabled(&tech)?;
 
             let Some(player) = game_state.players.get_mut(&player) else {
                 bail!("Player doesn't exist");
@@ -1343,7 +2738,7 @@ fn try_update_game_state(
             );
 
             player.take_tech(tech)?;
-            player.relics.remove(&Relic::MawOfWorlds);
+            player.use_relic(&Relic::MawOfWorlds)?;
         }
         Event::CompleteRelicsPhase => {
             game_state.assert_phase(Phase::Relics)?;
@@ -1397,6 +2792,10 @@ fn try_update_game_state(
             let Some(vote) = &mut state.vote else {
                 bail!("no agenda has been revealed yet");
             };
+            ensure!(
+                !vote.blocked_voters.contains(&player),
+                "{player} is not allowed to vote on this agenda"
+            );
 
             if let Some(outcome) = outcome {
                 let kind = AgendaElectKind::from(&outcome);
@@ -1458,6 +2857,34 @@ fn try_update_game_state(
             *extra = extra.saturating_add(value);
         }
         Event::SetCustodians { player } => game_state.score.custodians = player,
+        Event::DrawActionCards { player, count } => {
+            let Some(player) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            player.gain_action_cards(count);
+        }
+        Event::DrawSecretObjective { player } => {
+            let Some(player) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            ensure!(
+                player.secret_objectives_to_draw > 0,
+                "Player may not draw a secret objective"
+            );
+            player.secret_objectives_to_draw -= 1;
+        }
+        Event::DiscardActionCards { player, count } => {
+            let Some(player) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            player.discard_action_cards(count);
+        }
+        Event::SetTradeGoods { player, value } => {
+            let Some(player) = game_state.players.get_mut(&player) else {
+                bail!("Player doesn't exist");
+            };
+            player.trade_goods = value;
+        }
         Event::AddImperial { player, value } => {
             let imperial = game_state.score.imperial.entry(player).or_default();
             *imperial = imperial.saturating_add(value);
@@ -1476,6 +2903,18 @@ fn try_update_game_state(
         Event::ScoreExtraSecretObjective { player, objective } => {
             game_state.assert_expansion(&objective.info().expansion)?;
 
+            let has_opportunity = game_state
+                .secret_objective_opportunities
+                .get_mut(&player)
+                .is_some_and(|opportunities| opportunities.remove(&objective));
+            ensure!(
+                !game_state.game_settings.validate_scoring_windows
+                    || has_opportunity
+                    || objective.can_be_scored_in(game_state.phase),
+                "{objective:?} can only be scored during the {:?} phase",
+                objective.scoring_window()
+            );
+
             for scored in game_state.score.secret_objectives.values() {
                 if scored.contains(&objective) {
                     bail!("secred objective has already been scored");
@@ -1567,7 +3006,7 @@ fn try_update_game_state(
                 bail!("Player does not exist?");
             };
 
-            p.technologies.remove(&tech);
+            p.remove_tech(&tech);
         }
         Event::RepealLaw { law } => {
             if law.info().kind != AgendaKind::Law {
@@ -1626,7 +3065,7 @@ fn try_update_game_state(
                     player.relics.insert(Relic::ShardOfTheThrone);
                 }
 
-                if planet == Planet::MecatolRex && game_state.score.custodians.is_none() {
+                if planet.is_mecatol_rex() && game_state.score.custodians.is_none() {
                     game_state.score.custodians = Some(p.clone())
                 }
 
@@ -1659,7 +3098,7 @@ fn try_update_game_state(
                     );
                 }
                 PlanetAttachment::Terraform => {
-                    ensure!(planet != Planet::MecatolRex, "Cannot terraform Mecatol Rex");
+                    ensure!(!planet.is_mecatol_rex(), "Cannot terraform Mecatol Rex");
                     ensure!(
                         !matches!(
                             System::for_planet(&planet)?.system_type,
@@ -1711,6 +3150,15 @@ fn try_update_game_state(
             attachments.remove(&attachment);
         }
 
+        Event::RevealFracture => {
+            game_state.assert_expansion(&Expansion::ThundersEdge)?;
+            ensure!(
+                !game_state.map_data.fracture_in_play,
+                "The Fracture has already been revealed"
+            );
+            game_state.map_data.fracture_in_play = true;
+        }
+
         Event::AddAgendaBegin { agenda } => {
             game_state.assert_expansion(&agenda.info().expansion)?;
             ensure!(game_state.agenda_override_state.is_none(), "There is already an agenda override in progress, cancel or resolve that before starting a new one.");
@@ -1788,7 +3236,337 @@ fn try_update_game_state(
 
     // TODO: maybe not recalculate this all the time?
     game_state.update_available_leaders();
-    game_state.score.update_player_points(&game_state.players);
+    game_state
+        .score
+        .update_player_points(&game_state.players, game_state.map_data.fracture_in_play);
+
+    Ok(())
+}
+
+/// Cancel the most recently played action card, rolling back any effect it has had on the game.
+/// Explore a planet taken during the ongoing tactical action, drawing a card from the exploration
+/// deck matching the planet's trait and resolving it.
+fn explore_planet(
+    game_state: &mut GameState,
+    planet: Planet,
+    planet_trait: Option<PlanetTrait>,
+    timestamp: DateTime<Utc>,
+) -> Result<()> {
+    {
+        let Some(ActionPhaseProgress::Tactical(tactical)) = &game_state.action_progress else {
+            bail!(
+                "Invalid game state, expected tactical action, got {:?}",
+                game_state.action_progress
+            );
+        };
+
+        let Some(taken_from) = tactical.taken_planets.get(&planet) else {
+            bail!("Can only explore planet taken this turn")
+        };
+
+        ensure!(
+            taken_from.is_none(),
+            "Cannot explore planet that was taken from another player"
+        );
+
+        ensure!(
+            !tactical.planet_attachments.contains_key(&planet)
+                && !tactical.explored_planets.contains_key(&planet),
+            "Planet has already been explored this turn!"
+        );
+    }
+
+    let current_player = game_state.get_current_player()?;
+    let Some(attachments) = current_player.planets.get(&planet) else {
+        bail!("Player doesn't have planet that they just took, this is a bug!");
+    };
+    let planet_traits = get_planet_traits(&planet, attachments);
+
+    // Thunder's Edge has no traits, but can be explored using any of the exploration decks.
+    let any_deck = planet == Planet::ThundersEdge;
+    let planet_trait = match planet_trait {
+        Some(t) => {
+            ensure!(
+                any_deck || planet_traits.contains(&t),
+                "Planet does not have the trait {t:?}"
+            );
+            t
+        }
+        None => match planet_traits.as_slice() {
+            [t] => t.clone(),
+            [] if any_deck => bail!("Must choose which deck to draw from to explore {planet:?}"),
+            [] => bail!("Planet has no trait and cannot be explored"),
+            _ => bail!("Planet has several traits, must choose which deck to draw from"),
+        },
+    };
+
+    let Some(card) = game_state.draw_exploration_card(&planet_trait, &mut seeded_rng(timestamp))
+    else {
+        bail!("No cards left in the {planet_trait:?} exploration deck");
+    };
+    let effect = card.info().effect;
+    if !effect.leaves_deck() {
+        let Some(deck) = game_state.exploration_decks.get_mut(&planet_trait) else {
+            bail!("Drew a card from an exploration deck that doesn't exist, this is a bug!");
+        };
+        deck.discard(card);
+    }
+
+    let current_player = game_state.get_current_player()?;
+    let attachment = match &effect {
+        ExplorationEffect::Attachment(attachment) => {
+            let actual_attachment = attachment.clone().match_planet(&planet.info());
+            let Some(planet_attachments) = current_player.planets.get_mut(&planet) else {
+                bail!("Player doesn't have planet that they just took, this is a bug!");
+            };
+            planet_attachments.insert(actual_attachment.clone());
+            Some(actual_attachment)
+        }
+        ExplorationEffect::RelicFragment(fragment) => {
+            *current_player.relic_fragments.entry(*fragment).or_default() += 1;
+            None
+        }
+        ExplorationEffect::TradeGoods(trade_goods) => {
+            current_player.trade_goods += *trade_goods;
+            None
+        }
+        ExplorationEffect::GroundForceTradeGoods(trade_goods) => {
+            // Without tracked units the player checks the condition themselves.
+            if !game_state.game_settings.track_units
+                || remove_infantry_unless_mech(game_state, &planet)?
+            {
+                game_state.get_current_player()?.trade_goods += *trade_goods;
+            }
+            None
+        }
+        ExplorationEffect::GammaWormhole => {
+            let system = System::for_planet(&planet)?;
+            game_state.map_data.gamma_wormholes.push(system.id);
+            None
+        }
+        ExplorationEffect::GainUnit | ExplorationEffect::Other => {
+            /* Units and other effects are not tracked, only logged */
+            None
+        }
+    };
+
+    let Some(ActionPhaseProgress::Tactical(tactical)) = &mut game_state.action_progress else {
+        bail!(
+            "Invalid game state, was just tactical action, is now {:?}. This is a bug!",
+            game_state.action_progress
+        );
+    };
+    if let Some(attachment) = attachment {
+        tactical
+            .planet_attachments
+            .insert(planet.clone(), attachment);
+    }
+    tactical.explored_planets.insert(planet, card);
+
+    Ok(())
+}
+
+/// Checks that the current player has a mech on the planet, or else removes one of their infantry
+/// from it. Returns false if the player has neither.
+fn remove_infantry_unless_mech(game_state: &mut GameState, planet: &Planet) -> Result<bool> {
+    let player = game_state.current_player()?;
+    let location = UnitLocation::Planet(planet.clone());
+    let system = game_state.map_data.system_of(&location)?;
+    let count = |unit: Unit| {
+        game_state
+            .map_data
+            .systems
+            .get(&system)
+            .and_then(|units| units.planets.get(planet))
+            .and_then(|players| players.get(&player))
+            .and_then(|units| units.get(&unit))
+            .copied()
+            .unwrap_or_default()
+    };
+
+    if count(Unit::Mech) > 0 {
+        return Ok(true);
+    }
+    if count(Unit::Infantry) == 0 {
+        return Ok(false);
+    }
+
+    let infantry = [(Unit::Infantry, 1)].into_iter().collect();
+    game_state
+        .map_data
+        .remove_units(&player, &location, &infantry)?;
+    Ok(true)
+}
+
+/// Places the starting units of every player in their home system, ships in the space area and
+/// everything else on the first of their home planets. Factions whose starting units aren't known
+/// place them with [Event::PlaceUnits] instead.
+fn place_starting_units(game_state: &mut GameState) -> Result<()> {
+    let mut placements = Vec::new();
+    for (id, player) in game_state.players.iter() {
+        let Some(units) = player.faction.setup().starting_units else {
+            continue;
+        };
+        let home_system = player.home_system()?;
+        let Some(planet) = home_system
+            .planets
+            .iter()
+            .find(|planet| player.planets.contains_key(planet))
+        else {
+            bail!("{id} has no home planets to place their starting units on");
+        };
+
+        let (ships, others): (Vec<_>, Vec<_>) = units
+            .iter()
+            .map(|(unit, count)| (*unit, *count))
+            .partition(|(unit, _)| unit.kind() == UnitKind::Ship);
+        for (location, units) in [
+            (UnitLocation::Space(home_system.id.clone()), ships),
+            (UnitLocation::Planet(planet.clone()), others),
+        ] {
+            if !units.is_empty() {
+                placements.push((id.clone(), location, units.into_iter().collect()));
+            }
+        }
+    }
+
+    for (player, location, units) in placements {
+        game_state.map_data.add_units(&player, &location, &units)?;
+    }
+    Ok(())
+}
+
+/// Checks that the player has units with enough production in the system to produce the units,
+/// and enough resources to pay for them.
+fn assert_can_produce(
+    game_state: &GameState,
+    player_id: &PlayerId,
+    system: &str,
+    units: &EnumMap<Unit, u32>,
+) -> Result<()> {
+    let Some(player) = game_state.players.get(player_id) else {
+        bail!("Player doesn't exist '{player_id}'");
+    };
+
+    let mut cost = 0;
+    for (unit, count) in units.iter() {
+        let Some(stats) = player.unit_stats(unit) else {
+            bail!(
+                "The stats of the {unit:?} of {:?} are not known",
+                player.faction
+            );
+        };
+        let Some(unit_cost) = stats.cost else {
+            bail!("{unit:?} can't be produced");
+        };
+        cost += count.div_ceil(stats.units_per_cost) * unit_cost;
+    }
+
+    let production: u32 = game_state
+        .map_data
+        .systems
+        .get(system)
+        .into_iter()
+        .flat_map(|units| {
+            let space = units.space.get(player_id).map(|units| (None, units));
+            let planets = units.planets.iter().filter_map(|(planet, players)| {
+                players.get(player_id).map(|units| (Some(planet), units))
+            });
+            space.into_iter().chain(planets)
+        })
+        .flat_map(|(planet, units)| units.iter().map(move |(unit, count)| (planet, unit, count)))
+        .filter_map(|(planet, unit, count)| {
+            let value = match player.unit_stats(unit)?.production? {
+                Production::Fixed(value) => value,
+                Production::PlanetResourcesPlus(value) => {
+                    value + planet.map_or(0, |planet| player.planet_resources(planet))
+                }
+            };
+            Some(value * count)
+        })
+        .sum();
+
+    ensure!(
+        production > 0,
+        "{player_id} has no units with production in {system:?}"
+    );
+    let produced: u32 = units.values().sum();
+    ensure!(
+        produced <= production,
+        "Can produce at most {production} units in {system:?}, tried to produce {produced}"
+    );
+    ensure!(
+        cost <= player.spendable_resources(),
+        "Player can't spend {cost} resources"
+    );
+
+    Ok(())
+}
+
+/// Checks that the player has ground forces on the planet they take control of, and destroys the
+/// structures of the previous owner. Any defending ground forces must have been destroyed first.
+fn capture_planet(game_state: &mut GameState, player: &PlayerId, planet: &Planet) -> Result<()> {
+    let location = UnitLocation::Planet(planet.clone());
+    ensure!(
+        game_state.map_data.system_of(&location)? == game_state.activated_system()?,
+        "Can only take planets in the activated system"
+    );
+
+    let ground_forces = |units: &EnumMap<Unit, u32>| {
+        units
+            .keys()
+            .any(|unit| unit.kind() == UnitKind::GroundForce)
+    };
+    ensure!(
+        ground_forces(&game_state.map_data.units_at(player, &location)?),
+        "{player} must have ground forces on {planet:?} to take it"
+    );
+
+    let others = game_state
+        .players
+        .keys()
+        .filter(|other| *other != player)
+        .cloned()
+        .collect::<Vec<_>>();
+    for other in others {
+        let units = game_state.map_data.units_at(&other, &location)?;
+        if units.is_empty() {
+            continue;
+        }
+        ensure!(
+            !ground_forces(&units),
+            "The ground forces of {other} on {planet:?} must be destroyed first"
+        );
+        game_state
+            .map_data
+            .remove_units(&other, &location, &units)?;
+    }
+
+    Ok(())
+}
+
+fn cancel_last_action_card(game_state: &mut GameState) -> Result<()> {
+    let Some(played) = game_state.played_action_cards.last_mut() else {
+        bail!("No action card has been played");
+    };
+    played.cancelled = true;
+    let card = played.card.clone();
+    let effect = played.effect.take();
+
+    if let Some(effect) = effect {
+        effect.undo(game_state);
+    } else if card.info().play == ActionCardPlay::Action {
+        // The action is still spent when an action card played as an action is cancelled.
+        ensure!(
+            matches!(
+                &game_state.action_progress,
+                Some(ActionPhaseProgress::ActionCard(progress)) if progress.card == card
+            ),
+            "{card:?} has already been resolved"
+        );
+        game_state.action_progress = None;
+        game_state.phase = Phase::EndActionTurn;
+    }
 
     Ok(())
 }
@@ -1825,8 +3603,1605 @@ fn should_track_time_in(phase: Phase) -> bool {
         | Phase::LeaderAction
         | Phase::FrontierCardAction
         | Phase::RelicAction
+        | Phase::TechAction
         | Phase::ActionCardAction => true,
 
         Phase::Relics | Phase::Setup | Phase::Status | Phase::Agenda | Phase::Creation => false,
     }
 }
+
+/// Games created before the turn order was validated recorded their action phase turns in an
+/// order that depended on hash map iteration, let a player that starts an action take the turn.
+fn take_legacy_action_turn(game_state: &mut GameState, event: &Event) {
+    let player = match event {
+        Event::TacticalActionBegin { player, .. }
+        | Event::StrategicActionBegin { player, .. }
+        | Event::ActionCardActionBegin { player, .. }
+        | Event::LeaderActionBegin { player, .. }
+        | Event::FrontierCardActionBegin { player, .. }
+        | Event::RelicActionBegin { player, .. }
+        | Event::TechActionBegin { player, .. }
+        | Event::PassAction { player } => player,
+        _ => return,
+    };
+
+    if game_state.phase == Phase::Action && !game_state.passed_players.contains(player) {
+        game_state.current_player = Some(player.clone());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+    use ti_helper_game_data::{
+        common::{
+            color::Color, faction::Faction, game_settings::GameSettings, player_id::PlayerId,
+        },
+        components::{
+            action_card::ActionCard,
+            agenda::{Agenda, AgendaElect},
+            breakthrough::Breakthrough,
+            exploration::ExplorationCard,
+            galactic_event::GalacticEvent,
+            leaders::{Commander, Hero, Leader},
+            objectives::{public::PublicObjective, secret::SecretObjective},
+            phase::Phase,
+            planet::{Planet, PlanetTrait},
+            planet_attachment::PlanetAttachment,
+            promissory_note::PromissoryNote,
+            relic::{Relic, RelicFragment},
+            strategy_card::StrategyCard,
+            tech::{Technology, ValefarAssimilator},
+            unit::Unit,
+        },
+        enum_map::EnumMap,
+    };
+
+    use crate::gameplay::{
+        event::{ActionCardAction, Event, LeaderAction, RelicAction, TechAction},
+        game::Game,
+        game_state::{IonStormSide, UnitLocation},
+        player::{LeaderState, NewPlayer},
+        score::ScorableAgenda,
+        test_util::{
+            action_phase_game, action_phase_game_with_factions, apply, apply_err, apply_seeded,
+            pass_all, play_strategy_cards, player, settings, setup_game, PLAYERS,
+        },
+    };
+
+    fn score_secret(player_name: &str, objective: SecretObjective) -> Event {
+        Event::ScoreExtraSecretObjective {
+            player: player(player_name),
+            objective,
+        }
+    }
+
+    #[test]
+    fn players_act_in_order_of_their_lowest_strategy_card() {
+        let bob_begins = || Event::StrategicActionBegin {
+            player: player("Bob"),
+            card: StrategyCard::Diplomacy,
+        };
+
+        let mut game = action_phase_game(settings());
+        let turn_order = &game.current.turn_order;
+        assert_eq!(turn_order, &PLAYERS.map(|(name, ..)| player(name)));
+        let err = apply_err(&mut game, bob_begins());
+        assert!(err.contains("wrong players turn"), "{err}");
+
+        let mut game = action_phase_game(GameSettings {
+            validate_turn_order: false,
+            ..settings()
+        });
+        apply(&mut game, bob_begins());
+        assert_eq!(game.current.current_player, Some(player("Bob")));
+    }
+
+    #[test]
+    fn secret_objectives_are_scored_in_their_scoring_window() {
+        let mut game = action_phase_game(settings());
+
+        let err = apply_err(
+            &mut game,
+            score_secret("Alice", SecretObjective::HoardRawMaterials),
+        );
+        assert!(err.contains("Status"), "{err}");
+
+        apply(
+            &mut game,
+            score_secret("Alice", SecretObjective::DestroyTheirGreatestShip),
+        );
+    }
+
+    #[test]
+    fn last_player_to_pass_can_score_prove_endurance_in_the_status_phase() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        pass_all(&mut game);
+        assert_eq!(game.current.phase, Phase::Status);
+
+        let last = game.current.turn_order.last().cloned().unwrap();
+        let other = game.current.turn_order.first().cloned().unwrap();
+        apply_err(
+            &mut game,
+            score_secret(&other, SecretObjective::ProveEndurance),
+        );
+        apply(
+            &mut game,
+            score_secret(&last, SecretObjective::ProveEndurance),
+        );
+    }
+
+    #[test]
+    fn scoring_windows_are_not_validated_for_games_without_the_setting() {
+        let mut game = action_phase_game(GameSettings {
+            validate_scoring_windows: false,
+            ..settings()
+        });
+
+        apply(
+            &mut game,
+            score_secret("Alice", SecretObjective::HoardRawMaterials),
+        );
+        apply(
+            &mut game,
+            Event::UnscoreSecretObjective {
+                player: player("Alice"),
+                objective: SecretObjective::HoardRawMaterials,
+            },
+        );
+    }
+
+    #[test]
+    fn action_cards_can_be_played_when_the_hand_size_is_not_tracked() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let alice = player("Alice");
+        assert_eq!(game.current.players[&alice].action_cards, 0);
+
+        apply(
+            &mut game,
+            Event::ActionCardActionBegin {
+                player: alice.clone(),
+                card: ActionCard::EconomicInitiative,
+            },
+        );
+        assert_eq!(game.current.players[&alice].action_cards, 0);
+    }
+
+    #[test]
+    fn drawing_above_the_hand_limit_requires_a_discard() {
+        let mut game = action_phase_game(settings());
+        let draw = |name: &str, count| Event::DrawActionCards {
+            player: player(name),
+            count,
+        };
+
+        apply(&mut game, draw("Alice", 9));
+        let alice = &game.current.players[&player("Alice")];
+        assert_eq!(alice.action_cards, 9);
+        assert_eq!(alice.action_cards_to_discard, 2);
+
+        apply(
+            &mut game,
+            Event::DiscardActionCards {
+                player: player("Alice"),
+                count: 2,
+            },
+        );
+        let alice = &game.current.players[&player("Alice")];
+        assert_eq!(alice.action_cards, 7);
+        assert_eq!(alice.action_cards_to_discard, 0);
+    }
+
+    #[test]
+    fn action_cards_with_a_payload_require_it_in_new_games() {
+        let spy = |game: &mut Game, data| {
+            let player = game.current.current_player.clone().unwrap();
+            apply(
+                game,
+                Event::DrawActionCards {
+                    player: player.clone(),
+                    count: 1,
+                },
+            );
+            apply(
+                game,
+                Event::ActionCardActionBegin {
+                    player: player.clone(),
+                    card: ActionCard::Spy,
+                },
+            );
+            game.apply_or_err(Event::ActionCardActionCommit { player, data }, Utc::now())
+        };
+
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        assert!(spy(&mut game, None).is_err());
+
+        let mut game = action_phase_game(GameSettings {
+            validate_action_card_payloads: false,
+            ..settings()
+        });
+        play_strategy_cards(&mut game);
+        assert!(spy(&mut game, None).is_ok());
+    }
+
+    #[test]
+    fn sabotage_undoes_a_resolved_action_card() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let (alice, bob) = (player("Alice"), player("Bob"));
+        apply(
+            &mut game,
+            Event::DrawActionCards {
+                player: bob.clone(),
+                count: 2,
+            },
+        );
+
+        apply(
+            &mut game,
+            Event::ActionCardActionBegin {
+                player: alice.clone(),
+                card: ActionCard::Spy,
+            },
+        );
+        apply(
+            &mut game,
+            Event::ActionCardActionCommit {
+                player: alice.clone(),
+                data: Some(ActionCardAction::Spy {
+                    player: bob.clone(),
+                }),
+            },
+        );
+        assert_eq!(game.current.players[&alice].action_cards, 1);
+        assert_eq!(game.current.players[&bob].action_cards, 1);
+
+        apply(
+            &mut game,
+            Event::PlayActionCard {
+                player: bob.clone(),
+                card: ActionCard::Sabotage,
+                data: None,
+            },
+        );
+        assert_eq!(game.current.players[&alice].action_cards, 0);
+        assert_eq!(game.current.players[&bob].action_cards, 1);
+        assert_eq!(game.current.phase, Phase::EndActionTurn);
+        assert!(game.current.played_action_cards[0].cancelled);
+    }
+
+    #[test]
+    fn sabotage_ends_the_action_of_an_unresolved_action_card() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+
+        apply(
+            &mut game,
+            Event::ActionCardActionBegin {
+                player: player("Alice"),
+                card: ActionCard::EconomicInitiative,
+            },
+        );
+        apply(
+            &mut game,
+            Event::PlayActionCard {
+                player: player("Bob"),
+                card: ActionCard::Sabotage,
+                data: None,
+            },
+        );
+        assert_eq!(game.current.phase, Phase::EndActionTurn);
+        assert!(game.current.action_progress.is_none());
+
+        let err = apply_err(
+            &mut game,
+            Event::ActionCardActionCommit {
+                player: player("Alice"),
+                data: None,
+            },
+        );
+        assert!(err.contains("ActionCardAction"), "{err}");
+    }
+
+    fn ground_forces(game: &Game, planet: Planet, player: &PlayerId) -> u32 {
+        let system = game
+            .current
+            .map_data
+            .system_of(&UnitLocation::Planet(planet.clone()))
+            .unwrap();
+        game.current.map_data.systems[&system]
+            .planets
+            .get(&planet)
+            .and_then(|players| players.get(player))
+            .and_then(|units| units.get(&Unit::Infantry))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn impersonation_lets_the_player_draw_a_secret_objective() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let current = game.current.current_player.clone().unwrap();
+
+        apply(
+            &mut game,
+            Event::ActionCardActionBegin {
+                player: current.clone(),
+                card: ActionCard::Impersonation,
+            },
+        );
+        apply(
+            &mut game,
+            Event::ActionCardActionCommit {
+                player: current.clone(),
+                data: None,
+            },
+        );
+        assert_eq!(game.current.players[&current].secret_objectives_to_draw, 1);
+
+        let draw = Event::DrawSecretObjective {
+            player: current.clone(),
+        };
+        apply(&mut game, draw.clone());
+        assert_eq!(game.current.players[&current].secret_objectives_to_draw, 0);
+        apply_err(&mut game, draw);
+    }
+
+    #[test]
+    fn unexpected_action_removes_a_command_token_from_the_board() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let current = game.current.current_player.clone().unwrap();
+        apply(
+            &mut game,
+            Event::TacticalActionBegin {
+                player: current.clone(),
+                system: Some("26".into()),
+            },
+        );
+        apply(
+            &mut game,
+            Event::TacticalActionCommit {
+                player: current.clone(),
+            },
+        );
+        apply(
+            &mut game,
+            Event::EndTurn {
+                player: current.clone(),
+            },
+        );
+        for _ in 1..PLAYERS.len() {
+            let player = game.current.current_player.clone().unwrap();
+            apply(&mut game, Event::PassAction { player });
+        }
+        assert_eq!(game.current.current_player.as_ref(), Some(&current));
+
+        apply(
+            &mut game,
+            Event::ActionCardActionBegin {
+                player: current.clone(),
+                card: ActionCard::UnexpectedAction,
+            },
+        );
+        let commit = |system: &str| Event::ActionCardActionCommit {
+            player: current.clone(),
+            data: Some(ActionCardAction::UnexpectedAction {
+                system: system.into(),
+            }),
+        };
+        apply_err(&mut game, commit("27"));
+        apply(&mut game, commit("26"));
+        assert!(game.current.map_data.systems["26"]
+            .command_tokens
+            .is_empty());
+    }
+
+    #[test]
+    fn ghost_squad_moves_ground_forces_and_is_undone_by_sabotage() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let current = game.current.current_player.clone().unwrap();
+        let other = game
+            .current
+            .turn_order
+            .iter()
+            .find(|p| **p != current)
+            .cloned()
+            .unwrap();
+        apply(
+            &mut game,
+            Event::TacticalActionBegin {
+                player: current.clone(),
+                system: Some("27".into()),
+            },
+        );
+        let infantry = |count| {
+            [(Unit::Infantry, count)]
+                .into_iter()
+                .collect::<EnumMap<_, _>>()
+        };
+        land_infantry(&mut game, Planet::NewAlbion, 2);
+        land_infantry(&mut game, Planet::Starpoint, 1);
+        for planet in [Planet::NewAlbion, Planet::Starpoint] {
+            apply(
+                &mut game,
+                Event::TacticalActionTakePlanet {
+                    player: current.clone(),
+                    planet,
+                    explore: false,
+                    planet_trait: None,
+                },
+            );
+        }
+
+        apply(
+            &mut game,
+            Event::PlayActionCard {
+                player: current.clone(),
+                card: ActionCard::GhostSquad,
+                data: Some(ActionCardAction::GhostSquad {
+                    from: Planet::NewAlbion,
+                    to: Planet::Starpoint,
+                    units: infantry(1),
+                }),
+            },
+        );
+        assert_eq!(ground_forces(&game, Planet::NewAlbion, &current), 1);
+        assert_eq!(ground_forces(&game, Planet::Starpoint, &current), 2);
+
+        apply(
+            &mut game,
+            Event::PlayActionCard {
+                player: other,
+                card: ActionCard::Sabotage,
+                data: None,
+            },
+        );
+        assert_eq!(ground_forces(&game, Planet::NewAlbion, &current), 2);
+        assert_eq!(ground_forces(&game, Planet::Starpoint, &current), 1);
+    }
+
+    #[test]
+    fn custodians_need_mecatol_rex_activated_and_six_influence() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let current = game.current.current_player.clone().unwrap();
+        let remove = |trade_goods| Event::TacticalActionRemoveCustodians {
+            player: current.clone(),
+            planets: vec![Planet::Jord],
+            trade_goods,
+        };
+        apply(
+            &mut game,
+            Event::SetTradeGoods {
+                player: current.clone(),
+                value: 5,
+            },
+        );
+
+        let err = apply_err(&mut game, remove(4));
+        assert!(err.contains("TacticalAction"), "{err}");
+        apply(
+            &mut game,
+            Event::TacticalActionBegin {
+                player: current.clone(),
+                system: Some("18".into()),
+            },
+        );
+        let err = apply_err(&mut game, remove(3));
+        assert!(err.contains("influence"), "{err}");
+
+        apply(&mut game, remove(4));
+        assert_eq!(game.current.score.custodians, Some(current.clone()));
+        assert_eq!(game.current.players[&current].trade_goods, 1);
+    }
+
+    #[test]
+    fn custodians_cant_be_removed_from_another_system() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let current = game.current.current_player.clone().unwrap();
+        apply(
+            &mut game,
+            Event::SetTradeGoods {
+                player: current.clone(),
+                value: 6,
+            },
+        );
+        apply(
+            &mut game,
+            Event::TacticalActionBegin {
+                player: current.clone(),
+                system: Some("26".into()),
+            },
+        );
+        let err = apply_err(
+            &mut game,
+            Event::TacticalActionRemoveCustodians {
+                player: current,
+                planets: Vec::new(),
+                trade_goods: 6,
+            },
+        );
+        assert!(err.contains("Mecatol Rex"), "{err}");
+    }
+
+    #[test]
+    fn unlocked_commanders_are_available_to_their_owner_and_allies() {
+        let mut game = action_phase_game(settings());
+        let commander = Leader::Commander(Commander::ClaireGibson);
+        let available = |game: &Game, name: &str| {
+            game.current.available_leaders[&player(name)].contains(&commander)
+        };
+        assert!(!available(&game, "Alice"));
+
+        apply(
+            &mut game,
+            Event::UnlockCommander {
+                player: player("Alice"),
+                commander: Commander::ClaireGibson,
+            },
+        );
+        assert!(available(&game, "Alice"));
+        assert!(!available(&game, "Bob"));
+
+        apply(
+            &mut game,
+            give_note("Alice", "Bob", PromissoryNote::Alliance),
+        );
+        assert!(available(&game, "Bob"));
+    }
+
+    /// Unlocks the heroes of the player by scoring three objectives.
+    fn unlock_hero(game: &mut Game, player_name: &str) {
+        for objective in [
+            PublicObjective::DiversifyResearch,
+            PublicObjective::SwayTheCouncil,
+        ] {
+            apply(
+                game,
+                Event::ScoreExtraPublicObjective {
+                    player: player(player_name),
+                    objective: objective.into(),
+                },
+            );
+        }
+        apply(
+            game,
+            score_secret(player_name, SecretObjective::DestroyTheirGreatestShip),
+        );
+    }
+
+    #[test]
+    fn heroes_lock_again_when_an_objective_is_unscored() {
+        let mut game = action_phase_game(settings());
+        let hero = Leader::Hero(Hero::JaceX4thAirLegion);
+        let hero_state = |game: &Game| game.current.players[&player("Alice")].leaders[&hero];
+        assert_eq!(hero_state(&game), LeaderState::Locked);
+
+        unlock_hero(&mut game, "Alice");
+        assert_eq!(hero_state(&game), LeaderState::Unlocked);
+
+        apply(
+            &mut game,
+            Event::UnscoreSecretObjective {
+                player: player("Alice"),
+                objective: SecretObjective::DestroyTheirGreatestShip,
+            },
+        );
+        assert_eq!(hero_state(&game), LeaderState::Locked);
+    }
+
+    #[test]
+    fn ion_storm_is_placed_on_the_chosen_side_and_flipped_by_the_active_player() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let side = |game: &Game| game.current.map_data.ion_storm.as_ref().unwrap().side;
+        let flip = |name: &str| Event::FlipIonStorm {
+            player: player(name),
+        };
+
+        begin_tactical(&mut game, "47");
+        let explore = Event::ExploreFrontier {
+            player: player("Alice"),
+            system: "47".into(),
+            ion_storm_side: Some(IonStormSide::Beta),
+        };
+        // Seed 33 draws the Ion Storm.
+        apply_seeded(&mut game, explore, 33);
+        assert_eq!(side(&game), IonStormSide::Beta);
+
+        apply_err(&mut game, flip("Bob"));
+        apply(&mut game, flip("Alice"));
+        assert_eq!(side(&game), IonStormSide::Alpha);
+
+        apply(
+            &mut game,
+            Event::TacticalActionCommit {
+                player: player("Alice"),
+            },
+        );
+        apply_err(&mut game, flip("Alice"));
+    }
+
+    /// Takes the planet with the current player and explores it, `seed` decides which card is
+    /// drawn.
+    fn take_and_explore(game: &mut Game, planet: Planet, seed: i64) {
+        let player = game.current.current_player.clone().unwrap();
+        let take = Event::TacticalActionTakePlanet {
+            player,
+            planet,
+            explore: true,
+            planet_trait: None,
+        };
+        apply_seeded(game, take, seed);
+    }
+
+    #[test]
+    fn gamma_wormhole_is_placed_and_purged_when_exploring_a_taken_planet() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        begin_tactical(&mut game, "26");
+        land_infantry(&mut game, Planet::Lodor, 1);
+        // Seed 0 draws the Gamma Wormhole.
+        take_and_explore(&mut game, Planet::Lodor, 0);
+
+        assert_eq!(
+            game.current.map_data.gamma_wormholes,
+            vec!["26".to_string()]
+        );
+        let deck = &game.current.exploration_decks[&PlanetTrait::Cultural];
+        assert!(!deck.draw_pile.contains(&ExplorationCard::GammaWormhole));
+        assert!(deck.discard_pile.is_empty());
+    }
+
+    #[test]
+    fn core_mine_removes_an_infantry_from_the_planet() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let alice = begin_tactical(&mut game, "38");
+        land_infantry(&mut game, Planet::Abyz, 1);
+
+        // Seed 1 draws the Core Mine.
+        take_and_explore(&mut game, Planet::Abyz, 1);
+        assert_eq!(game.current.players[&alice].trade_goods, 1);
+        assert_eq!(ground_forces(&game, Planet::Abyz, &alice), 0);
+    }
+
+    #[test]
+    fn only_unexplored_frontier_systems_can_be_explored() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let explore = |system: &str| Event::ExploreFrontier {
+            player: player("Alice"),
+            system: system.into(),
+            ion_storm_side: None,
+        };
+
+        begin_tactical(&mut game, "26");
+        let err = apply_err(&mut game, explore("26"));
+        assert!(err.contains("not a frontier system"), "{err}");
+
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        begin_tactical(&mut game, "47");
+        apply(&mut game, explore("47"));
+        let err = apply_err(&mut game, explore("47"));
+        assert!(err.contains("already been explored"), "{err}");
+    }
+
+    fn units(units: &[(Unit, u32)]) -> EnumMap<Unit, u32> {
+        units.iter().copied().collect()
+    }
+
+    /// Moves infantry of the current player from their home planet to the planet, which must be
+    /// in the activated system.
+    fn land_infantry(game: &mut Game, planet: Planet, count: u32) {
+        let current = game.current.current_player.clone().unwrap();
+        let home_planet = game.current.players[&current]
+            .home_system()
+            .unwrap()
+            .planets
+            .into_iter()
+            .find(|p| ground_forces(game, p.clone(), &current) > 0)
+            .unwrap();
+        let infantry = units(&[(Unit::Infantry, count)]);
+        apply(
+            game,
+            Event::MoveUnits {
+                player: current.clone(),
+                from: UnitLocation::Planet(home_planet),
+                units: infantry.clone(),
+            },
+        );
+        apply(
+            game,
+            Event::LandUnits {
+                player: current,
+                planet,
+                units: infantry,
+            },
+        );
+    }
+
+    fn begin_tactical(game: &mut Game, system: &str) -> PlayerId {
+        let current = game.current.current_player.clone().unwrap();
+        apply(
+            game,
+            Event::TacticalActionBegin {
+                player: current.clone(),
+                system: Some(system.into()),
+            },
+        );
+        current
+    }
+
+    #[test]
+    fn starting_units_are_placed_in_the_home_system() {
+        let game = action_phase_game(settings());
+        let alice = player("Alice");
+        let map_data = &game.current.map_data;
+
+        assert_eq!(
+            map_data
+                .units_at(&alice, &UnitLocation::Space("1".into()))
+                .unwrap(),
+            units(&[(Unit::Carrier, 2), (Unit::Destroyer, 1), (Unit::Fighter, 3)])
+        );
+        assert_eq!(
+            map_data
+                .units_at(&alice, &UnitLocation::Planet(Planet::Jord))
+                .unwrap(),
+            units(&[(Unit::Infantry, 5), (Unit::SpaceDock, 1)])
+        );
+        // Structures and ground forces go on the first home planet.
+        assert_eq!(
+            map_data
+                .units_at(&player("Bob"), &UnitLocation::Planet(Planet::Arretze))
+                .unwrap()
+                .get(&Unit::SpaceDock),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    fn production_is_limited_by_production_and_resources() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let current = begin_tactical(&mut game, "1");
+        assert_eq!(current, player("Alice"));
+        let produce = |location: UnitLocation, produced: &[(Unit, u32)]| Event::ProduceUnits {
+            player: current.clone(),
+            location,
+            units: units(produced),
+        };
+        let space = UnitLocation::Space("1".into());
+
+        // Jord has 4 resources, so the space dock there has a production of 6.
+        let err = apply_err(&mut game, produce(space.clone(), &[(Unit::Fighter, 7)]));
+        assert!(err.contains("at most 6 units"), "{err}");
+        let err = apply_err(&mut game, produce(space.clone(), &[(Unit::Dreadnought, 2)]));
+        assert!(err.contains("8 resources"), "{err}");
+        let err = apply_err(
+            &mut game,
+            produce(UnitLocation::Planet(Planet::Jord), &[(Unit::Pds, 1)]),
+        );
+        assert!(err.contains("can't be produced"), "{err}");
+
+        apply(&mut game, produce(space.clone(), &[(Unit::Fighter, 6)]));
+        assert_eq!(
+            game.current
+                .map_data
+                .units_at(&current, &space)
+                .unwrap()
+                .get(&Unit::Fighter),
+            Some(&9)
+        );
+    }
+
+    #[test]
+    fn units_cant_be_produced_without_production_in_the_system() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let current = begin_tactical(&mut game, "26");
+        let err = apply_err(
+            &mut game,
+            Event::ProduceUnits {
+                player: current,
+                location: UnitLocation::Space("26".into()),
+                units: units(&[(Unit::Carrier, 1)]),
+            },
+        );
+        assert!(err.contains("no units with production"), "{err}");
+    }
+
+    #[test]
+    fn taking_a_planet_needs_ground_forces_and_destroys_the_defenders_structures() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let alice = begin_tactical(&mut game, "16");
+        let bob = player("Bob");
+        let arretze = UnitLocation::Planet(Planet::Arretze);
+        let take = Event::TacticalActionTakePlanet {
+            player: alice.clone(),
+            planet: Planet::Arretze,
+            explore: false,
+            planet_trait: None,
+        };
+
+        let err = apply_err(&mut game, take.clone());
+        assert!(err.contains("must have ground forces"), "{err}");
+
+        apply(
+            &mut game,
+            Event::MoveUnits {
+                player: alice.clone(),
+                from: UnitLocation::Planet(Planet::Jord),
+                units: units(&[(Unit::Infantry, 2)]),
+            },
+        );
+        apply(
+            &mut game,
+            Event::LandUnits {
+                player: alice.clone(),
+                planet: Planet::Arretze,
+                units: units(&[(Unit::Infantry, 2)]),
+            },
+        );
+        let err = apply_err(&mut game, take.clone());
+        assert!(err.contains("must be destroyed first"), "{err}");
+
+        apply(
+            &mut game,
+            Event::CombatResult {
+                winner: alice.clone(),
+                loser: bob.clone(),
+                location: Some(arretze.clone()),
+            },
+        );
+        assert_eq!(
+            game.current.map_data.units_at(&bob, &arretze).unwrap(),
+            units(&[(Unit::SpaceDock, 1)])
+        );
+
+        apply(&mut game, take);
+        assert!(game
+            .current
+            .map_data
+            .units_at(&bob, &arretze)
+            .unwrap()
+            .is_empty());
+        assert!(game.current.players[&alice]
+            .planets
+            .contains_key(&Planet::Arretze));
+        assert!(!game.current.players[&bob]
+            .planets
+            .contains_key(&Planet::Arretze));
+    }
+
+    #[test]
+    fn units_are_only_placed_by_exploration_cards_after_setup() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let current = begin_tactical(&mut game, "26");
+        let place = |unit| Event::PlaceUnits {
+            player: current.clone(),
+            location: UnitLocation::Planet(Planet::Lodor),
+            units: units(&[(unit, 1)]),
+        };
+        land_infantry(&mut game, Planet::Lodor, 1);
+
+        let err = apply_err(&mut game, place(Unit::Infantry));
+        assert!(err.contains("explored with a card"), "{err}");
+        // Seed 2 draws the Mercenary Outfit.
+        take_and_explore(&mut game, Planet::Lodor, 2);
+        let err = apply_err(&mut game, place(Unit::Mech));
+        assert!(err.contains("places 1 Infantry"), "{err}");
+        apply(&mut game, place(Unit::Infantry));
+        assert_eq!(ground_forces(&game, Planet::Lodor, &current), 2);
+    }
+
+    #[test]
+    fn warfare_produces_units_in_the_home_system() {
+        let mut game = action_phase_game(settings());
+        for (name, card) in [
+            ("Alice", StrategyCard::Leadership),
+            ("Bob", StrategyCard::Diplomacy),
+        ] {
+            apply(
+                &mut game,
+                Event::StrategicActionBegin {
+                    player: player(name),
+                    card,
+                },
+            );
+            apply(&mut game, Event::StrategicActionCommit);
+            apply(
+                &mut game,
+                Event::EndTurn {
+                    player: player(name),
+                },
+            );
+        }
+        apply(
+            &mut game,
+            Event::StrategicActionBegin {
+                player: player("Carol"),
+                card: StrategyCard::Warfare,
+            },
+        );
+        let produce = |system: &str| Event::ProduceUnits {
+            player: player("Carol"),
+            location: UnitLocation::Space(system.into()),
+            units: units(&[(Unit::Carrier, 1)]),
+        };
+
+        let err = apply_err(&mut game, produce("1"));
+        assert!(err.contains("home system"), "{err}");
+        apply(&mut game, produce("10"));
+    }
+
+    #[test]
+    fn players_unlock_and_exhaust_their_own_breakthrough() {
+        let mut game = action_phase_game(settings());
+        let unlock = |faction| Event::UnlockBreakthrough {
+            player: player("Alice"),
+            breakthrough: Breakthrough(faction),
+        };
+        let exhaust = Event::ExhaustBreakthrough {
+            player: player("Alice"),
+            breakthrough: Breakthrough(Faction::FederationOfSol),
+        };
+
+        let err = apply_err(&mut game, unlock(Faction::EmiratesOfHacan));
+        assert!(err.contains("doesn't have the breakthrough"), "{err}");
+        let err = apply_err(&mut game, exhaust.clone());
+        assert!(err.contains("not ready"), "{err}");
+
+        apply(&mut game, unlock(Faction::FederationOfSol));
+        apply(&mut game, exhaust.clone());
+        let err = apply_err(&mut game, exhaust);
+        assert!(err.contains("Exhausted"), "{err}");
+    }
+
+    #[test]
+    fn combat_results_need_a_location_when_units_are_tracked() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let alice = begin_tactical(&mut game, "16");
+        let err = apply_err(
+            &mut game,
+            Event::CombatResult {
+                winner: alice,
+                loser: player("Bob"),
+                location: None,
+            },
+        );
+        assert!(err.contains("location is required"), "{err}");
+    }
+
+    #[test]
+    fn exhausted_technologies_cant_be_used_again() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let (alice, bob) = (player("Alice"), player("Bob"));
+        for (player, tech) in [
+            (&alice, Technology::AiDevelopmentAlgorithm),
+            (&bob, Technology::ProductionBiomes),
+        ] {
+            apply(
+                &mut game,
+                Event::AddTechToPlayer {
+                    player: player.clone(),
+                    tech,
+                },
+            );
+        }
+
+        let exhaust = Event::ExhaustTech {
+            player: alice.clone(),
+            tech: Technology::AiDevelopmentAlgorithm,
+        };
+        apply(&mut game, exhaust.clone());
+        assert_eq!(game.current.players[&alice].ignored_prerequisites, 1);
+        let err = apply_err(&mut game, exhaust);
+        assert!(err.contains("is exhausted"), "{err}");
+        apply(
+            &mut game,
+            Event::PassAction {
+                player: alice.clone(),
+            },
+        );
+
+        let begin = Event::TechActionBegin {
+            player: bob.clone(),
+            tech: Technology::ProductionBiomes,
+        };
+        apply(&mut game, begin.clone());
+        apply(
+            &mut game,
+            Event::TechActionCommit {
+                player: bob.clone(),
+                data: Some(TechAction::ProductionBiomes {
+                    player: alice.clone(),
+                }),
+            },
+        );
+        assert_eq!(game.current.players[&alice].trade_goods, 2);
+        assert_eq!(game.current.players[&bob].trade_goods, 4);
+        apply(
+            &mut game,
+            Event::EndTurn {
+                player: bob.clone(),
+            },
+        );
+        apply(
+            &mut game,
+            Event::PassAction {
+                player: player("Carol"),
+            },
+        );
+
+        let err = apply_err(&mut game, begin);
+        assert!(err.contains("is exhausted"), "{err}");
+    }
+
+    #[test]
+    fn the_nekro_virus_copies_technologies_and_assimilates_unit_upgrades() {
+        let factions = [
+            Faction::NekroVirus,
+            Faction::EmiratesOfHacan,
+            Faction::FederationOfSol,
+        ];
+        let mut game = action_phase_game_with_factions(settings(), factions);
+        let copy = |name: &str, from: &str, tech| Event::TechnologicalSingularity {
+            player: player(name),
+            from: player(from),
+            tech,
+        };
+        let err = apply_err(&mut game, copy("Bob", "Carol", Technology::NeuralMotivator));
+        assert!(err.contains("Only the Nekro Virus"), "{err}");
+        let err = apply_err(&mut game, copy("Alice", "Bob", Technology::NeuralMotivator));
+        assert!(err.contains("doesn't have technology"), "{err}");
+        apply(&mut game, copy("Alice", "Bob", Technology::SarweenTools));
+        assert!(game.current.players[&player("Alice")].has_tech(&Technology::SarweenTools));
+        apply_err(&mut game, copy("Alice", "Carol", Technology::SarweenTools));
+
+        apply(
+            &mut game,
+            Event::AddTechToPlayer {
+                player: player("Carol"),
+                tech: Technology::AdvancedCarrierII,
+            },
+        );
+        let assimilate = |assimilator, tech| Event::PlaceValefarAssimilator {
+            player: player("Alice"),
+            from: player("Carol"),
+            assimilator,
+            tech,
+        };
+        let err = apply_err(
+            &mut game,
+            assimilate(ValefarAssimilator::X, Technology::NeuralMotivator),
+        );
+        assert!(err.contains("faction unit upgrades"), "{err}");
+        apply(
+            &mut game,
+            assimilate(ValefarAssimilator::X, Technology::AdvancedCarrierII),
+        );
+        let err = apply_err(
+            &mut game,
+            assimilate(ValefarAssimilator::Y, Technology::AdvancedCarrierII),
+        );
+        assert!(err.contains("already has an assimilator"), "{err}");
+    }
+
+    fn give_note(giver: &str, receiver: &str, note: PromissoryNote) -> Event {
+        Event::GivePromissoryNote {
+            player: player(giver),
+            receiver: player(receiver),
+            note,
+        }
+    }
+
+    #[test]
+    fn support_for_the_throne_scores_until_it_is_returned() {
+        let mut game = action_phase_game(settings());
+        let support = PromissoryNote::SupportForTheThrone;
+        let points = |game: &Game| game.current.score.player_points[&player("Bob")];
+
+        let err = apply_err(&mut game, give_note("Alice", "Alice", support));
+        assert!(err.contains("to themselves"), "{err}");
+        apply(&mut game, give_note("Alice", "Bob", support));
+        assert_eq!(points(&game), 1);
+        let err = apply_err(&mut game, give_note("Alice", "Carol", support));
+        assert!(err.contains("already held"), "{err}");
+
+        apply(
+            &mut game,
+            Event::ReturnPromissoryNote {
+                player: player("Bob"),
+                owner: player("Alice"),
+                note: support,
+            },
+        );
+        assert_eq!(points(&game), 0);
+        assert!(game.current.players[&player("Bob")]
+            .promissory_notes
+            .is_empty());
+    }
+
+    #[test]
+    fn played_promissory_notes_are_returned_or_placed_in_the_play_area() {
+        let mut game = action_phase_game(settings());
+        let err = apply_err(
+            &mut game,
+            give_note("Alice", "Bob", PromissoryNote::TradeConvoys),
+        );
+        assert!(err.contains("belongs to EmiratesOfHacan"), "{err}");
+        apply(
+            &mut game,
+            give_note("Bob", "Alice", PromissoryNote::TradeConvoys),
+        );
+        apply(
+            &mut game,
+            give_note("Alice", "Carol", PromissoryNote::MilitarySupport),
+        );
+        let play = |name: &str, owner: &str, note| Event::PlayPromissoryNote {
+            player: player(name),
+            owner: player(owner),
+            note,
+            planet: None,
+        };
+
+        apply(
+            &mut game,
+            play("Alice", "Bob", PromissoryNote::TradeConvoys),
+        );
+        let alice = &game.current.players[&player("Alice")];
+        assert!(
+            alice.has_promissory_note_in_play_area(PromissoryNote::TradeConvoys, &player("Bob"))
+        );
+        let err = apply_err(
+            &mut game,
+            play("Alice", "Bob", PromissoryNote::TradeConvoys),
+        );
+        assert!(err.contains("already in"), "{err}");
+
+        apply(
+            &mut game,
+            play("Carol", "Alice", PromissoryNote::MilitarySupport),
+        );
+        let carol = &game.current.players[&player("Carol")];
+        assert!(!carol.holds_promissory_note(PromissoryNote::MilitarySupport, &player("Alice")));
+    }
+
+    /// Elects the player for the agenda outside of the agenda phase.
+    fn elect(game: &mut Game, agenda: Agenda, player_name: &str) {
+        apply(game, Event::AddAgendaBegin { agenda });
+        apply(
+            game,
+            Event::AddAgendaResolve {
+                elected_outcome: AgendaElect::Player(player(player_name)),
+            },
+        );
+    }
+
+    #[test]
+    fn shard_of_the_throne_moves_to_the_winner_of_a_combat() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        elect(&mut game, Agenda::ShardOfTheThrone, "Carol");
+        let points = |game: &Game, name: &str| game.current.score.player_points[&player(name)];
+        assert_eq!((points(&game, "Alice"), points(&game, "Carol")), (0, 1));
+
+        let alice = begin_tactical(&mut game, "10");
+        apply(
+            &mut game,
+            Event::CombatResult {
+                winner: alice.clone(),
+                loser: player("Carol"),
+                location: Some(UnitLocation::Space("10".into())),
+            },
+        );
+        assert!(game.current.score.agenda_scores.iter().any(
+            |score| matches!(score, ScorableAgenda::ShardOfTheThrone { player } if *player == alice)
+        ));
+        assert_eq!((points(&game, "Alice"), points(&game, "Carol")), (1, 0));
+    }
+
+    #[test]
+    fn crown_of_emphidia_moves_to_the_player_taking_a_home_planet() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        elect(&mut game, Agenda::TheCrownOfEmphidia, "Carol");
+        let points = |game: &Game, name: &str| game.current.score.player_points[&player(name)];
+
+        let alice = begin_tactical(&mut game, "10");
+        land_infantry(&mut game, Planet::WrenTerra, 1);
+        apply(
+            &mut game,
+            Event::TacticalActionTakePlanet {
+                player: alice.clone(),
+                planet: Planet::WrenTerra,
+                explore: false,
+                planet_trait: None,
+            },
+        );
+        assert!(game.current.score.agenda_scores.iter().any(
+            |score| matches!(score, ScorableAgenda::TheCrownOfEmphidia { player } if *player == alice)
+        ));
+        assert_eq!((points(&game, "Alice"), points(&game, "Carol")), (1, 0));
+    }
+
+    #[test]
+    fn thunders_edge_factions_pick_starting_techs_and_place_their_units() {
+        let mut game = Game::default();
+        apply(
+            &mut game,
+            Event::SetSettings {
+                settings: settings(),
+            },
+        );
+        for (name, faction, color) in [
+            ("Alice", Faction::FederationOfSol, Color::Blue),
+            ("Bob", Faction::CrimsonRebellion, Color::Red),
+            ("Carol", Faction::DeepwroughtScolarate, Color::Green),
+        ] {
+            let player = NewPlayer {
+                name: name.to_string(),
+                faction,
+                color,
+            };
+            apply(&mut game, Event::AddPlayer { player });
+        }
+        apply(&mut game, Event::CreationDone);
+        let setup_techs = |name: &str, technologies: Vec<Technology>| Event::SetupPlayerTechs {
+            player: player(name),
+            technologies,
+        };
+
+        // The starting techs of the Crimson Rebellion aren't known yet.
+        let err = apply_err(
+            &mut game,
+            setup_techs("Bob", vec![Technology::NeuralMotivator]),
+        );
+        assert!(err.contains("initialization"), "{err}");
+        let err = apply_err(
+            &mut game,
+            setup_techs("Carol", vec![Technology::NeuralMotivator]),
+        );
+        assert!(err.contains("Invalid amount"), "{err}");
+        apply(
+            &mut game,
+            setup_techs(
+                "Carol",
+                vec![Technology::SarweenTools, Technology::PlasmaScoring],
+            ),
+        );
+
+        let place = |name: &str| Event::PlaceUnits {
+            player: player(name),
+            location: UnitLocation::Space("26".into()),
+            units: units(&[(Unit::Carrier, 1)]),
+        };
+        let err = apply_err(&mut game, place("Alice"));
+        assert!(err.contains("placed when the game starts"), "{err}");
+        apply(&mut game, place("Bob"));
+    }
+
+    /// Makes the current player land infantry on the planet in the system and end their turn.
+    fn invade(game: &mut Game, system: &str, planet: Planet, infantry: u32) -> PlayerId {
+        let current = begin_tactical(game, system);
+        land_infantry(game, planet, infantry);
+        let commit = Event::TacticalActionCommit {
+            player: current.clone(),
+        };
+        apply(game, commit);
+        apply(
+            game,
+            Event::EndTurn {
+                player: current.clone(),
+            },
+        );
+        current
+    }
+
+    #[test]
+    fn unit_dsgn_flayesh_gains_trade_goods_from_attachments_and_destroys_other_units() {
+        let factions = [
+            Faction::NekroVirus,
+            Faction::EmiratesOfHacan,
+            Faction::BaronyOfLetnev,
+        ];
+        let mut game = action_phase_game_with_factions(settings(), factions);
+        let new_albion = UnitLocation::Planet(Planet::NewAlbion);
+        let alice = begin_tactical(&mut game, "27");
+        land_infantry(&mut game, Planet::NewAlbion, 1);
+        apply(
+            &mut game,
+            Event::TacticalActionTakePlanet {
+                player: alice.clone(),
+                planet: Planet::NewAlbion,
+                explore: false,
+                planet_trait: None,
+            },
+        );
+        apply(
+            &mut game,
+            Event::TacticalActionCommit {
+                player: alice.clone(),
+            },
+        );
+        apply(
+            &mut game,
+            Event::EndTurn {
+                player: alice.clone(),
+            },
+        );
+        let bob = invade(&mut game, "27", Planet::NewAlbion, 2);
+        invade(&mut game, "26", Planet::Lodor, 1);
+
+        unlock_hero(&mut game, "Alice");
+        apply(
+            &mut game,
+            Event::AddPlanetAttachment {
+                player: alice.clone(),
+                planet: Planet::NewAlbion,
+                attachment: PlanetAttachment::BioticResearchFacility,
+            },
+        );
+        apply(
+            &mut game,
+            Event::LeaderActionBegin {
+                player: alice.clone(),
+                leader: Hero::UnitDsgnFlayesh.into(),
+            },
+        );
+        let flayesh = |tech| Event::LeaderActionCommit {
+            player: alice.clone(),
+            leader: Hero::UnitDsgnFlayesh.into(),
+            data: Some(LeaderAction::UnitDsgnFlayesh {
+                planet: Planet::NewAlbion,
+                tech,
+            }),
+        };
+        let err = apply_err(&mut game, flayesh(Technology::SarweenTools));
+        assert!(err.contains("specialty"), "{err}");
+
+        let trade_goods = game.current.players[&alice].trade_goods;
+        apply(&mut game, flayesh(Technology::NeuralMotivator));
+        let p = &game.current.players[&alice];
+        assert!(p.has_tech(&Technology::NeuralMotivator));
+        // New Albion is 1/1 and the research facility adds 1/1 since the planet has a specialty.
+        assert_eq!(p.trade_goods, trade_goods + 4);
+        let map_data = &game.current.map_data;
+        assert!(map_data.units_at(&bob, &new_albion).unwrap().is_empty());
+    }
+
+    #[test]
+    fn airo_shir_aur_moves_units_to_a_system_with_other_ships() {
+        let factions = [
+            Faction::MahactGeneSorcerers,
+            Faction::EmiratesOfHacan,
+            Faction::BaronyOfLetnev,
+        ];
+        let mut game = action_phase_game_with_factions(settings(), factions);
+        let alice = player("Alice");
+        let home = UnitLocation::Space("52".into());
+        let ships = game.current.map_data.units_at(&alice, &home).unwrap();
+        unlock_hero(&mut game, "Alice");
+        apply(
+            &mut game,
+            Event::LeaderActionBegin {
+                player: alice.clone(),
+                leader: Hero::AiroShirAur.into(),
+            },
+        );
+        let airo = |to: &str| Event::LeaderActionCommit {
+            player: alice.clone(),
+            leader: Hero::AiroShirAur.into(),
+            data: Some(LeaderAction::AiroShirAur {
+                from: "52".into(),
+                to: to.into(),
+            }),
+        };
+        let err = apply_err(&mut game, airo("26"));
+        assert!(err.contains("another player's ships"), "{err}");
+
+        // Bob's home system contains his starting fleet.
+        apply(&mut game, airo("16"));
+        let map_data = &game.current.map_data;
+        assert!(map_data.units_at(&alice, &home).unwrap().is_empty());
+        let target = UnitLocation::Space("16".into());
+        assert_eq!(map_data.units_at(&alice, &target).unwrap(), ships);
+    }
+
+    /// Gives Alice, playing `faction`, Dynamis Core and begins playing it on their next turn.
+    fn begin_dynamis_core(faction: Faction) -> (Game, PlayerId) {
+        let factions = [faction, Faction::EmiratesOfHacan, Faction::BaronyOfLetnev];
+        let mut game = action_phase_game_with_factions(settings(), factions);
+        play_strategy_cards(&mut game);
+        let alice = player("Alice");
+        apply(
+            &mut game,
+            Event::GainRelicAction {
+                player: alice.clone(),
+                relic: Relic::DynamisCore,
+            },
+        );
+        apply(
+            &mut game,
+            Event::EndTurn {
+                player: alice.clone(),
+            },
+        );
+        for name in ["Bob", "Carol"] {
+            apply(
+                &mut game,
+                Event::PassAction {
+                    player: player(name),
+                },
+            );
+        }
+        apply(
+            &mut game,
+            Event::RelicActionBegin {
+                player: alice.clone(),
+                relic: Relic::DynamisCore,
+            },
+        );
+        (game, alice)
+    }
+
+    #[test]
+    fn dynamis_core_uses_the_known_commodity_value() {
+        let (mut game, current) = begin_dynamis_core(Faction::FederationOfSol);
+        let trade_goods = game.current.players[&current].trade_goods;
+        let commit = |data| Event::RelicActionCommit {
+            player: current.clone(),
+            data,
+        };
+
+        let err = apply_err(
+            &mut game,
+            commit(Some(RelicAction::DynamisCore { commodities: 3 })),
+        );
+        assert!(err.contains("commodity value"), "{err}");
+        apply(&mut game, commit(None));
+        assert_eq!(game.current.players[&current].trade_goods, trade_goods + 6);
+    }
+
+    #[test]
+    fn dynamis_core_needs_the_commodity_value_of_thunders_edge_factions() {
+        let (mut game, current) = begin_dynamis_core(Faction::CrimsonRebellion);
+        let trade_goods = game.current.players[&current].trade_goods;
+        let commit = |data| Event::RelicActionCommit {
+            player: current.clone(),
+            data,
+        };
+
+        let err = apply_err(&mut game, commit(None));
+        assert!(err.contains("must be provided"), "{err}");
+        apply(
+            &mut game,
+            commit(Some(RelicAction::DynamisCore { commodities: 3 })),
+        );
+        assert_eq!(game.current.players[&current].trade_goods, trade_goods + 5);
+    }
+
+    #[test]
+    fn purging_matching_relic_fragments_draws_a_relic_nobody_owns() {
+        use RelicFragment::*;
+
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let alice = player("Alice");
+        for fragment in [Cultural, Cultural, Unknown] {
+            apply(
+                &mut game,
+                Event::GainRelicFragment {
+                    player: alice.clone(),
+                    fragment,
+                },
+            );
+        }
+        let purge = |fragments: [RelicFragment; 3]| Event::PurgeRelicFragments {
+            player: alice.clone(),
+            fragments: fragments.into(),
+        };
+
+        let err = apply_err(&mut game, purge([Cultural, Hazardous, Unknown]));
+        assert!(err.contains("same type"), "{err}");
+        let err = apply_err(&mut game, purge([Cultural, Cultural, Cultural]));
+        assert!(err.contains("enough Cultural"), "{err}");
+
+        apply(&mut game, purge([Cultural, Unknown, Cultural]));
+        let p = &game.current.players[&alice];
+        assert!(p.relic_fragments.values().all(|count| *count == 0));
+        assert_eq!(p.relics.len(), 1);
+        let relic = p.relics.iter().next().unwrap();
+        assert!(!game.current.relic_deck().contains(relic));
+        assert_eq!(game.current.phase, Phase::EndActionTurn);
+    }
+
+    #[test]
+    fn the_fracture_must_be_revealed_before_its_planets_are_taken() {
+        let mut game = action_phase_game(settings());
+        play_strategy_cards(&mut game);
+        let alice = begin_tactical(&mut game, "F5");
+        land_infantry(&mut game, Planet::ThundersEdge, 1);
+        let take = |planet_trait| Event::TacticalActionTakePlanet {
+            player: alice.clone(),
+            planet: Planet::ThundersEdge,
+            explore: true,
+            planet_trait,
+        };
+
+        let err = apply_err(&mut game, take(Some(PlanetTrait::Hazardous)));
+        assert!(err.contains("has not been revealed"), "{err}");
+
+        apply(&mut game, Event::RevealFracture);
+        let err = apply_err(&mut game, Event::RevealFracture);
+        assert!(err.contains("already been revealed"), "{err}");
+
+        // Thunder's Edge has no traits, the player picks which deck to explore it with.
+        let err = apply_err(&mut game, take(None));
+        assert!(err.contains("Must choose which deck"), "{err}");
+        // Seed 5 draws a Hazardous Relic Fragment.
+        apply_seeded(&mut game, take(Some(PlanetTrait::Hazardous)), 5);
+        assert_eq!(
+            game.current.players[&alice].relic_fragments[&RelicFragment::Hazardous],
+            1
+        );
+    }
+
+    #[test]
+    fn styx_only_scores_while_the_fracture_is_in_play() {
+        let mut game = action_phase_game(settings());
+        let alice = player("Alice");
+        let points = |game: &Game| game.current.score.player_points[&alice];
+        apply(
+            &mut game,
+            Event::SetPlanetOwner {
+                player: Some(alice.clone()),
+                planet: Planet::Styx,
+            },
+        );
+        let before = points(&game);
+
+        apply(&mut game, Event::RevealFracture);
+        assert_eq!(points(&game), before + 1);
+    }
+
+    #[test]
+    fn galactic_events_are_active_from_the_strategy_phase() {
+        let mut game = Game::default();
+        apply(
+            &mut game,
+            Event::SetSettings {
+                settings: settings(),
+            },
+        );
+        let events = vec![
+            GalacticEvent::CivilizedSociety,
+            GalacticEvent::ZealousOrthodoxy,
+        ];
+        let duplicates = vec![GalacticEvent::TotalWar, GalacticEvent::TotalWar];
+        let err = apply_err(&mut game, Event::SetGalacticEvents { events: duplicates });
+        assert!(err.contains("unique"), "{err}");
+
+        let mut game = setup_game(GameSettings {
+            galactic_events: events.clone(),
+            ..settings()
+        });
+        assert!(game.current.active_galactic_events.is_empty());
+        apply(&mut game, Event::StartGame);
+        assert_eq!(game.current.active_galactic_events, events);
+    }
+}
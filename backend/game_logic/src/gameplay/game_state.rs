@@ -5,36 +5,46 @@ use std::{
 
 use chrono::{DateTime, Utc};
 use eyre::{bail, ensure, eyre, Context};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use ts_rs::TS;
 
 use ti_helper_game_data::{
     common::{
-        expansions::Expansion, faction::Faction, game_settings::GameSettings, map::HexMap,
+        expansions::Expansion,
+        faction::{Faction, StartingTechPool},
+        game_settings::GameSettings,
+        map::HexMap,
         player_id::PlayerId,
     },
     components::{
-        action_card::ActionCard,
+        action_card::{ActionCard, ActionCardPlay},
         agenda::{Agenda, AgendaElect, AgendaElectKind, AgendaKind, ForOrAgainst},
+        exploration::ExplorationCard,
         frontier_card::FrontierCard,
-        leaders::{Leader, LeaderAbilityKind},
-        objectives::Objective,
+        galactic_event::GalacticEvent,
+        leaders::{Agent, Hero, Leader, LeaderAbilityKind},
+        objectives::{secret::SecretObjective, Objective},
         phase::Phase,
-        planet::Planet,
+        planet::{Planet, PlanetTrait},
         planet_attachment::PlanetAttachment,
+        promissory_note::PromissoryNote,
         relic::Relic,
         strategy_card::StrategyCard,
-        system::SystemId,
-        tech::Technology,
+        system::{systems, System, SystemId, SystemType, WormHoleType},
+        tech::{TechOrigin, Technology},
+        unit::{Unit, UnitKind},
     },
     enum_map::EnumMap,
 };
 
 use super::{
     agenda::{AgendaRecord, AgendaState, VoteState},
+    deck::Deck,
     error::GameError,
     event::{StrategicPrimaryAction, StrategicSecondaryAction},
-    player::Player,
+    player::{LeaderState, Player},
     score::Score,
     status::StatusPhaseState,
 };
@@ -95,6 +105,10 @@ pub struct GameState {
     /// Laws in play.
     pub laws: EnumMap<Agenda, AgendaElect>,
 
+    /// Galactic events that are currently modifying the game, they are activated at the start of
+    /// each strategy phase.
+    pub active_galactic_events: Vec<GalacticEvent>,
+
     /// State required for the agenda 'admin view'.
     pub agenda_override_state: Option<AgendaOverrideState>,
 
@@ -123,6 +137,23 @@ pub struct GameState {
     ///
     /// This player has initiative 0 in the action and status phase.
     pub naalu_telepathy: Option<PlayerId>,
+
+    /// Secret objectives that players have become eligible to score outside of the status phase
+    /// due to something that happened in the game, e.g. being elected by an agenda.
+    ///
+    /// These are cleared whenever the game moves on to the next phase.
+    pub secret_objective_opportunities: HashMap<PlayerId, HashSet<SecretObjective>>,
+
+    /// The exploration deck for each planet trait.
+    ///
+    /// A deck is created the first time a card is drawn from it.
+    pub exploration_decks: EnumMap<PlanetTrait, Deck<ExplorationCard>>,
+
+    /// The frontier exploration deck, created the first time a frontier token is explored.
+    pub frontier_deck: Option<Deck<FrontierCard>>,
+
+    /// Every action card played this game, in the order they were played.
+    pub played_action_cards: Vec<PlayedActionCard>,
 }
 
 /// Information relevant to things that has happened on the gameboard.
@@ -134,6 +165,243 @@ pub struct MapData {
     pub milty_information: Option<MiltyInformation>,
     /// Which planets (if any) has been destroyed by the stellar converter.
     pub stellar_converter_destroyed_planets: Vec<Planet>,
+    /// The ion storm token, if it has been placed on the board.
+    pub ion_storm: Option<IonStorm>,
+    /// Systems that have had a gamma wormhole token placed in them.
+    pub gamma_wormholes: Vec<SystemId>,
+    /// Systems whose frontier token has been explored.
+    pub explored_frontiers: Vec<SystemId>,
+    /// Systems that have become entropic fields and the frontier card that made them so.
+    pub entropic_fields: EnumMap<SystemId, FrontierCard>,
+    /// The units, structures and command tokens in each system, only tracked if
+    /// [GameSettings::track_units] is set.
+    pub systems: EnumMap<SystemId, SystemUnits>,
+    /// Whether the Fracture from Thunder's Edge has been revealed, bringing its systems into play.
+    pub fracture_in_play: bool,
+}
+
+impl MapData {
+    /// The system that contains the location.
+    pub fn system_of(&self, location: &UnitLocation) -> Result<SystemId, GameError> {
+        Ok(match location {
+            UnitLocation::Space(system) => system.clone(),
+            UnitLocation::Planet(Planet::Mirage) => {
+                let Some(system) = self
+                    .milty_information
+                    .as_ref()
+                    .and_then(|milty| milty.mirage_system.clone())
+                else {
+                    bail!("Mirage has not been placed on the board");
+                };
+                system
+            }
+            UnitLocation::Planet(planet) => System::for_planet(planet)?.id,
+        })
+    }
+
+    /// Whether the two systems are adjacent, either next to each other on the galactic grid or
+    /// connected through wormholes of the same type. Returns `None` if the map isn't known.
+    pub fn are_adjacent(&self, a: &SystemId, b: &SystemId) -> Option<bool> {
+        let hex_map = &self.milty_information.as_ref()?.hex_map;
+        if hex_map.are_neighbors(a, b) {
+            return Some(true);
+        }
+
+        let b = self.wormholes(b);
+        Some(
+            self.wormholes(a)
+                .iter()
+                .any(|wormhole| b.contains(wormhole)),
+        )
+    }
+
+    /// The wormholes in the system, including wormhole tokens placed in it.
+    fn wormholes(&self, system: &SystemId) -> Vec<WormHoleType> {
+        let mut wormholes = systems()
+            .remove(system)
+            .map(|system| system.wormholes)
+            .unwrap_or_default();
+        if self.gamma_wormholes.contains(system) {
+            wormholes.push(WormHoleType::Gamma);
+        }
+        if let Some(ion_storm) = self.ion_storm.as_ref().filter(|ion| &ion.system == system) {
+            wormholes.push(match ion_storm.side {
+                IonStormSide::Alpha => WormHoleType::Alpha,
+                IonStormSide::Beta => WormHoleType::Beta,
+            });
+        }
+
+        wormholes
+    }
+
+    /// The units belonging to the player at the location.
+    pub fn units_at(
+        &self,
+        player: &PlayerId,
+        location: &UnitLocation,
+    ) -> Result<EnumMap<Unit, u32>, GameError> {
+        let system = self.systems.get(&self.system_of(location)?);
+        let units = match location {
+            UnitLocation::Space(_) => system.and_then(|system| system.space.get(player)),
+            UnitLocation::Planet(planet) => system
+                .and_then(|system| system.planets.get(planet))
+                .and_then(|planet| planet.get(player)),
+        };
+        Ok(units.cloned().unwrap_or_default())
+    }
+
+    /// Place units belonging to the player at the location.
+    pub fn add_units(
+        &mut self,
+        player: &PlayerId,
+        location: &UnitLocation,
+        units: &EnumMap<Unit, u32>,
+    ) -> Result<(), GameError> {
+        ensure!(
+            !units.is_empty() && units.values().all(|count| *count > 0),
+            "No units selected"
+        );
+        for unit in units.keys() {
+            match (unit.kind(), location) {
+                (UnitKind::Ship, UnitLocation::Planet(_)) => {
+                    bail!("Ships can't be placed on planets")
+                }
+                (UnitKind::Structure, UnitLocation::Space(_)) => {
+                    bail!("Structures can't be placed in space")
+                }
+                _ => {}
+            }
+        }
+
+        let system_id = self.system_of(location)?;
+        let system = self.systems.entry(system_id).or_default();
+        let placed = match location {
+            UnitLocation::Space(_) => system.space.entry(player.clone()).or_default(),
+            UnitLocation::Planet(planet) => system
+                .planets
+                .entry(planet.clone())
+                .or_default()
+                .entry(player.clone())
+                .or_default(),
+        };
+        for (unit, count) in units.iter() {
+            *placed.entry(*unit).or_default() += count;
+        }
+
+        Ok(())
+    }
+
+    /// Remove units belonging to the player from the location.
+    pub fn remove_units(
+        &mut self,
+        player: &PlayerId,
+        location: &UnitLocation,
+        units: &EnumMap<Unit, u32>,
+    ) -> Result<(), GameError> {
+        ensure!(
+            !units.is_empty() && units.values().all(|count| *count > 0),
+            "No units selected"
+        );
+        let system_id = self.system_of(location)?;
+        let Some(system) = self.systems.get_mut(&system_id) else {
+            bail!("There are no units in {system_id:?}");
+        };
+        let player_units = match location {
+            UnitLocation::Space(_) => system.space.get_mut(player),
+            UnitLocation::Planet(planet) => system
+                .planets
+                .get_mut(planet)
+                .and_then(|planet| planet.get_mut(player)),
+        };
+        let Some(player_units) = player_units else {
+            bail!("{player} has no units at {location:?}");
+        };
+
+        for (unit, count) in units.iter() {
+            let Some(placed) = player_units
+                .get_mut(unit)
+                .filter(|placed| **placed >= *count)
+            else {
+                bail!("{player} doesn't have {count} {unit:?} at {location:?}");
+            };
+            *placed -= count;
+            if *placed == 0 {
+                player_units.remove(unit);
+            }
+        }
+
+        system.space.retain(|_, units| !units.is_empty());
+        for planet_units in system.planets.values_mut() {
+            planet_units.retain(|_, units| !units.is_empty());
+        }
+        system.planets.retain(|_, units| !units.is_empty());
+
+        Ok(())
+    }
+}
+
+/// The ion storm token, placed in a system when the Ion Storm frontier card is explored.
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct IonStorm {
+    /// The system the token is in.
+    pub system: SystemId,
+    /// Which side of the token is face up.
+    pub side: IonStormSide,
+}
+
+/// The side of the ion storm token, which determines what type of wormhole it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[allow(missing_docs)]
+pub enum IonStormSide {
+    Alpha,
+    Beta,
+}
+
+impl IonStormSide {
+    /// The other side of the token.
+    pub fn flipped(self) -> Self {
+        match self {
+            IonStormSide::Alpha => IonStormSide::Beta,
+            IonStormSide::Beta => IonStormSide::Alpha,
+        }
+    }
+}
+
+/// The units, structures and command tokens in a system.
+#[derive(Clone, Default, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemUnits {
+    /// The units in the space area of the system, per player.
+    pub space: HashMap<PlayerId, EnumMap<Unit, u32>>,
+    /// The units and structures on each planet in the system, per player.
+    pub planets: EnumMap<Planet, HashMap<PlayerId, EnumMap<Unit, u32>>>,
+    /// The players that have a command token in the system.
+    pub command_tokens: HashSet<PlayerId>,
+}
+
+impl SystemUnits {
+    /// Returns true if the player has any units in the system, in space or on a planet.
+    pub fn contains_units_of(&self, player: &PlayerId) -> bool {
+        self.space.contains_key(player)
+            || self
+                .planets
+                .values()
+                .any(|players| players.contains_key(player))
+    }
+}
+
+/// Where on the board units are placed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum UnitLocation {
+    /// The space area of a system.
+    Space(SystemId),
+    /// A planet.
+    Planet(Planet),
 }
 
 /// Information relevant to things that has happened on the gameboard.
@@ -173,6 +441,8 @@ pub enum ActionPhaseProgress {
     FrontierCard(FrontierCardProgress),
     /// The progress of a relic action.
     Relic(RelicProgress),
+    /// The progress of a technology action.
+    Tech(TechProgress),
 }
 
 impl ActionPhaseProgress {
@@ -272,12 +542,19 @@ pub enum StrategicSecondaryProgress {
 #[ts(export)]
 pub struct TacticalProgress {
     /// What system was activated, if any.
-    pub activated_system: Option<SystemId>, // TODO: Maybe in the future we should track systems for all tactical actions (Could use some cool interactive map :eyes:)
+    pub activated_system: Option<SystemId>,
     /// Which planets have been taken this far and the player who owned them previously (if any).
     pub taken_planets: EnumMap<Planet, Option<PlayerId>>,
     /// What planet attachments have been selected for the taken planets.
     /// NOTE: Does not include attachments kept when taken from another player.
     pub planet_attachments: EnumMap<Planet, PlanetAttachment>,
+    /// Whether the player spent 6 influence to remove the custodians token from Mecatol Rex
+    /// during this action.
+    pub removed_custodians: bool,
+    /// The exploration cards drawn for the planets explored this far.
+    pub explored_planets: EnumMap<Planet, ExplorationCard>,
+    /// The frontier card drawn when exploring a frontier token during this action, if any.
+    pub explored_frontier: Option<FrontierCard>,
 }
 
 /// The progress of an action card being played.
@@ -289,6 +566,69 @@ pub struct ActionCardProgress {
     pub card: ActionCard,
 }
 
+/// An action card that has been played, either as an action or in response to something.
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct PlayedActionCard {
+    /// The player that played the card.
+    pub player: PlayerId,
+    /// Which card was played.
+    pub card: ActionCard,
+    /// The round the card was played in.
+    pub round: u32,
+    /// The phase the card was played in.
+    pub phase: Phase,
+    /// Weather the card was cancelled, e.g. by "Sabotage".
+    pub cancelled: bool,
+    /// Weather the card was played as an action and that action has been resolved.
+    #[serde(default)]
+    pub resolved: bool,
+    /// The state from before the card was resolved, for cards with an effect, kept so that the
+    /// card can be undone if it is cancelled after it was resolved.
+    #[serde(skip)]
+    #[ts(skip)]
+    pub effect: Option<ActionCardEffect>,
+}
+
+/// The parts of the game state an action card can change when it is resolved, as they were before
+/// the card was resolved.
+#[derive(Clone, Debug)]
+pub struct ActionCardEffect {
+    /// The players before the card was resolved.
+    pub players: HashMap<PlayerId, Player>,
+    /// The score before the card was resolved.
+    pub score: Score,
+    /// The laws in play before the card was resolved.
+    pub laws: EnumMap<Agenda, AgendaElect>,
+    /// The exploration decks before the card was resolved.
+    pub exploration_decks: EnumMap<PlanetTrait, Deck<ExplorationCard>>,
+    /// The map, with the units on the board, before the card was resolved.
+    pub map_data: MapData,
+}
+
+impl ActionCardEffect {
+    /// Records the parts of the game state an action card can change.
+    pub fn record(game_state: &GameState) -> Self {
+        Self {
+            players: game_state.players.clone(),
+            score: game_state.score.clone(),
+            laws: game_state.laws.clone(),
+            exploration_decks: game_state.exploration_decks.clone(),
+            map_data: game_state.map_data.clone(),
+        }
+    }
+
+    /// Restores the game state to how it was before the action card was resolved.
+    pub fn undo(self, game_state: &mut GameState) {
+        game_state.players = self.players;
+        game_state.score = self.score;
+        game_state.laws = self.laws;
+        game_state.exploration_decks = self.exploration_decks;
+        game_state.map_data = self.map_data;
+    }
+}
+
 /// The progress of a leader action being played.
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
@@ -298,6 +638,54 @@ pub struct ActionCardProgress {
 pub enum LeaderProgress {
     /// This leader needs no special handling.
     Nothing { leader: Leader },
+    /// Universities of Jol-Nar hero, replace technologies.
+    RinTheMastersLegacy,
+    /// Nekro Virus hero, gain trade goods and a technology from a planet.
+    UnitDsgnFlayesh,
+    /// Titans of Ul hero, attach to Elysium.
+    UlTheProgenitor,
+    /// Xxcha Kingdom hero, discard a law.
+    XxekirGrom,
+    /// Naaz-Rokha Alliance hero, gain a relic.
+    HeshAndPrit,
+    /// Council Keleres hero, draw action cards.
+    HarkaLeeds,
+    /// Yin Brotherhood hero (Codex III), invade up to 3 planets.
+    DannelOfTheTenthCxIII,
+    /// Mahact Gene-Sorcerers hero, move units into a system with another player's ships.
+    AiroShirAur,
+}
+
+impl LeaderProgress {
+    /// The progress for when the leader starts being played.
+    pub fn new(leader: Leader) -> Self {
+        match leader {
+            Leader::Hero(Hero::RinTheMastersLegacy) => LeaderProgress::RinTheMastersLegacy,
+            Leader::Hero(Hero::UnitDsgnFlayesh) => LeaderProgress::UnitDsgnFlayesh,
+            Leader::Hero(Hero::UlTheProgenitor) => LeaderProgress::UlTheProgenitor,
+            Leader::Hero(Hero::XxekirGrom) => LeaderProgress::XxekirGrom,
+            Leader::Hero(Hero::HeshAndPrit) => LeaderProgress::HeshAndPrit,
+            Leader::Hero(Hero::HarkaLeeds) => LeaderProgress::HarkaLeeds,
+            Leader::Hero(Hero::DannelOfTheTenthCxIII) => LeaderProgress::DannelOfTheTenthCxIII,
+            Leader::Hero(Hero::AiroShirAur) => LeaderProgress::AiroShirAur,
+            leader => LeaderProgress::Nothing { leader },
+        }
+    }
+
+    /// The leader that is being played.
+    pub fn leader(&self) -> Leader {
+        match self {
+            LeaderProgress::Nothing { leader } => *leader,
+            LeaderProgress::RinTheMastersLegacy => Hero::RinTheMastersLegacy.into(),
+            LeaderProgress::UnitDsgnFlayesh => Hero::UnitDsgnFlayesh.into(),
+            LeaderProgress::UlTheProgenitor => Hero::UlTheProgenitor.into(),
+            LeaderProgress::XxekirGrom => Hero::XxekirGrom.into(),
+            LeaderProgress::HeshAndPrit => Hero::HeshAndPrit.into(),
+            LeaderProgress::HarkaLeeds => Hero::HarkaLeeds.into(),
+            LeaderProgress::DannelOfTheTenthCxIII => Hero::DannelOfTheTenthCxIII.into(),
+            LeaderProgress::AiroShirAur => Hero::AiroShirAur.into(),
+        }
+    }
 }
 
 /// The progress of a frontier card being played.
@@ -318,6 +706,15 @@ pub struct RelicProgress {
     pub relic: Relic,
 }
 
+/// The progress of a technology action.
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TechProgress {
+    /// The technology that is exhausted for the action.
+    pub tech: Technology,
+}
+
 impl GameState {
     /// Update the turn order according to initiative order.
     pub fn calculate_action_turn_order(&mut self) -> Result<(), GameError> {
@@ -327,11 +724,13 @@ impl GameState {
 
         // sort players by the smallest number of the strategy cards they hold (initiative order)
         self.turn_order.sort_by_key(|player| {
-            let strategy_card = (self.strategy_card_holders.iter())
-                .find_map(|(card, holder)| (player == holder).then_some(card));
+            let initiative = (self.strategy_card_holders.iter())
+                .filter(|(_card, holder)| player == *holder)
+                .map(|(card, _holder)| card.card_number())
+                .min();
 
             // error out of outer function if player doesn't have a strategy card
-            let Some(strategy_card) = strategy_card else {
+            let Some(initiative) = initiative else {
                 result = Err(eyre!("Player {player:?} does not have a strategy card."));
                 return 99;
             };
@@ -339,7 +738,7 @@ impl GameState {
             if self.naalu_telepathy.as_ref() == Some(player) {
                 0 // Naalu's faction ability/promisary note gives initiative 0.
             } else {
-                strategy_card.card_number()
+                initiative
             }
         });
 
@@ -412,10 +811,20 @@ impl GameState {
                     self.calculate_action_turn_order()?;
                     self.passed_players.clear();
                     self.phase = Phase::Status;
+                    self.secret_objective_opportunities.clear();
                     self.current_turn_start_time = None;
 
+                    let action_cards_to_draw = self
+                        .players
+                        .iter()
+                        .map(|(id, p)| {
+                            let scheming = u32::from(p.faction == Faction::YssarilTribes);
+                            (id.clone(), p.status_phase_action_card_draws() + scheming)
+                        })
+                        .collect();
                     self.status_phase_state = Some(StatusPhaseState::new(
                         self.expected_objectives_before_stage_two(),
+                        action_cards_to_draw,
                     ))
                 }
                 _ => bail!("wtf"),
@@ -434,10 +843,12 @@ impl GameState {
         timestamp: DateTime<Utc>,
     ) -> Result<(), GameError> {
         self.phase = phase;
+        self.secret_objective_opportunities.clear();
         match phase {
             Phase::Strategy => {
-                self.calculate_strategy_turn_order()?;
                 self.round = self.round.saturating_add(1);
+                self.active_galactic_events = self.game_settings.galactic_events.clone();
+                self.calculate_strategy_turn_order()?;
                 self.naalu_telepathy = (self.players.iter())
                     .find(|(_player_id, player)| player.faction == Faction::NaaluCollective)
                     .map(|(player_id, _player)| player_id.clone());
@@ -469,6 +880,212 @@ impl GameState {
         Ok(())
     }
 
+    /// Mark that the provided player has the opportunity to score the secret objective, unless it
+    /// is not part of the game or has already been scored.
+    pub fn add_secret_objective_opportunity(
+        &mut self,
+        player: &PlayerId,
+        objective: SecretObjective,
+    ) {
+        if !self
+            .game_settings
+            .expansions
+            .is_enabled(&objective.info().expansion)
+        {
+            return;
+        }
+
+        let already_scored = self
+            .score
+            .secret_objectives
+            .values()
+            .any(|scored| scored.contains(&objective));
+        if already_scored {
+            return;
+        }
+
+        self.secret_objective_opportunities
+            .entry(player.clone())
+            .or_default()
+            .insert(objective);
+    }
+
+    /// Returns the relics that are left in the relic deck, i.e. relics that are part of the game
+    /// and haven't been gained, purged or attached by any player.
+    pub fn relic_deck(&self) -> Vec<Relic> {
+        let nano_forge_attached = self.players.values().any(|p| {
+            p.planets
+                .values()
+                .any(|attachments| attachments.contains(&PlanetAttachment::NanoForge))
+        });
+
+        Relic::iter()
+            .filter(|relic| {
+                self.game_settings
+                    .expansions
+                    .is_enabled(&relic.info().expansion)
+            })
+            .filter(|relic| {
+                !self
+                    .players
+                    .values()
+                    .any(|p| p.relics.contains(relic) || p.purged_relics.contains(relic))
+            })
+            .filter(|relic| *relic != Relic::NanoForge || !nano_forge_attached)
+            .collect()
+    }
+
+    /// Give the relic to the player, applying any effects of gaining it.
+    pub fn gain_relic(&mut self, player: &PlayerId, relic: Relic) -> Result<(), GameError> {
+        match relic {
+            Relic::ShardOfTheThrone => {
+                ensure!(
+                    self.score.shard_of_the_throne.is_none(),
+                    "Shard of the Throne is already assigned to a player? (This is a bug)"
+                );
+                self.score.shard_of_the_throne = Some(player.clone());
+            }
+            _ => { /* Has no relevant effect at this point */ }
+        }
+
+        let Some(p) = self.players.get_mut(player) else {
+            bail!("Player doesn't exist '{player}'");
+        };
+        p.relics.insert(relic);
+        Ok(())
+    }
+
+    /// Give control of the planet to the player, taking it (and its attachments) from its
+    /// current owner if any. Returns the previous owner of the planet.
+    ///
+    /// This handles the effects of a planet changing owner, i.e. custodians, the Shard of the
+    /// Throne, the Crown of Emphidia agenda and the Become a Martyr secret objective.
+    pub fn take_planet(
+        &mut self,
+        player: &PlayerId,
+        planet: Planet,
+    ) -> Result<Option<PlayerId>, GameError> {
+        ensure!(
+            self.players.contains_key(player),
+            "Player doesn't exist '{player}'"
+        );
+
+        let (current_owner, attachments) = self
+            .players
+            .iter_mut()
+            .find_map(|(id, player)| {
+                if let Some(attachments) = player.planets.remove(&planet) {
+                    return Some((Some(id.clone()), attachments));
+                }
+                None
+            })
+            .unwrap_or((None, HashSet::new()));
+
+        ensure!(
+            planet != Planet::Mirage || current_owner.is_some(),
+            "Mirage must be spawned using the frontier card action"
+        );
+
+        let home_system_of = if planet == Planet::Mirage {
+            None
+        } else {
+            match System::for_planet(&planet)?.system_type {
+                SystemType::HomeSystem(faction) => Some(faction),
+                _ => None,
+            }
+        };
+
+        let mut lost_home_system_planet = false;
+        let shard_of_the_throne = if let Some(current_owner) = current_owner.as_ref() {
+            let Some(p) = self.players.get_mut(current_owner) else {
+                bail!("Player doesn't exist {current_owner:?} (THIS IS A BUG!)");
+            };
+
+            lost_home_system_planet = home_system_of.as_ref() == Some(&p.faction);
+
+            let legendary_attachment = attachments.iter().any(|a| a.info().set_legendary);
+            if planet.info().is_legendary || legendary_attachment || lost_home_system_planet {
+                p.relics.remove(&Relic::ShardOfTheThrone)
+            } else {
+                false
+            }
+        } else {
+            false
+        };
+
+        let Some(new_owner) = self.players.get_mut(player) else {
+            bail!("Player doesn't exist '{player}'");
+        };
+
+        if shard_of_the_throne {
+            new_owner.relics.insert(Relic::ShardOfTheThrone);
+            self.score.shard_of_the_throne = Some(player.clone());
+        }
+
+        new_owner.planets.insert(planet.clone(), attachments);
+
+        // Give the player Custodians if they are the first to take Mecatol Rex
+        if planet.is_mecatol_rex() && self.score.custodians.is_none() {
+            self.score.custodians = Some(player.clone());
+        }
+
+        if let Some(previous_owner) = current_owner.as_ref() {
+            if lost_home_system_planet {
+                self.score
+                    .transfer_crown_of_emphidia(previous_owner, player);
+            }
+
+            if home_system_of.is_some() {
+                self.add_secret_objective_opportunity(
+                    previous_owner,
+                    SecretObjective::BecomeAMartyr,
+                );
+            }
+        }
+
+        Ok(current_owner)
+    }
+
+    /// Draw a random card from the exploration deck of the provided planet trait.
+    pub fn draw_exploration_card(
+        &mut self,
+        planet_trait: &PlanetTrait,
+        rng: &mut impl Rng,
+    ) -> Option<ExplorationCard> {
+        let expansions = &self.game_settings.expansions;
+        let deck = self
+            .exploration_decks
+            .entry(planet_trait.clone())
+            .or_insert_with(|| {
+                Deck::new(
+                    ExplorationCard::iter()
+                        .map(|card| card.info())
+                        .filter(|info| &info.deck == planet_trait)
+                        .filter(|info| expansions.is_enabled(&info.expansion))
+                        .flat_map(|info| std::iter::repeat_n(info.card, info.num_in_deck))
+                        .collect(),
+                )
+            });
+
+        deck.draw(rng)
+    }
+
+    /// Draw a random card from the frontier exploration deck.
+    pub fn draw_frontier_card(&mut self, rng: &mut impl Rng) -> Option<FrontierCard> {
+        let expansions = &self.game_settings.expansions;
+        let deck = self.frontier_deck.get_or_insert_with(|| {
+            Deck::new(
+                FrontierCard::iter()
+                    .map(|card| card.info())
+                    .filter(|info| expansions.is_enabled(&info.expansion))
+                    .flat_map(|info| std::iter::repeat_n(info.card, info.num_in_deck))
+                    .collect(),
+            )
+        });
+
+        deck.draw(rng)
+    }
+
     /// Returns the player after the provided player.
     pub fn next_player_after(&self, after: &PlayerId) -> Result<Option<PlayerId>, GameError> {
         let next_player = self
@@ -514,6 +1131,67 @@ impl GameState {
         Ok(())
     }
 
+    /// The most recently played action card, if it was played in the current round and phase.
+    ///
+    /// An action card played as an action can still be responded to once it has been resolved,
+    /// until the turn ends.
+    pub fn last_played_action_card(&self) -> Option<&PlayedActionCard> {
+        self.played_action_cards.last().filter(|played| {
+            played.round == self.round
+                && (played.phase == self.phase
+                    || (played.resolved && self.phase == Phase::EndActionTurn))
+        })
+    }
+
+    /// Asserts that the provided player can play an action card with the given [ActionCardPlay]
+    /// timing at this point in the game.
+    pub fn assert_action_card_window(
+        &self,
+        player: &PlayerId,
+        play: &ActionCardPlay,
+    ) -> Result<(), GameError> {
+        match play {
+            ActionCardPlay::StartOfPhase(phase) => self.assert_phase(*phase)?,
+            ActionCardPlay::Agenda(_) => {
+                self.assert_phase(Phase::Agenda)?;
+                ensure!(
+                    self.agenda
+                        .as_ref()
+                        .is_some_and(|agenda| agenda.vote.is_some()),
+                    "no agenda has been revealed"
+                );
+            }
+            ActionCardPlay::StatusPhaseReturnStrategyCards => self.assert_phase(Phase::Status)?,
+            ActionCardPlay::AfterAction => {
+                self.assert_phase(Phase::EndActionTurn)?;
+                self.assert_player_turn(player)?;
+            }
+            ActionCardPlay::AfterActionCardIsPlayed => {
+                let Some(played) = self.last_played_action_card() else {
+                    bail!("no action card has been played that can be responded to");
+                };
+                ensure!(
+                    &played.player != player,
+                    "can't respond to your own action card"
+                );
+                ensure!(!played.cancelled, "the action card has been cancelled");
+            }
+            ActionCardPlay::AfterStrategyCardIsPlayed => {
+                self.assert_phase(Phase::StrategicAction)?;
+                ensure!(
+                    self.current_player.as_ref() != Some(player),
+                    "can't respond to your own strategic action"
+                );
+            }
+            ActionCardPlay::StrategyCardIsChosen => self.assert_phase(Phase::Strategy)?,
+            ActionCardPlay::TacticalAction => self.assert_phase(Phase::TacticalAction)?,
+            ActionCardPlay::Action => bail!("card can only be played as an action"),
+            ActionCardPlay::NotImplemented => bail!("card can't be played yet"),
+        }
+
+        Ok(())
+    }
+
     /// Asserts that the provided expansion is enabled.
     pub fn assert_expansion(&self, expansion: &Expansion) -> Result<(), GameError> {
         if !self.game_settings.expansions.is_enabled(expansion) {
@@ -522,6 +1200,37 @@ impl GameState {
         Ok(())
     }
 
+    /// Asserts that units on the board are tracked in this game.
+    pub fn assert_units_tracked(&self) -> Result<(), GameError> {
+        if !self.game_settings.track_units {
+            bail!("Units are not tracked in this game");
+        }
+        Ok(())
+    }
+
+    /// The system activated by the ongoing tactical action.
+    pub fn activated_system(&self) -> Result<SystemId, GameError> {
+        let Some(ActionPhaseProgress::Tactical(tactical)) = &self.action_progress else {
+            bail!(
+                "Invalid game state, expected tactical action, got {:?}",
+                self.action_progress
+            );
+        };
+        let Some(system) = &tactical.activated_system else {
+            bail!("No system has been activated");
+        };
+        Ok(system.clone())
+    }
+
+    /// Asserts that the provided technology is in use with the configured expansions, i.e. that
+    /// its expansion is enabled and that it is the right version for Codex I.
+    pub fn assert_tech_enabled(&self, tech: &Technology) -> Result<(), GameError> {
+        if !tech.is_enabled_in(&self.game_settings.expansions) {
+            bail!("Technology {tech:?} is not in use with the configured expansions");
+        }
+        Ok(())
+    }
+
     /// Asserts that the configured expansions is valid for the provided action.
     pub fn assert_action_expansion(
         &self,
@@ -529,9 +1238,9 @@ impl GameState {
     ) -> Result<(), GameError> {
         match action {
             StrategicPrimaryAction::Technology { tech, extra } => {
-                self.assert_expansion(&tech.info().expansion)?;
+                self.assert_tech_enabled(tech)?;
                 if let Some(t) = extra {
-                    self.assert_expansion(&t.info().expansion)?;
+                    self.assert_tech_enabled(t)?;
                 }
             }
             StrategicPrimaryAction::Imperial { score_objective } => {
@@ -551,15 +1260,15 @@ impl GameState {
     ) -> Result<(), GameError> {
         match action {
             StrategicSecondaryAction::Technology { tech } => {
-                self.assert_expansion(&tech.info().expansion)?;
+                self.assert_tech_enabled(tech)?;
             }
             StrategicSecondaryAction::TechnologyJolNar {
                 first_tech,
                 second_tech,
             } => {
-                self.assert_expansion(&first_tech.info().expansion)?;
+                self.assert_tech_enabled(first_tech)?;
                 if let Some(t) = second_tech {
-                    self.assert_expansion(&t.info().expansion)?;
+                    self.assert_tech_enabled(t)?;
                 }
             }
             StrategicSecondaryAction::Skip => {}
@@ -606,11 +1315,47 @@ impl GameState {
             bail!("player does not exist (this is a bug)");
         };
 
-        Ok(match player.faction {
-            Faction::Winnu => player.technologies.len() == 1,
-            Faction::ArgentFlight => player.technologies.len() == 2,
-            Faction::CouncilKeleres => player.technologies.len() == 2 && !player.planets.is_empty(),
-            _ => true,
+        let setup = player.faction.setup();
+        let techs_picked = setup
+            .techs
+            .is_none_or(|choice| player.technologies.len() == choice.count);
+        let planets_picked = setup.home_system_choices.is_empty() || !player.planets.is_empty();
+
+        Ok(techs_picked && planets_picked)
+    }
+
+    /// Returns the technologies the player may pick from during faction setup.
+    ///
+    /// For [StartingTechPool::Research] this is every enabled technology, prerequisites are
+    /// checked as each technology is researched.
+    pub fn starting_tech_options(
+        &self,
+        player_id: &PlayerId,
+    ) -> Result<Vec<Technology>, GameError> {
+        let Some(player) = self.players.get(player_id) else {
+            bail!("player does not exist (this is a bug)");
+        };
+        let Some(choice) = player.faction.setup().techs else {
+            bail!("Players faction has no technology setup to perform");
+        };
+
+        let expansions = &self.game_settings.expansions;
+        let enabled = Technology::iter().filter(|t| t.is_enabled_in(expansions));
+        Ok(match choice.pool {
+            StartingTechPool::NoPrerequisites => enabled
+                .filter(|t| t.info().requirements.is_empty())
+                .filter(|t| t.info().origin == TechOrigin::Base)
+                .collect(),
+            StartingTechPool::Listed(techs) => techs,
+            StartingTechPool::OtherPlayers => self
+                .players
+                .iter()
+                .filter(|(id, _)| *id != player_id)
+                .flat_map(|(_, p)| p.technologies.iter())
+                .filter(|t| t.info().origin == TechOrigin::Base)
+                .cloned()
+                .collect(),
+            StartingTechPool::Research => enabled.collect(),
         })
     }
 
@@ -619,11 +1364,31 @@ impl GameState {
         self.game_settings.expansions.max_number_of_players()
     }
 
-    /// Update [GameState::available_leaders].
+    /// Unlock heroes for players that have scored 3 objectives, lock them for players that no
+    /// longer have, and update [GameState::available_leaders].
+    ///
+    /// Besides action leaders this includes the players unlocked commanders and the unlocked
+    /// commanders of players whose alliance promissory note the player holds.
     pub fn update_available_leaders(&mut self) {
-        for (player_id, player) in self.players.iter() {
-            let can_play_hero = self.score.scored_objectives_count(player_id) >= 3;
+        // Heroes follow the scored objectives, so they lock again if an objective is unscored
+        for (player_id, player) in self.players.iter_mut() {
+            let hero_state = if self.score.scored_objectives_count(player_id) < 3 {
+                LeaderState::Locked
+            } else {
+                LeaderState::Unlocked
+            };
 
+            player
+                .leaders
+                .iter_mut()
+                .filter(|(leader, state)| {
+                    matches!(leader, Leader::Hero(..))
+                        && matches!(state, LeaderState::Locked | LeaderState::Unlocked)
+                })
+                .for_each(|(_, state)| *state = hero_state);
+        }
+
+        for (player_id, player) in self.players.iter() {
             // Yssaril can play everyones agents
             let yssaril_agent = |leader: &Leader| {
                 if player.faction != Faction::YssarilTribes {
@@ -634,16 +1399,52 @@ impl GameState {
                     return false;
                 }
 
+                let own_agent = Leader::Agent(Agent::CleverCleverSsruu);
+                if !player
+                    .leaders
+                    .get(&own_agent)
+                    .is_some_and(|state| state.is_usable())
+                {
+                    return false;
+                }
+
                 self.players
                     .values()
                     .any(|player| player.faction == leader.info().faction())
             };
 
+            let own_leader = |leader: &Leader| {
+                player
+                    .leaders
+                    .get(leader)
+                    .is_some_and(|state| state.is_usable())
+            };
+
+            // Unlocked commanders are available whether or not their ability is an action
+            let own_commander =
+                |leader: &Leader| matches!(leader, Leader::Commander(..)) && own_leader(leader);
+
+            // Holding another players alliance note gives access to their unlocked commander
+            let alliance_commander = |leader: &Leader| {
+                if !matches!(leader, Leader::Commander(..)) {
+                    return false;
+                }
+
+                player
+                    .promissory_notes
+                    .iter()
+                    .filter(|held| held.note == PromissoryNote::Alliance && held.in_play_area)
+                    .filter_map(|held| self.players.get(&held.owner))
+                    .any(|owner| owner.leaders.get(leader) == Some(&LeaderState::Unlocked))
+            };
+
             let available_to_this_player = Leader::iter()
-                // TODO: there may be cases where players can use other players factions
-                .filter(|leader| leader.info().faction() == player.faction || yssaril_agent(leader))
-                .filter(|leader| leader.info().ability_kind() == LeaderAbilityKind::Action)
-                .filter(|leader| can_play_hero || !matches!(leader, Leader::Hero(..)))
+                .filter(|leader| {
+                    let action = leader.info().ability_kind() == LeaderAbilityKind::Action;
+                    (action && (own_leader(leader) || yssaril_agent(leader)))
+                        || own_commander(leader)
+                        || alliance_commander(leader)
+                })
                 .collect();
 
             self.available_leaders
@@ -680,6 +1481,30 @@ impl GameState {
 
             self.score.add_agenda_record(&agenda_record);
 
+            let elected_player = match outcome {
+                AgendaElect::Player(player) => Some(player.clone()),
+                AgendaElect::Planet(planet)
+                | AgendaElect::PlanetWithTrait(planet)
+                | AgendaElect::CulturalPlanet(planet)
+                | AgendaElect::HazardousPlanet(planet)
+                | AgendaElect::IndustrialPlanet(planet) => self
+                    .players
+                    .iter()
+                    .find(|(_, p)| p.planets.contains_key(planet))
+                    .map(|(id, _)| id.clone()),
+                _ => None,
+            };
+            if let Some(player) = elected_player {
+                self.add_secret_objective_opportunity(&player, SecretObjective::DriveTheDebate);
+            }
+
+            if self.laws.len() >= 3 {
+                let players = self.players.keys().cloned().collect::<Vec<_>>();
+                for player in players {
+                    self.add_secret_objective_opportunity(&player, SecretObjective::DictatePolicy);
+                }
+            }
+
             // TODO: resolve any other vote effects such as VPs or techs
         } else {
             // Do nothing, i.e. discard agenda without resolving it.
@@ -689,3 +1514,54 @@ impl GameState {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use ti_helper_game_data::common::map::{Coordinate, HexMap, HexPosition, Tile};
+
+    use super::{MapData, MiltyInformation};
+
+    fn tile(system: &str, ring: u32, position: u32) -> Tile {
+        Tile {
+            system: system.into(),
+            position: HexPosition::Pos(Coordinate {
+                ring,
+                position,
+                rotation: 0,
+            }),
+        }
+    }
+
+    #[test]
+    fn systems_are_adjacent_on_the_grid_and_through_wormholes() {
+        let adjacent =
+            |map_data: &MapData, a: &str, b: &str| map_data.are_adjacent(&a.into(), &b.into());
+        assert_eq!(adjacent(&MapData::default(), "18", "26"), None);
+
+        let hex_map = HexMap {
+            tiles: vec![
+                tile("18", 0, 0),
+                tile("26", 1, 0),
+                tile("27", 1, 3),
+                tile("25", 3, 3),
+                tile("39", 3, 9),
+            ],
+            ring_count: 3,
+        };
+        let mut map_data = MapData {
+            milty_information: Some(MiltyInformation {
+                hex_map,
+                mirage_system: None,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(adjacent(&map_data, "18", "26"), Some(true));
+        assert_eq!(adjacent(&map_data, "26", "27"), Some(false));
+        // Lodor and Quann have alpha and beta wormholes, system 39 has an alpha wormhole.
+        assert_eq!(adjacent(&map_data, "26", "39"), Some(true));
+        assert_eq!(adjacent(&map_data, "26", "25"), Some(false));
+
+        map_data.gamma_wormholes = vec!["27".into(), "25".into()];
+        assert_eq!(adjacent(&map_data, "27", "25"), Some(true));
+    }
+}
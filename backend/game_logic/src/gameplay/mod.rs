@@ -2,6 +2,8 @@
 pub mod agenda;
 /// Handles assignment of colors to players based on factions.
 pub mod color_assignment;
+/// Drawing cards from server-managed decks.
+pub mod deck;
 /// Game error.
 pub mod error;
 /// Game events.
@@ -18,3 +20,6 @@ pub mod player;
 pub mod score;
 /// Status phase state.
 pub mod status;
+/// Helpers for setting up games in tests.
+#[cfg(test)]
+mod test_util;
@@ -1,18 +1,29 @@
 use std::collections::HashSet;
 
-use eyre::ensure;
+use eyre::{bail, ensure};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 use ti_helper_game_data::{
-    common::{color::Color, faction::Faction, game_settings::Expansions},
+    common::{color::Color, faction::Faction, game_settings::Expansions, player_id::PlayerId},
     components::{
-        planet::Planet, planet_attachment::PlanetAttachment, relic::Relic, tech::Technology,
+        breakthrough::Breakthrough,
+        leaders::{Agent, Leader},
+        planet::Planet,
+        planet_attachment::PlanetAttachment,
+        promissory_note::PromissoryNote,
+        relic::{Relic, RelicFragment, RelicUsage},
+        system::{systems, System, SystemType},
+        tech::{TechCategory, TechOrigin, TechType, Technology, ValefarAssimilator},
+        unit::{Unit, UnitStats},
     },
     enum_map::EnumMap,
 };
 
-use super::error::GameError;
+use super::{agenda::get_planet_tech_specialties, error::GameError};
+
+/// The maximum number of action cards a player may have in their hand.
+pub const ACTION_CARD_HAND_LIMIT: u32 = 7;
 
 /// A new player that is currently being created.
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
@@ -42,8 +53,98 @@ pub struct Player {
     pub planets: EnumMap<Planet, HashSet<PlanetAttachment>>,
     /// Which technologies the player has.
     pub technologies: HashSet<Technology>,
+    /// Which of the players technologies are currently exhausted.
+    pub exhausted_technologies: HashSet<Technology>,
+    /// How many prerequisites the player may ignore for the next technology they research, e.g.
+    /// from "The Prophet's Tears".
+    pub ignored_prerequisites: u32,
     /// Which relics the player currently owns.
     pub relics: HashSet<Relic>,
+    /// Which of the players relics are currently exhausted.
+    pub exhausted_relics: HashSet<Relic>,
+    /// Which relics the player has purged.
+    pub purged_relics: HashSet<Relic>,
+    /// How many relic fragments of each type the player has.
+    pub relic_fragments: EnumMap<RelicFragment, u32>,
+    /// The number of trade goods the player has.
+    pub trade_goods: u32,
+    /// The number of action cards the player has in their hand.
+    ///
+    /// Not every way of drawing action cards is tracked, so this is only advisory.
+    pub action_cards: u32,
+    /// The number of action cards the player has to discard to get down to their hand limit.
+    #[serde(default)]
+    pub action_cards_to_discard: u32,
+    /// The number of secret objectives the player may draw, e.g. from "Impersonation".
+    ///
+    /// Secret objective hands are not tracked, this is a reminder until the player has drawn them.
+    #[serde(default)]
+    pub secret_objectives_to_draw: u32,
+    /// The state of each of the players leaders.
+    pub leaders: EnumMap<Leader, LeaderState>,
+    /// Which faction unit upgrades of other players the Nekro Virus' assimilator tokens are placed
+    /// on.
+    pub valefar_assimilators: EnumMap<ValefarAssimilator, Technology>,
+    /// The state of the players faction breakthrough.
+    pub breakthroughs: EnumMap<Breakthrough, BreakthroughState>,
+    /// Promissory notes of other players that this player holds, in hand or in their play area.
+    pub promissory_notes: Vec<HeldPromissoryNote>,
+}
+
+/// A promissory note that a player has received from another player.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct HeldPromissoryNote {
+    /// Which promissory note it is.
+    pub note: PromissoryNote,
+    /// The player that the promissory note originally belongs to.
+    pub owner: PlayerId,
+    /// Weather the promissory note is face up in the play area, rather than in the players hand.
+    pub in_play_area: bool,
+}
+
+/// The state of a leader owned by a player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum LeaderState {
+    /// An agent that can be used.
+    Ready,
+    /// An agent that has been used, it is readied during the status phase.
+    Exhausted,
+    /// A commander or hero that has not yet been unlocked.
+    Locked,
+    /// A commander or hero that has been unlocked.
+    Unlocked,
+    /// A hero that has been used.
+    Purged,
+}
+
+/// The state of a faction breakthrough owned by a player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum BreakthroughState {
+    /// A breakthrough that has not yet been unlocked.
+    Locked,
+    /// A breakthrough that has been unlocked and can be used.
+    Unlocked,
+    /// A breakthrough that has been exhausted, it is readied during the status phase.
+    Exhausted,
+}
+
+impl LeaderState {
+    /// The state that the leader starts the game in.
+    pub fn initial(leader: &Leader) -> Self {
+        match leader {
+            Leader::Agent(_) => LeaderState::Ready,
+            Leader::Commander(_) | Leader::Hero(_) => LeaderState::Locked,
+        }
+    }
+
+    /// Returns true if a leader in this state can use its ability.
+    pub fn is_usable(&self) -> bool {
+        matches!(self, LeaderState::Ready | LeaderState::Unlocked)
+    }
 }
 
 impl NewPlayer {
@@ -57,13 +158,36 @@ impl NewPlayer {
             .collect();
 
         let techs = self.faction.get_starting_techs(expansions);
+        let leaders = Leader::iter()
+            .filter(|leader| leader.info().faction() == self.faction)
+            .filter(|leader| leader.is_enabled_in(expansions))
+            .map(|leader| (leader, LeaderState::initial(&leader)))
+            .collect();
+        let breakthroughs = [Breakthrough(self.faction)]
+            .into_iter()
+            .filter(|breakthrough| breakthrough.is_enabled_in(expansions))
+            .map(|breakthrough| (breakthrough, BreakthroughState::Locked))
+            .collect();
         Player {
             name: self.name,
             faction: self.faction,
             color: self.color,
             planets,
             technologies: techs,
+            exhausted_technologies: HashSet::new(),
+            ignored_prerequisites: 0,
             relics: HashSet::new(),
+            exhausted_relics: HashSet::new(),
+            purged_relics: HashSet::new(),
+            relic_fragments: EnumMap::new(),
+            trade_goods: 0,
+            action_cards: 0,
+            action_cards_to_discard: 0,
+            secret_objectives_to_draw: 0,
+            leaders,
+            valefar_assimilators: EnumMap::default(),
+            breakthroughs,
+            promissory_notes: Vec::new(),
         }
     }
 }
@@ -74,27 +198,454 @@ impl Player {
         self.planets.remove(planet);
     }
 
-    /// Add a technology to the players technologie list.
+    /// Add a technology to the players technologie list, replacing the generic unit upgrade if
+    /// it is a faction unit upgrade.
     pub fn take_tech(&mut self, tech: Technology) -> Result<(), GameError> {
         ensure!(
             !self.has_tech(&tech),
             "Player {self:?} already has tech {tech:?}"
         );
+
+        // The Nekro Virus assimilates other factions' technologies and may own both a faction
+        // unit upgrade and the generic one it replaces.
+        if self.faction != Faction::NekroVirus {
+            let info = tech.info();
+            if let TechOrigin::Faction(faction) = info.origin {
+                ensure!(
+                    faction == self.faction,
+                    "{tech:?} belongs to {faction:?}, not {:?}",
+                    self.faction
+                );
+            }
+
+            if let Some(replaced) = info.replaces {
+                self.remove_tech(&replaced);
+            }
+
+            if let Some(replacement) = self
+                .technologies
+                .iter()
+                .find(|t| t.replaces().as_ref() == Some(&tech))
+            {
+                bail!("{tech:?} is replaced by {replacement:?}, which the player already owns");
+            }
+        }
+
         self.technologies.insert(tech);
         Ok(())
     }
 
+    /// Remove a technology from the player, it is no longer exhausted if they regain it.
+    pub fn remove_tech(&mut self, tech: &Technology) {
+        self.technologies.remove(tech);
+        self.exhausted_technologies.remove(tech);
+    }
+
     /// Research a technology (for actions stating 'gain', use [`take_tech()`] instead), performing necessary checks for that action.
-    pub fn research_tech(&mut self, tech: Technology) -> Result<(), GameError> {
+    ///
+    /// Prerequisites are only checked if `validate_prerequisites` is set, which is decided by the
+    /// game settings.
+    pub fn research_tech(
+        &mut self,
+        tech: Technology,
+        validate_prerequisites: bool,
+    ) -> Result<(), GameError> {
         ensure!(
             self.faction != Faction::NekroVirus,
             "Nekro Virus cannot research techs"
         );
-        self.take_tech(tech)
+
+        let missing = self.missing_prerequisites(&tech);
+        let missing_count = missing.values().sum::<u32>();
+        ensure!(
+            !validate_prerequisites || missing_count <= self.ignored_prerequisites,
+            "Missing prerequisites for {tech:?}: {:?}",
+            *missing
+        );
+
+        self.take_tech(tech)?;
+        self.ignored_prerequisites = 0;
+        Ok(())
+    }
+
+    /// The prerequisites of each category that the player lacks to research the provided
+    /// technology, after using the technology specialties of their planets as skips.
+    ///
+    /// Planets are not exhausted when their specialty is used, since planet exhaustion isn't
+    /// tracked.
+    pub fn missing_prerequisites(&self, tech: &Technology) -> EnumMap<TechCategory, u32> {
+        tech.info()
+            .requirements
+            .iter()
+            .filter_map(|(category, required)| {
+                let owned = self
+                    .technologies
+                    .iter()
+                    .filter(|t| t.info().tech_type == TechType::Category(category.clone()))
+                    .count() as u32;
+                let skips = self
+                    .planets
+                    .iter()
+                    .flat_map(|(planet, attachments)| {
+                        get_planet_tech_specialties(planet, attachments)
+                    })
+                    .filter(|specialty| specialty == category)
+                    .count() as u32;
+
+                let missing = required.saturating_sub(owned + skips);
+                (missing > 0).then(|| (category.clone(), missing))
+            })
+            .collect()
+    }
+
+    /// Exhaust a technology that the player owns for its exhaust ability.
+    pub fn exhaust_tech(&mut self, tech: &Technology) -> Result<(), GameError> {
+        ensure!(
+            self.has_tech(tech),
+            "Player doesn't have technology {tech:?}"
+        );
+        ensure!(
+            tech.info().play.is_some(),
+            "Technology {tech:?} can't be exhausted"
+        );
+        ensure!(
+            self.exhausted_technologies.insert(tech.clone()),
+            "Technology {tech:?} is exhausted"
+        );
+
+        Ok(())
+    }
+
+    /// Use a relic that the player owns, exhausting or purging it depending on the relic.
+    pub fn use_relic(&mut self, relic: &Relic) -> Result<(), GameError> {
+        ensure!(
+            self.relics.contains(relic),
+            "Player doesn't own the relic {relic:?}"
+        );
+        ensure!(
+            !self.exhausted_relics.contains(relic),
+            "Relic {relic:?} is exhausted"
+        );
+
+        match relic.info().usage {
+            RelicUsage::Exhaust => {
+                self.exhausted_relics.insert(relic.clone());
+            }
+            RelicUsage::Purge => {
+                self.relics.remove(relic);
+                self.purged_relics.insert(relic.clone());
+            }
+            RelicUsage::Attach => {
+                self.relics.remove(relic);
+            }
+            RelicUsage::Passive => bail!("Relic {relic:?} can't be used"),
+        }
+
+        Ok(())
+    }
+
+    /// Use the ability of the leader, exhausting it if it's an agent and purging it if it's a hero.
+    ///
+    /// The Yssaril Tribes may use other players agents, which exhausts their own agent instead.
+    pub fn use_leader(&mut self, leader: &Leader) -> Result<(), GameError> {
+        let leader = match leader {
+            Leader::Agent(_)
+                if !self.leaders.contains_key(leader) && self.faction == Faction::YssarilTribes =>
+            {
+                Leader::Agent(Agent::CleverCleverSsruu)
+            }
+            _ => *leader,
+        };
+
+        let Some(state) = self.leaders.get_mut(&leader) else {
+            bail!("Player doesn't have the leader {leader:?}");
+        };
+        ensure!(
+            state.is_usable(),
+            "Leader {leader:?} can't be used, it is {state:?}"
+        );
+
+        *state = match leader {
+            Leader::Agent(_) => LeaderState::Exhausted,
+            Leader::Hero(_) => LeaderState::Purged,
+            Leader::Commander(_) => bail!("Commanders are not used, they are always active"),
+        };
+
+        Ok(())
+    }
+
+    /// Returns true if the player holds the promissory note of the `owner`, in hand or in their
+    /// play area.
+    pub fn holds_promissory_note(&self, note: PromissoryNote, owner: &PlayerId) -> bool {
+        self.promissory_notes
+            .iter()
+            .any(|held| held.note == note && &held.owner == owner)
+    }
+
+    /// Returns true if the player has the promissory note of the `owner` in their play area.
+    pub fn has_promissory_note_in_play_area(&self, note: PromissoryNote, owner: &PlayerId) -> bool {
+        self.promissory_notes
+            .iter()
+            .any(|held| held.note == note && &held.owner == owner && held.in_play_area)
+    }
+
+    /// Remove the promissory note of the `owner` from the player, returning weather it was in
+    /// their play area.
+    pub fn take_promissory_note(
+        &mut self,
+        note: PromissoryNote,
+        owner: &PlayerId,
+    ) -> Result<bool, GameError> {
+        let Some(index) = self
+            .promissory_notes
+            .iter()
+            .position(|held| held.note == note && &held.owner == owner)
+        else {
+            bail!("{} doesn't hold {owner}'s {note:?}", self.name);
+        };
+
+        Ok(self.promissory_notes.remove(index).in_play_area)
+    }
+
+    /// Ready all of the players exhausted agents.
+    pub fn ready_agents(&mut self) {
+        self.leaders
+            .values_mut()
+            .filter(|state| **state == LeaderState::Exhausted)
+            .for_each(|state| *state = LeaderState::Ready);
+    }
+
+    /// Ready all of the players exhausted breakthroughs.
+    pub fn ready_breakthroughs(&mut self) {
+        self.breakthroughs
+            .values_mut()
+            .filter(|state| **state == BreakthroughState::Exhausted)
+            .for_each(|state| *state = BreakthroughState::Unlocked);
+    }
+
+    /// Purge the provided relic fragments, they must be 3 fragments of the same type (where
+    /// unknown fragments can be used as any type).
+    pub fn purge_relic_fragments(&mut self, fragments: &[RelicFragment]) -> Result<(), GameError> {
+        ensure!(
+            fragments.len() == 3,
+            "Must purge exactly 3 relic fragments, got {}",
+            fragments.len()
+        );
+
+        let mut known_types = fragments
+            .iter()
+            .filter(|f| **f != RelicFragment::Unknown)
+            .collect::<Vec<_>>();
+        known_types.dedup();
+        ensure!(
+            known_types.len() <= 1,
+            "Relic fragments must be of the same type, got {fragments:?}"
+        );
+
+        for fragment in fragments {
+            let count = self.relic_fragments.entry(*fragment).or_default();
+            ensure!(
+                *count > 0,
+                "Player doesn't have enough {fragment:?} fragments"
+            );
+            *count -= 1;
+        }
+
+        Ok(())
     }
 
     /// Returns true if the player currently has the technology.
     pub fn has_tech(&self, tech: &Technology) -> bool {
         self.technologies.contains(tech)
     }
+
+    /// The most resources the player could spend, from their planets and trade goods.
+    ///
+    /// Planet exhaustion isn't tracked, so every planet is counted as ready.
+    pub fn spendable_resources(&self) -> u32 {
+        let planets: u32 = self
+            .planets
+            .keys()
+            .map(|planet| self.planet_resources(planet))
+            .sum();
+        planets + self.trade_goods
+    }
+
+    /// The resource value of one of the players planets, including its attachments.
+    pub fn planet_resources(&self, planet: &Planet) -> u32 {
+        let attachments = self.planets.get(planet).into_iter().flatten();
+        planet.info().resources
+            + attachments
+                .map(|attachment| attachment.info().resources)
+                .sum::<u32>()
+    }
+
+    /// The influence value of one of the players planets, including its attachments.
+    pub fn planet_influence(&self, planet: &Planet) -> u32 {
+        let attachments = self.planets.get(planet).into_iter().flatten();
+        planet.info().influence
+            + attachments
+                .map(|attachment| attachment.info().influence)
+                .sum::<u32>()
+    }
+
+    /// The home system of the player.
+    ///
+    /// Factions that pick their starting planets have the home system of the faction whose planets
+    /// they picked.
+    pub fn home_system(&self) -> Result<System, GameError> {
+        let choices = self.faction.setup().home_system_choices;
+        let faction = if choices.is_empty() {
+            Some(self.faction)
+        } else {
+            choices.into_iter().find(|faction| {
+                (faction.home_system_planets().iter()).any(|p| self.planets.contains_key(p))
+            })
+        };
+
+        let home_system = systems().into_values().find(
+            |system| matches!(system.system_type, SystemType::HomeSystem(f) if Some(f) == faction),
+        );
+        let Some(home_system) = home_system else {
+            bail!("{:?} has no home system", self.faction);
+        };
+        Ok(home_system)
+    }
+
+    /// The stats of the players version of the unit, taking researched unit upgrades into
+    /// account.
+    ///
+    /// Upgrades of faction specific units are not tracked, so those have their printed stats.
+    pub fn unit_stats(&self, unit: &Unit) -> Option<UnitStats> {
+        match unit {
+            Unit::Flagship => self.faction.flagship().map(|info| info.stats),
+            Unit::Mech => self.faction.mech().map(|info| info.stats),
+            _ if unit.upgrade().is_some_and(|tech| self.has_tech(&tech)) => unit.upgraded_stats(),
+            _ => unit.stats(),
+        }
+    }
+
+    /// The maximum number of action cards the player may have in their hand, or `None` if there
+    /// is no limit.
+    pub fn action_card_hand_limit(&self) -> Option<u32> {
+        // Yssaril Tribes faction ability "Crafty"
+        if self.faction == Faction::YssarilTribes {
+            return None;
+        }
+
+        Some(ACTION_CARD_HAND_LIMIT)
+    }
+
+    /// The number of action cards the player draws during the status phase.
+    ///
+    /// This does not include the extra card that the Yssaril Tribes draw and then discard
+    /// ("Scheming"), as it doesn't change the size of their hand.
+    pub fn status_phase_action_card_draws(&self) -> u32 {
+        1 + u32::from(self.has_tech(&Technology::NeuralMotivator))
+    }
+
+    /// Add `count` action cards to the players hand.
+    ///
+    /// Cards above the hand limit are not discarded, they are added to the cards the player has to
+    /// discard.
+    pub fn gain_action_cards(&mut self, count: u32) {
+        self.action_cards += count;
+        self.update_action_cards_to_discard();
+    }
+
+    /// Remove `count` action cards from the players hand, either by discarding or playing them.
+    ///
+    /// The hand size is advisory, so removing more cards than the player is known to have leaves
+    /// them with an empty hand.
+    pub fn discard_action_cards(&mut self, count: u32) {
+        self.action_cards = self.action_cards.saturating_sub(count);
+        self.update_action_cards_to_discard();
+    }
+
+    /// Recalculate how many action cards the player has above their hand limit.
+    fn update_action_cards_to_discard(&mut self) {
+        self.action_cards_to_discard = self
+            .action_card_hand_limit()
+            .map(|limit| self.action_cards.saturating_sub(limit))
+            .unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ti_helper_game_data::{
+        common::faction::Faction,
+        components::{
+            planet::Planet,
+            planet_attachment::PlanetAttachment,
+            tech::{TechCategory, Technology},
+        },
+    };
+
+    use crate::gameplay::{
+        event::Event,
+        game::Game,
+        test_util::{apply, player, settings, setup_game, setup_game_with_factions},
+    };
+
+    #[test]
+    fn research_facilities_only_add_a_specialty_to_planets_without_one() {
+        let mut game = setup_game(settings());
+        let missing = |game: &Game| {
+            game.current.players[&player("Bob")]
+                .missing_prerequisites(&Technology::DacxiveAnimators)
+                .get(&TechCategory::Biotic)
+                .copied()
+                .unwrap_or_default()
+        };
+        let take_with_facility = |game: &mut Game, planet: Planet| {
+            let owner = Event::SetPlanetOwner {
+                player: Some(player("Bob")),
+                planet: planet.clone(),
+            };
+            apply(game, owner);
+            let attachment = Event::AddPlanetAttachment {
+                player: player("Bob"),
+                planet,
+                attachment: PlanetAttachment::BioticResearchFacility,
+            };
+            apply(game, attachment);
+        };
+        assert_eq!(missing(&game), 1);
+
+        take_with_facility(&mut game, Planet::Gral);
+        assert_eq!(missing(&game), 1);
+
+        take_with_facility(&mut game, Planet::Arinam);
+        assert_eq!(missing(&game), 0);
+    }
+
+    #[test]
+    fn faction_unit_upgrades_replace_the_generic_upgrade() {
+        let game = setup_game(settings());
+        let mut sol = game.current.players[&player("Alice")].clone();
+        sol.take_tech(Technology::CarrierII).unwrap();
+        sol.take_tech(Technology::AdvancedCarrierII).unwrap();
+        assert!(!sol.has_tech(&Technology::CarrierII));
+
+        let err = sol.take_tech(Technology::CarrierII).unwrap_err();
+        assert!(err.to_string().contains("replaced by"), "{err}");
+        let err = sol.take_tech(Technology::SuperDreadnoughtII).unwrap_err();
+        assert!(err.to_string().contains("belongs to"), "{err}");
+    }
+
+    #[test]
+    fn the_nekro_virus_may_own_other_factions_upgrades_and_generic_ones() {
+        let factions = [
+            Faction::NekroVirus,
+            Faction::EmiratesOfHacan,
+            Faction::BaronyOfLetnev,
+        ];
+        let game = setup_game_with_factions(settings(), factions);
+        let mut nekro = game.current.players[&player("Alice")].clone();
+        nekro.take_tech(Technology::CarrierII).unwrap();
+        nekro.take_tech(Technology::AdvancedCarrierII).unwrap();
+        assert!(nekro.has_tech(&Technology::CarrierII));
+        assert!(nekro.has_tech(&Technology::AdvancedCarrierII));
+    }
 }
@@ -59,7 +59,13 @@ pub struct Score {
 
 impl Score {
     /// Update [Score::player_points] to the correct values.
-    pub fn update_player_points(&mut self, all_players: &HashMap<PlayerId, Player>) {
+    ///
+    /// Styx only provides its victory point while the Fracture is in play.
+    pub fn update_player_points(
+        &mut self,
+        all_players: &HashMap<PlayerId, Player>,
+        fracture_in_play: bool,
+    ) {
         self.max_points = 10; // TODO set up score
 
         for (player_id, player) in all_players {
@@ -106,6 +112,11 @@ impl Score {
                 player_points += 1;
             }
 
+            // Check if the player controls Styx in the Fracture
+            if fracture_in_play && player.planets.contains_key(&Planet::Styx) {
+                player_points += 1;
+            }
+
             player_points += self
                 .agenda_scores
                 .iter()
@@ -231,6 +242,30 @@ impl Score {
         self.agenda_scores.push(scorable_agenda);
     }
 
+    /// Move the [ScorableAgenda::TheCrownOfEmphidia] from one player to another, if `from` holds it.
+    pub fn transfer_crown_of_emphidia(&mut self, from: &PlayerId, to: &PlayerId) {
+        for score in self.agenda_scores.iter_mut() {
+            match score {
+                ScorableAgenda::TheCrownOfEmphidia { player } if player == from => {
+                    *player = to.clone()
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Move the [ScorableAgenda::ShardOfTheThrone] from one player to another, if `from` holds it.
+    pub fn transfer_shard_of_the_throne(&mut self, from: &PlayerId, to: &PlayerId) {
+        for score in self.agenda_scores.iter_mut() {
+            match score {
+                ScorableAgenda::ShardOfTheThrone { player } if player == from => {
+                    *player = to.clone()
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Handle that the particular agenda is repealed, only has an effect if it is a scorable agenda.
     pub fn handle_law_repealed(&mut self, agenda: &Agenda) {
         let Some((index, _)) = self
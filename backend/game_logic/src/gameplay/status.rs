@@ -21,16 +21,24 @@ pub struct StatusPhaseState {
     pub revealed_objective: Option<Objective>,
     /// The number of objectives expected to have been revealed before we start revealing stage II cards.
     pub expected_objectives_before_stage_two: usize,
+    /// The number of action cards each player draws during this status phase.
+    ///
+    /// Includes the extra card that the Yssaril Tribes draw and then discard.
+    pub action_cards_to_draw: HashMap<PlayerId, u32>,
 }
 
 impl StatusPhaseState {
     /// Create a new empty state for a new status phase.
-    pub fn new(expected_objectives_before_stage_two: usize) -> Self {
+    pub fn new(
+        expected_objectives_before_stage_two: usize,
+        action_cards_to_draw: HashMap<PlayerId, u32>,
+    ) -> Self {
         Self {
             scored_public_objectives: HashMap::new(),
             scored_secret_objectives: HashMap::new(),
             revealed_objective: None,
             expected_objectives_before_stage_two,
+            action_cards_to_draw,
         }
     }
 
@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+use ti_helper_game_data::{
+    common::{
+        color::Color,
+        faction::Faction,
+        game_settings::{Expansions, GameSettings},
+        player_id::PlayerId,
+    },
+    components::{objectives::public::PublicObjective, strategy_card::StrategyCard},
+};
+
+use super::{
+    event::{Event, StrategicPrimaryAction},
+    game::Game,
+    player::NewPlayer,
+};
+
+/// The players in a test game, in table order. The first player is the speaker.
+pub const PLAYERS: [(&str, Faction, Color); 3] = [
+    ("Alice", Faction::FederationOfSol, Color::Blue),
+    ("Bob", Faction::EmiratesOfHacan, Color::Yellow),
+    ("Carol", Faction::BaronyOfLetnev, Color::Red),
+];
+
+/// The strategy cards picked in a test game, in the order they are picked.
+const STRATEGY_CARDS: [StrategyCard; 6] = [
+    StrategyCard::Leadership,
+    StrategyCard::Diplomacy,
+    StrategyCard::Politics,
+    StrategyCard::Construction,
+    StrategyCard::Trade,
+    StrategyCard::Warfare,
+];
+
+/// Returns the [PlayerId] of the provided name.
+pub fn player(name: &str) -> PlayerId {
+    name.into()
+}
+
+/// Settings for a game with every expansion and all validations enabled.
+pub fn settings() -> GameSettings {
+    GameSettings {
+        max_points: 10,
+        expansions: Expansions {
+            prophecy_of_kings: true,
+            codex_1: true,
+            codex_2: true,
+            codex_3: true,
+            thunders_edge: true,
+        },
+        validate_prerequisites: true,
+        validate_scoring_windows: true,
+        track_units: true,
+        validate_turn_order: true,
+        validate_action_card_payloads: true,
+        galactic_events: Vec::new(),
+    }
+}
+
+/// Applies the event, panicking if it is rejected.
+pub fn apply(game: &mut Game, event: Event) {
+    let debug = format!("{event:?}");
+    if let Err(e) = game.apply_or_err(event, Utc::now()) {
+        panic!("Failed to apply {debug}: {e}");
+    }
+}
+
+/// Applies the event like [apply] at `seed` microseconds after the epoch, which seeds the cards
+/// it draws.
+pub fn apply_seeded(game: &mut Game, event: Event, seed: i64) {
+    let debug = format!("{event:?}");
+    let timestamp = DateTime::from_timestamp_micros(seed).expect("Invalid seed");
+    if let Err(e) = game.apply_or_err(event, timestamp) {
+        panic!("Failed to apply {debug}: {e}");
+    }
+}
+
+/// Applies the event, panicking if it is accepted. Returns the error message.
+pub fn apply_err(game: &mut Game, event: Event) -> String {
+    let debug = format!("{event:?}");
+    match game.apply_or_err(event, Utc::now()) {
+        Ok(()) => panic!("Expected {debug} to be rejected"),
+        Err(e) => e.to_string(),
+    }
+}
+
+/// Creates a game with [PLAYERS] that is set up but hasn't started.
+pub fn setup_game(settings: GameSettings) -> Game {
+    setup_game_with_factions(settings, PLAYERS.map(|(_, faction, _)| faction))
+}
+
+/// Creates a game like [setup_game], where the players play the provided factions instead.
+pub fn setup_game_with_factions(settings: GameSettings, factions: [Faction; 3]) -> Game {
+    let mut game = Game::default();
+    apply(&mut game, Event::SetSettings { settings });
+    for ((name, _, color), faction) in PLAYERS.into_iter().zip(factions) {
+        let player = NewPlayer {
+            name: name.to_string(),
+            faction,
+            color,
+        };
+        apply(&mut game, Event::AddPlayer { player });
+    }
+    apply(&mut game, Event::CreationDone);
+    apply(
+        &mut game,
+        Event::SetupSpeaker {
+            player: player(PLAYERS[0].0),
+        },
+    );
+    apply(
+        &mut game,
+        Event::RevealInitialObjectives {
+            first_objective: PublicObjective::DiversifyResearch.into(),
+            second_objective: PublicObjective::SwayTheCouncil.into(),
+        },
+    );
+    game
+}
+
+/// Creates a game with [PLAYERS] in the action phase of the first round.
+///
+/// Each player holds two strategy cards, in table order the players have initiative 1, 2 and 3.
+pub fn action_phase_game(settings: GameSettings) -> Game {
+    action_phase_game_with_factions(settings, PLAYERS.map(|(_, faction, _)| faction))
+}
+
+/// Creates a game like [action_phase_game], where the players play the provided factions instead.
+pub fn action_phase_game_with_factions(settings: GameSettings, factions: [Faction; 3]) -> Game {
+    let mut game = setup_game_with_factions(settings, factions);
+    apply(&mut game, Event::StartGame);
+    for (i, card) in STRATEGY_CARDS.into_iter().enumerate() {
+        let player = player(PLAYERS[i % PLAYERS.len()].0);
+        apply(&mut game, Event::TakeStrategyCard { player, card });
+    }
+    apply(&mut game, Event::CompleteStrategyPhase);
+    game
+}
+
+/// Plays every strategy card of the game created by [action_phase_game] in initiative order,
+/// leaving the first player to act in the action phase.
+pub fn play_strategy_cards(game: &mut Game) {
+    for (i, card) in STRATEGY_CARDS.into_iter().enumerate() {
+        let player = player(PLAYERS[i % PLAYERS.len()].0);
+        apply(
+            game,
+            Event::StrategicActionBegin {
+                player: player.clone(),
+                card,
+            },
+        );
+        if card == StrategyCard::Politics {
+            let action = StrategicPrimaryAction::Politics {
+                new_speaker: player.clone(),
+            };
+            apply(
+                game,
+                Event::StrategicActionPrimary {
+                    player: player.clone(),
+                    action,
+                },
+            );
+        }
+        apply(game, Event::StrategicActionCommit);
+        apply(game, Event::EndTurn { player });
+    }
+}
+
+/// Makes every player pass in turn order, ending the action phase.
+pub fn pass_all(game: &mut Game) {
+    for _ in PLAYERS {
+        let player = game
+            .current
+            .current_player
+            .clone()
+            .expect("No current player");
+        apply(game, Event::PassAction { player });
+    }
+}
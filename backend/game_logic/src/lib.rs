@@ -1,4 +1,5 @@
 #![forbid(unsafe_code)]
+#![recursion_limit = "256"]
 #![deny(missing_docs)]
 #![allow(clippy::single_match)]
 
@@ -113,6 +113,10 @@ pub fn render_map(
     let mut outside_galaxy_count = 0;
 
     for tile in hex_map.tiles.iter() {
+        if matches!(tile.position, HexPosition::Fracture) && !game_state.map_data.fracture_in_play {
+            continue;
+        }
+
         let (tile_pos, rotation) =
             get_tile_pos_and_rotation(&tile.position, &mut outside_galaxy_count);
 
@@ -276,7 +280,7 @@ fn get_tile_pos_and_rotation(
     outside_galaxy_count: &mut i32,
 ) -> (Vec2, f32) {
     match position {
-        HexPosition::OutsideGalaxy => {
+        HexPosition::OutsideGalaxy | HexPosition::Fracture => {
             let pos = Vec2::new(-5.5, -2.0 + 4.0 * (*outside_galaxy_count as f32));
 
             (*outside_galaxy_count) += 1;
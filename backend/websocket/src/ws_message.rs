@@ -53,6 +53,12 @@ impl NewGame {
                         codex_3: *cod3,
                         thunders_edge: *te,
                     },
+                    validate_prerequisites: true,
+                    validate_scoring_windows: true,
+                    track_units: true,
+                    validate_turn_order: true,
+                    validate_action_card_payloads: true,
+                    galactic_events: Vec::new(),
                 },
             },
             GameConfig::ImportFromMilty {
@@ -65,6 +71,11 @@ impl NewGame {
                 Event::ImportFromMilty {
                     max_points: self.points,
                     milty_data: Box::new(milty_data),
+                    validate_prerequisites: true,
+                    validate_scoring_windows: true,
+                    track_units: true,
+                    validate_turn_order: true,
+                    validate_action_card_payloads: true,
                 }
             }
         })
@@ -5,4 +5,4 @@ import type { Phase } from "./Phase";
 /**
  * When an action card can be played.
  */
-export type ActionCardPlay = { "StartOfPhase": Phase } | { "Agenda": AgendaStagePlay } | "StatusPhaseReturnStrategyCards" | "Action" | "AfterActionCardIsPlayed" | "AfterStrategyCardIsPlayed" | "NotImplemented";
\ No newline at end of file
+export type ActionCardPlay = { "StartOfPhase": Phase } | { "Agenda": AgendaStagePlay } | "StatusPhaseReturnStrategyCards" | "Action" | "AfterAction" | "AfterActionCardIsPlayed" | "AfterStrategyCardIsPlayed" | "StrategyCardIsChosen" | "TacticalAction" | "NotImplemented";
\ No newline at end of file
@@ -5,5 +5,6 @@ import type { LeaderProgress } from "./LeaderProgress";
 import type { RelicProgress } from "./RelicProgress";
 import type { StrategicProgress } from "./StrategicProgress";
 import type { TacticalProgress } from "./TacticalProgress";
+import type { TechProgress } from "./TechProgress";
 
-export type ActionPhaseProgress = { "t": "Strategic" } & StrategicProgress | { "t": "Tactical" } & TacticalProgress | { "t": "ActionCard" } & ActionCardProgress | { "t": "Leader" } & LeaderProgress | { "t": "FrontierCard" } & FrontierCardProgress | { "t": "Relic" } & RelicProgress;
\ No newline at end of file
+export type ActionPhaseProgress = { "t": "Strategic" } & StrategicProgress | { "t": "Tactical" } & TacticalProgress | { "t": "ActionCard" } & ActionCardProgress | { "t": "Leader" } & LeaderProgress | { "t": "FrontierCard" } & FrontierCardProgress | { "t": "Relic" } & RelicProgress | { "t": "Tech" } & TechProgress;
\ No newline at end of file
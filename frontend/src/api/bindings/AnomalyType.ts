@@ -3,4 +3,4 @@
 /**
  * A type of anomaly.
  */
-export type AnomalyType = "AsteroidField" | "Nebula" | "Supernova" | "MuaatSupernova" | "GravityRift";
\ No newline at end of file
+export type AnomalyType = "AsteroidField" | "Nebula" | "Supernova" | "MuaatSupernova" | "GravityRift" | "EntropicScar";
\ No newline at end of file
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Faction } from "./Faction";
+
+/**
+ * A faction breakthrough from Thunder's Edge, each faction has exactly one.
+ *
+ * The names and abilities of the breakthroughs aren't known yet, so a breakthrough is identified
+ * by the faction it belongs to.
+ */
+export type Breakthrough = Faction;
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The state of a faction breakthrough owned by a player.
+ */
+export type BreakthroughState = "Locked" | "Unlocked" | "Exhausted";
\ No newline at end of file
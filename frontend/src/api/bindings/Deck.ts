@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type Deck<T> = { 
+/**
+ * The cards left to draw.
+ */
+drawPile: Array<T>, 
+/**
+ * The cards that have been discarded.
+ */
+discardPile: Array<T>, };
\ No newline at end of file
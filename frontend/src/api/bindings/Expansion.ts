@@ -3,4 +3,4 @@
 /**
  * An expansion for Twilight Imperial 4th edition.
  */
-export type Expansion = "Base" | "ProphecyOfKings" | "Codex" | "CodexII" | "CodexIII";
\ No newline at end of file
+export type Expansion = "Base" | "ProphecyOfKings" | "Codex" | "CodexII" | "CodexIII" | "ThundersEdge";
\ No newline at end of file
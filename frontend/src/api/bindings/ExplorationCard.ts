@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A card in one of the planet exploration decks (cultural, hazardous or industrial).
+ */
+export type ExplorationCard = "CulturalRelicFragment" | "DemilitarizedZone" | "DysonSphere" | "Freelancers" | "GammaWormhole" | "MercenaryOutfit" | "ParadiseWorld" | "TombOfEmphidia" | "HazardousRelicFragment" | "CoreMine" | "Expedition" | "LazaxSurvivors" | "MiningWorld" | "RichWorld" | "VolatileFuelSource" | "WarfareResearchFacility" | "IndustrialRelicFragment" | "AbandonedWarehouses" | "BioticResearchFacility" | "CyberneticResearchFacility" | "FunctioningBase" | "LocalFabricators" | "PropulsionResearchFacility";
\ No newline at end of file
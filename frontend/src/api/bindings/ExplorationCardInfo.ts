@@ -0,0 +1,35 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Expansion } from "./Expansion";
+import type { ExplorationCard } from "./ExplorationCard";
+import type { ExplorationEffect } from "./ExplorationEffect";
+import type { PlanetTrait } from "./PlanetTrait";
+
+export type ExplorationCardInfo = { 
+/**
+ * Which card this is in regards to.
+ */
+card: ExplorationCard, 
+/**
+ * The 'pretty' name of the card.
+ */
+name: string, 
+/**
+ * Which expansion this card comes from.
+ */
+expansion: Expansion, 
+/**
+ * Which exploration deck the card belongs to.
+ */
+deck: PlanetTrait, 
+/**
+ * The number of cards that exists in a deck.
+ */
+numInDeck: number, 
+/**
+ * What happens when the card is drawn.
+ */
+effect: ExplorationEffect, 
+/**
+ * The text on the card.
+ */
+text: string, };
\ No newline at end of file
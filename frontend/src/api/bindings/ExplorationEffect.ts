@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlanetAttachment } from "./PlanetAttachment";
+import type { RelicFragment } from "./RelicFragment";
+
+/**
+ * What happens when an exploration card is drawn.
+ */
+export type ExplorationEffect = { "Attachment": PlanetAttachment } | { "RelicFragment": RelicFragment } | { "TradeGoods": number } | { "GroundForceTradeGoods": number } | "GammaWormhole" | "GainUnit" | "Other";
\ No newline at end of file
@@ -3,4 +3,4 @@
 /**
  * A playable faction in the game.
  */
-export type Faction = "Arborec" | "BaronyOfLetnev" | "ClanOfSaar" | "EmbersOfMuaat" | "EmiratesOfHacan" | "FederationOfSol" | "GhostsOfCreuss" | "L1Z1XMindnet" | "MentakCoalition" | "NaaluCollective" | "NekroVirus" | "SardakkNorr" | "UniversitiesOfJolNar" | "Winnu" | "XxchaKingdom" | "YinBrotherhood" | "YssarilTribes" | "ArgentFlight" | "Empyrean" | "MahactGeneSorcerers" | "NaazRokhaAlliance" | "Nomad" | "TitansOfUl" | "VuilRaithCabal" | "CouncilKeleres";
\ No newline at end of file
+export type Faction = "Arborec" | "BaronyOfLetnev" | "ClanOfSaar" | "EmbersOfMuaat" | "EmiratesOfHacan" | "FederationOfSol" | "GhostsOfCreuss" | "L1Z1XMindnet" | "MentakCoalition" | "NaaluCollective" | "NekroVirus" | "SardakkNorr" | "UniversitiesOfJolNar" | "Winnu" | "XxchaKingdom" | "YinBrotherhood" | "YssarilTribes" | "ArgentFlight" | "Empyrean" | "MahactGeneSorcerers" | "NaazRokhaAlliance" | "Nomad" | "TitansOfUl" | "VuilRaithCabal" | "CouncilKeleres" | "LastBastion" | "RalNelConsortium" | "DeepwroughtScolarate" | "CrimsonRebellion" | "FirmamentObsidian";
\ No newline at end of file
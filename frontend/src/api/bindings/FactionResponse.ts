@@ -1,5 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Faction } from "./Faction";
+import type { FactionSetup } from "./FactionSetup";
+import type { FactionUnitInfo } from "./FactionUnitInfo";
 
 export type FactionResponse = { 
 /**
@@ -9,4 +11,20 @@ faction: Faction,
 /**
  * The name of the faction in 'pretty' format.
  */
-name: string, };
\ No newline at end of file
+name: string, 
+/**
+ * The faction specific choices made during setup.
+ */
+setup: FactionSetup, 
+/**
+ * The flagship of the faction, if known.
+ */
+flagship: FactionUnitInfo | null, 
+/**
+ * The mech of the faction, if known.
+ */
+mech: FactionUnitInfo | null, 
+/**
+ * The commodity value of the faction, if known.
+ */
+commodities: number | null, };
\ No newline at end of file
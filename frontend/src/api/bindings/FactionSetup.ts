@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Faction } from "./Faction";
+import type { StartingTechChoice } from "./StartingTechChoice";
+import type { Unit } from "./Unit";
+
+export type FactionSetup = { 
+/**
+ * The starting technologies the player picks, if any.
+ */
+techs: StartingTechChoice | null, 
+/**
+ * The factions whose home system the player may pick their starting planets from.
+ *
+ * Empty if the faction starts with the planets of its own home system.
+ */
+homeSystemChoices: Array<Faction>, 
+/**
+ * The units the faction starts the game with, `None` if they aren't known and have to be
+ * placed by the player.
+ */
+startingUnits: { [key in Unit]: number } | null, };
\ No newline at end of file
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Unit } from "./Unit";
+import type { UnitStats } from "./UnitStats";
+
+export type FactionUnitInfo = { 
+/**
+ * The name of the unit in 'pretty' format.
+ */
+name: string, 
+/**
+ * Which unit this is.
+ */
+unit: Unit, 
+/**
+ * The stats of the unit.
+ */
+stats: UnitStats, };
\ No newline at end of file
@@ -21,6 +21,6 @@ expansion: Expansion,
  */
 numInDeck: number, 
 /**
- * Weather this frontier carries with it an action or not.
+ * Weather this frontier carries with it an action, or what happens when it is drawn.
  */
 frontierType: FrontierCardType, };
\ No newline at end of file
@@ -1,6 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
 /**
- * When this frontier card can be played.
+ * When this frontier card can be played, or what happens when it is drawn.
  */
-export type FrontierCardType = "Action" | "Unhandled";
\ No newline at end of file
+export type FrontierCardType = "Action" | "DrawSecretObjective" | { "DrawActionCards": number } | "RelicFragment" | "IonStorm" | "GammaWormhole" | "EntropicField" | "Other";
\ No newline at end of file
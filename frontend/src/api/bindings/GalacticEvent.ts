@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A galactic event from Thunder's Edge, which modifies the rules for the whole game.
+ */
+export type GalacticEvent = "AdventOfTheWarSun" | "AgeOfCommerce" | "AgeOfExploration" | "CallOfTheVoid" | "CivilizedSociety" | "ConventionsOfWarAbandoned" | "CosmicPhenomenae" | "CulturalExchangeProgram" | "DangerousWilds" | "HiddenAgenda" | "MercenariesForHire" | "MinorFactions" | "MonumentsToTheAges" | "RapidMobilization" | "StellarAtomics" | "TotalWar" | "WeirdWormholes" | "WildWildGalaxy" | "ZealousOrthodoxy";
\ No newline at end of file
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Expansion } from "./Expansion";
+
+export type GalacticEventInfo = { 
+/**
+ * The name of the galactic event in 'pretty' format.
+ */
+name: string, 
+/**
+ * The rules text of the galactic event, empty until the cards are known.
+ */
+description: string, 
+/**
+ * Which expansion the galactic event belongs to.
+ */
+expansion: Expansion, };
\ No newline at end of file
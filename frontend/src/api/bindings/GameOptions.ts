@@ -3,11 +3,16 @@ import type { ActionCard } from "./ActionCard";
 import type { ActionCardInfo } from "./ActionCardInfo";
 import type { Agenda } from "./Agenda";
 import type { AgendaInfo } from "./AgendaInfo";
+import type { Breakthrough } from "./Breakthrough";
 import type { Color } from "./Color";
+import type { ExplorationCard } from "./ExplorationCard";
+import type { ExplorationCardInfo } from "./ExplorationCardInfo";
 import type { Faction } from "./Faction";
 import type { FactionResponse } from "./FactionResponse";
 import type { FrontierCard } from "./FrontierCard";
 import type { FrontierCardInfo } from "./FrontierCardInfo";
+import type { GalacticEvent } from "./GalacticEvent";
+import type { GalacticEventInfo } from "./GalacticEventInfo";
 import type { Leader } from "./Leader";
 import type { LeaderInfo } from "./LeaderInfo";
 import type { Objective } from "./Objective";
@@ -16,11 +21,15 @@ import type { Planet } from "./Planet";
 import type { PlanetAttachment } from "./PlanetAttachment";
 import type { PlanetAttachmentInfo } from "./PlanetAttachmentInfo";
 import type { PlanetInfo } from "./PlanetInfo";
+import type { PromissoryNote } from "./PromissoryNote";
+import type { PromissoryNoteInfo } from "./PromissoryNoteInfo";
 import type { Relic } from "./Relic";
 import type { RelicInfo } from "./RelicInfo";
 import type { System } from "./System";
 import type { TechInfo } from "./TechInfo";
 import type { Technology } from "./Technology";
+import type { Unit } from "./Unit";
+import type { UnitInfo } from "./UnitInfo";
 
 export type GameOptions = { 
 /**
@@ -79,7 +88,27 @@ leadersByFaction: { [key in Faction]: Array<Leader> },
  * What frontier cards exists in the game.
  */
 frontierCards: { [key in FrontierCard]: FrontierCardInfo }, 
+/**
+ * What exploration cards exists in the game.
+ */
+explorationCards: { [key in ExplorationCard]: ExplorationCardInfo }, 
 /**
  * What relics exists in the game.
  */
-relics: { [key in Relic]: RelicInfo }, };
\ No newline at end of file
+relics: { [key in Relic]: RelicInfo }, 
+/**
+ * What promissory notes exists in the game.
+ */
+promissoryNotes: { [key in PromissoryNote]: PromissoryNoteInfo }, 
+/**
+ * What faction breakthroughs exists in the game.
+ */
+breakthroughs: Array<Breakthrough>, 
+/**
+ * What galactic events exists in the game.
+ */
+galacticEvents: { [key in GalacticEvent]: GalacticEventInfo }, 
+/**
+ * What units exists in the game.
+ */
+units: { [key in Unit]: UnitInfo }, };
\ No newline at end of file
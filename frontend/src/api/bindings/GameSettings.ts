@@ -1,5 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Expansions } from "./Expansions";
+import type { GalacticEvent } from "./GalacticEvent";
 
 export type GameSettings = { 
 /**
@@ -9,4 +10,42 @@ maxPoints: number,
 /**
  * Which expansions are in play this game.
  */
-expansions: Expansions, };
\ No newline at end of file
+expansions: Expansions, 
+/**
+ * If technology prerequisites should be validated when researching technologies.
+ *
+ * Games created before prerequisites were validated don't have this set.
+ */
+validatePrerequisites: boolean, 
+/**
+ * If secret objectives should only be scored in their scoring window, or when an out of phase
+ * scoring opportunity has been recorded.
+ *
+ * Games created before scoring windows were validated don't have this set.
+ */
+validateScoringWindows: boolean, 
+/**
+ * If units on the board are tracked, which requires tactical actions to activate a system.
+ *
+ * Games created before units were tracked don't have this set.
+ */
+trackUnits: boolean, 
+/**
+ * If players must take their actions in initiative order, starting with their lowest
+ * strategy card.
+ *
+ * Games created before the turn order was enforced don't have this set. Their action phase
+ * turn order depended on hash map iteration order, so a player starting an action becomes
+ * the current player instead.
+ */
+validateTurnOrder: boolean, 
+/**
+ * If action cards that change tracked state must be committed with their payload.
+ *
+ * Games created before these payloads were validated don't have this set.
+ */
+validateActionCardPayloads: boolean, 
+/**
+ * Which galactic events are in play this game.
+ */
+galacticEvents: Array<GalacticEvent>, };
\ No newline at end of file
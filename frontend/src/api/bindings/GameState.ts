@@ -5,12 +5,19 @@ import type { AgendaElect } from "./AgendaElect";
 import type { AgendaOverrideState } from "./AgendaOverrideState";
 import type { AgendaRecord } from "./AgendaRecord";
 import type { AgendaState } from "./AgendaState";
+import type { Deck } from "./Deck";
+import type { ExplorationCard } from "./ExplorationCard";
+import type { FrontierCard } from "./FrontierCard";
+import type { GalacticEvent } from "./GalacticEvent";
 import type { GameSettings } from "./GameSettings";
 import type { Leader } from "./Leader";
 import type { MapData } from "./MapData";
 import type { Phase } from "./Phase";
+import type { PlanetTrait } from "./PlanetTrait";
+import type { PlayedActionCard } from "./PlayedActionCard";
 import type { Player } from "./Player";
 import type { Score } from "./Score";
+import type { SecretObjective } from "./SecretObjective";
 import type { StatusPhaseState } from "./StatusPhaseState";
 import type { StrategyCard } from "./StrategyCard";
 
@@ -83,6 +90,11 @@ agendaVoteHistory: Array<AgendaRecord>,
  * Laws in play.
  */
 laws: { [key in Agenda]: AgendaElect }, 
+/**
+ * Galactic events that are currently modifying the game, they are activated at the start of
+ * each strategy phase.
+ */
+activeGalacticEvents: Array<GalacticEvent>, 
 /**
  * State required for the agenda 'admin view'.
  */
@@ -116,4 +128,25 @@ currentTurnStartTime: string | null,
  *
  * This player has initiative 0 in the action and status phase.
  */
-naaluTelepathy: string | null, };
\ No newline at end of file
+naaluTelepathy: string | null, 
+/**
+ * Secret objectives that players have become eligible to score outside of the status phase
+ * due to something that happened in the game, e.g. being elected by an agenda.
+ *
+ * These are cleared whenever the game moves on to the next phase.
+ */
+secretObjectiveOpportunities: { [key: string]: Array<SecretObjective> }, 
+/**
+ * The exploration deck for each planet trait.
+ *
+ * A deck is created the first time a card is drawn from it.
+ */
+explorationDecks: { [key in PlanetTrait]: Deck<ExplorationCard> }, 
+/**
+ * The frontier exploration deck, created the first time a frontier token is explored.
+ */
+frontierDeck: Deck<FrontierCard> | null, 
+/**
+ * Every action card played this game, in the order they were played.
+ */
+playedActionCards: Array<PlayedActionCard>, };
\ No newline at end of file
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromissoryNote } from "./PromissoryNote";
+
+export type HeldPromissoryNote = { 
+/**
+ * Which promissory note it is.
+ */
+note: PromissoryNote, 
+/**
+ * The player that the promissory note originally belongs to.
+ */
+owner: string, 
+/**
+ * Weather the promissory note is face up in the play area, rather than in the players hand.
+ */
+inPlayArea: boolean, };
\ No newline at end of file
@@ -4,4 +4,4 @@ import type { Coordinate } from "./Coordinate";
 /**
  * A position of a tile in the game.
  */
-export type HexPosition = "OutsideGalaxy" | { "Pos": Coordinate };
\ No newline at end of file
+export type HexPosition = "OutsideGalaxy" | { "Pos": Coordinate } | "Fracture";
\ No newline at end of file
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IonStormSide } from "./IonStormSide";
+
+export type IonStorm = { 
+/**
+ * The system the token is in.
+ */
+system: string, 
+/**
+ * Which side of the token is face up.
+ */
+side: IonStormSide, };
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The side of the ion storm token, which determines what type of wormhole it is.
+ */
+export type IonStormSide = "Alpha" | "Beta";
\ No newline at end of file
@@ -1,4 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Leader } from "./Leader";
 
-export type LeaderProgress = { "action": "nothing", leader: Leader, };
\ No newline at end of file
+export type LeaderProgress = { "action": "nothing", leader: Leader, } | { "action": "rinTheMastersLegacy" } | { "action": "unitDsgnFlayesh" } | { "action": "ulTheProgenitor" } | { "action": "xxekirGrom" } | { "action": "heshAndPrit" } | { "action": "harkaLeeds" } | { "action": "dannelOfTheTenthCxIII" } | { "action": "airoShirAur" };
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The state of a leader owned by a player.
+ */
+export type LeaderState = "Ready" | "Exhausted" | "Locked" | "Unlocked" | "Purged";
\ No newline at end of file
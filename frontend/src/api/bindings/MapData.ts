@@ -1,6 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FrontierCard } from "./FrontierCard";
+import type { IonStorm } from "./IonStorm";
 import type { MiltyInformation } from "./MiltyInformation";
 import type { Planet } from "./Planet";
+import type { SystemUnits } from "./SystemUnits";
 
 export type MapData = { 
 /**
@@ -10,4 +13,29 @@ miltyInformation: MiltyInformation | null,
 /**
  * Which planets (if any) has been destroyed by the stellar converter.
  */
-stellarConverterDestroyedPlanets: Array<Planet>, };
\ No newline at end of file
+stellarConverterDestroyedPlanets: Array<Planet>, 
+/**
+ * The ion storm token, if it has been placed on the board.
+ */
+ionStorm: IonStorm | null, 
+/**
+ * Systems that have had a gamma wormhole token placed in them.
+ */
+gammaWormholes: Array<string>, 
+/**
+ * Systems whose frontier token has been explored.
+ */
+exploredFrontiers: Array<string>, 
+/**
+ * Systems that have become entropic fields and the frontier card that made them so.
+ */
+entropicFields: { [key in string]: FrontierCard }, 
+/**
+ * The units, structures and command tokens in each system, only tracked if
+ * [GameSettings::track_units] is set.
+ */
+systems: { [key in string]: SystemUnits }, 
+/**
+ * Whether the Fracture from Thunder's Edge has been revealed, bringing its systems into play.
+ */
+fractureInPlay: boolean, };
\ No newline at end of file
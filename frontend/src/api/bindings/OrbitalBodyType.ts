@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What type of orbital body type.
+ */
+export type OrbitalBodyType = "Planet" | "SpaceStation";
\ No newline at end of file
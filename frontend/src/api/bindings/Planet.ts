@@ -3,4 +3,4 @@
 /**
  * A planet
  */
-export type Planet = "Nestphar" | "ArcPrime" | "WrenTerra" | "LisisII" | "Ragh" | "Muaat" | "Hercant" | "Arretze" | "Kamdorn" | "Jord" | "Creuss" | "ZeroZeroZero" | "MollPrimus" | "Druaa" | "Maaluuk" | "MordaiII" | "TrenLak" | "Quinarra" | "Jol" | "Nar" | "Winnu" | "ArchonRen" | "ArchonTau" | "Darien" | "Retillion" | "Shalloq" | "MecatolRex" | "Abyz" | "Fria" | "Arinam" | "Meer" | "Arnor" | "Lor" | "Bereg" | "LirtaIV" | "Centauri" | "Gral" | "Corneeq" | "Resculon" | "DalBootha" | "Xxehan" | "Lazar" | "Sakulag" | "Lodor" | "MeharXull" | "Mellon" | "Zohbat" | "NewAlbion" | "Starpoint" | "Quann" | "Qucenn" | "Rarron" | "Saudor" | "TarMann" | "TequRan" | "Torkan" | "Thibah" | "VefutII" | "Wellon" | "ArchonVail" | "Perimiter" | "Ang" | "SemLore" | "Vorhal" | "Atlas" | "Primor" | "HopesEnd" | "Cormund" | "Everra" | "JeolIr" | "Accoen" | "Kraag" | "Siig" | "Bakal" | "AlioPrima" | "Lisis" | "Velnor" | "Cealdri" | "Xanhact" | "VegaMajor" | "VegaMinor" | "Abaddon" | "Ashtroth" | "Loki" | "RigelI" | "RigelII" | "RigelIII" | "Valk" | "Avar" | "Ylir" | "TheDark" | "Ixth" | "Naazir" | "Rokha" | "Arcturus" | "Elysium" | "Acheron" | "Mallice" | "Mirage" | "CustodiaVigilia";
\ No newline at end of file
+export type Planet = "Nestphar" | "ArcPrime" | "WrenTerra" | "LisisII" | "Ragh" | "Muaat" | "Hercant" | "Arretze" | "Kamdorn" | "Jord" | "Creuss" | "ZeroZeroZero" | "MollPrimus" | "Druaa" | "Maaluuk" | "MordaiII" | "TrenLak" | "Quinarra" | "Jol" | "Nar" | "Winnu" | "ArchonRen" | "ArchonTau" | "Darien" | "Retillion" | "Shalloq" | "MecatolRex" | "Abyz" | "Fria" | "Arinam" | "Meer" | "Arnor" | "Lor" | "Bereg" | "LirtaIV" | "Centauri" | "Gral" | "Corneeq" | "Resculon" | "DalBootha" | "Xxehan" | "Lazar" | "Sakulag" | "Lodor" | "MeharXull" | "Mellon" | "Zohbat" | "NewAlbion" | "Starpoint" | "Quann" | "Qucenn" | "Rarron" | "Saudor" | "TarMann" | "TequRan" | "Torkan" | "Thibah" | "VefutII" | "Wellon" | "ArchonVail" | "Perimiter" | "Ang" | "SemLore" | "Vorhal" | "Atlas" | "Primor" | "HopesEnd" | "Cormund" | "Everra" | "JeolIr" | "Accoen" | "Kraag" | "Siig" | "Bakal" | "AlioPrima" | "Lisis" | "Velnor" | "Cealdri" | "Xanhact" | "VegaMajor" | "VegaMinor" | "Abaddon" | "Ashtroth" | "Loki" | "RigelI" | "RigelII" | "RigelIII" | "Valk" | "Avar" | "Ylir" | "TheDark" | "Ixth" | "Naazir" | "Rokha" | "Arcturus" | "Elysium" | "Acheron" | "Mallice" | "Mirage" | "CustodiaVigilia" | "Lesab" | "Olergodt" | "ViraPicsIII" | "Andeara" | "Lemox" | "TheWatchtower" | "Emelpar" | "Faunus" | "Garbozia" | "Tempesta" | "Industrex" | "Capha" | "Kostboth" | "Cresius" | "LazulRex" | "Hercalor" | "Tiamat" | "NewTerra" | "Tinnes" | "Bellatrix" | "TsionStation" | "Tarana" | "OluzStation" | "Cocytus" | "Styx" | "Lethe" | "Phlegethon" | "MecatolRexOmega" | "ThundersEdge" | "Ordinian" | "Avernus" | "Cronos" | "CronosHollow" | "Tallin" | "TallinHollow" | "Revelation" | "MezLoOrzFeiZsha" | "RepoLoOrzQet" | "Ikatena" | "AhkCreuxx" | "Elnath" | "Horizon" | "LuthienVi";
\ No newline at end of file
@@ -1,5 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Expansion } from "./Expansion";
+import type { OrbitalBodyType } from "./OrbitalBodyType";
 import type { PlanetTrait } from "./PlanetTrait";
 import type { TechCategory } from "./TechCategory";
 
@@ -9,13 +10,13 @@ export type PlanetInfo = {
  */
 name: string, 
 /**
- * Which, if any, planet trait the planet has.
+ * Which, if any, planet traits the planet has.
  */
-planetTrait: PlanetTrait | null, 
+planetTraits: Array<PlanetTrait>, 
 /**
- * Which, if any, technology bonus the planet has.
+ * Which, if any, technology bonuses the planet has.
  */
-techSpecialty: TechCategory | null, 
+techSpecialities: Array<TechCategory>, 
 /**
  * How many resources the planet has.
  */
@@ -31,4 +32,8 @@ expansion: Expansion,
 /**
  * Weather the planet is legendary or not.
  */
-isLegendary: boolean, };
\ No newline at end of file
+isLegendary: boolean, 
+/**
+ * Weather this is a planet or a space station.
+ */
+bodyType: OrbitalBodyType, };
\ No newline at end of file
@@ -0,0 +1,29 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ActionCard } from "./ActionCard";
+import type { Phase } from "./Phase";
+
+export type PlayedActionCard = { 
+/**
+ * The player that played the card.
+ */
+player: string, 
+/**
+ * Which card was played.
+ */
+card: ActionCard, 
+/**
+ * The round the card was played in.
+ */
+round: number, 
+/**
+ * The phase the card was played in.
+ */
+phase: Phase, 
+/**
+ * Weather the card was cancelled, e.g. by "Sabotage".
+ */
+cancelled: boolean, 
+/**
+ * Weather the card was played as an action and that action has been resolved.
+ */
+resolved: boolean, };
\ No newline at end of file
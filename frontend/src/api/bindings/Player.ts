@@ -1,10 +1,17 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Breakthrough } from "./Breakthrough";
+import type { BreakthroughState } from "./BreakthroughState";
 import type { Color } from "./Color";
 import type { Faction } from "./Faction";
+import type { HeldPromissoryNote } from "./HeldPromissoryNote";
+import type { Leader } from "./Leader";
+import type { LeaderState } from "./LeaderState";
 import type { Planet } from "./Planet";
 import type { PlanetAttachment } from "./PlanetAttachment";
 import type { Relic } from "./Relic";
+import type { RelicFragment } from "./RelicFragment";
 import type { Technology } from "./Technology";
+import type { ValefarAssimilator } from "./ValefarAssimilator";
 
 export type Player = { 
 /**
@@ -27,7 +34,65 @@ planets: { [key in Planet]: Array<PlanetAttachment> },
  * Which technologies the player has.
  */
 technologies: Array<Technology>, 
+/**
+ * Which of the players technologies are currently exhausted.
+ */
+exhaustedTechnologies: Array<Technology>, 
+/**
+ * How many prerequisites the player may ignore for the next technology they research, e.g.
+ * from "The Prophet's Tears".
+ */
+ignoredPrerequisites: number, 
 /**
  * Which relics the player currently owns.
  */
-relics: Array<Relic>, };
\ No newline at end of file
+relics: Array<Relic>, 
+/**
+ * Which of the players relics are currently exhausted.
+ */
+exhaustedRelics: Array<Relic>, 
+/**
+ * Which relics the player has purged.
+ */
+purgedRelics: Array<Relic>, 
+/**
+ * How many relic fragments of each type the player has.
+ */
+relicFragments: { [key in RelicFragment]: number }, 
+/**
+ * The number of trade goods the player has.
+ */
+tradeGoods: number, 
+/**
+ * The number of action cards the player has in their hand.
+ *
+ * Not every way of drawing action cards is tracked, so this is only advisory.
+ */
+actionCards: number, 
+/**
+ * The number of action cards the player has to discard to get down to their hand limit.
+ */
+actionCardsToDiscard: number, 
+/**
+ * The number of secret objectives the player may draw, e.g. from "Impersonation".
+ *
+ * Secret objective hands are not tracked, this is a reminder until the player has drawn them.
+ */
+secretObjectivesToDraw: number, 
+/**
+ * The state of each of the players leaders.
+ */
+leaders: { [key in Leader]: LeaderState }, 
+/**
+ * Which faction unit upgrades of other players the Nekro Virus' assimilator tokens are placed
+ * on.
+ */
+valefarAssimilators: { [key in ValefarAssimilator]: Technology }, 
+/**
+ * The state of the players faction breakthrough.
+ */
+breakthroughs: { [key in Breakthrough]: BreakthroughState }, 
+/**
+ * Promissory notes of other players that this player holds, in hand or in their play area.
+ */
+promissoryNotes: Array<HeldPromissoryNote>, };
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How many units a unit with production can produce.
+ */
+export type Production = { "Fixed": number } | { "PlanetResourcesPlus": number };
\ No newline at end of file
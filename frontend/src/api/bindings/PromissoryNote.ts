@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A promissory note.
+ */
+export type PromissoryNote = "Ceasefire" | "PoliticalSecret" | "SupportForTheThrone" | "TradeAgreement" | "Alliance" | "Stymie" | "WarFunding" | "RaghsCall" | "FiresOfTheGashlai" | "TradeConvoys" | "MilitarySupport" | "CreussIff" | "CyberneticEnhancements" | "PromiseOfProtection" | "GiftOfPrescience" | "Antivirus" | "TekklarLegion" | "ResearchAgreement" | "Acquiescence" | "PoliticalFavor" | "GreyfireMutagen" | "SpyNet" | "StrikeWingAmbuscade" | "BloodPact" | "DarkPact" | "ScepterOfDominion" | "BlackMarketForgery" | "TheCavalry" | "Terraform" | "Crucible" | "StymieOmega" | "WarFundingOmega" | "CyberneticEnhancementsOmega" | "AcquiescenceOmega" | "GreyfireMutagenOmega" | "KeleresRider";
\ No newline at end of file
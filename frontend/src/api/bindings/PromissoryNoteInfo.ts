@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Expansion } from "./Expansion";
+import type { Faction } from "./Faction";
+import type { PromissoryNote } from "./PromissoryNote";
+import type { PromissoryNoteUsage } from "./PromissoryNoteUsage";
+
+export type PromissoryNoteInfo = { 
+/**
+ * Which promissory note this is in regards to.
+ */
+note: PromissoryNote, 
+/**
+ * The 'pretty' name of the promissory note.
+ */
+name: string, 
+/**
+ * The faction the promissory note belongs to, or `None` if every player has one.
+ */
+faction: Faction | null, 
+/**
+ * Which expansion this promissory note comes from.
+ */
+expansion: Expansion, 
+/**
+ * What happens to the promissory note when it is received or played.
+ */
+usage: PromissoryNoteUsage, };
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What happens to a promissory note when it is received or played.
+ */
+export type PromissoryNoteUsage = "PlacedOnReceive" | "PlayArea" | "Return" | "Attach";
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A relic fragment, gained when exploring planets.
+ */
+export type RelicFragment = "Cultural" | "Hazardous" | "Industrial" | "Unknown";
\ No newline at end of file
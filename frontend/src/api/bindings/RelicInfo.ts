@@ -2,6 +2,7 @@
 import type { Expansion } from "./Expansion";
 import type { Relic } from "./Relic";
 import type { RelicPlay } from "./RelicPlay";
+import type { RelicUsage } from "./RelicUsage";
 
 /**
  * All relevant information for a relic card.
@@ -23,6 +24,10 @@ expansion: Expansion,
  * When the relic can be played / is relevant.
  */
 play: RelicPlay, 
+/**
+ * What happens to the relic when it is used.
+ */
+usage: RelicUsage, 
 /**
  * A description of what the relic is about.
  */
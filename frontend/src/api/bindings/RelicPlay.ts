@@ -3,4 +3,4 @@
 /**
  * When this relic can be used.
  */
-export type RelicPlay = "Action" | "Agenda" | "Possession" | "AfterTactical" | "Unhandled";
\ No newline at end of file
+export type RelicPlay = "Action" | "Agenda" | "Possession" | "AfterTactical" | "TacticalAction" | "Research" | "SpendStrategyToken" | "Combat" | "Unhandled";
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What happens to the relic when it is used.
+ */
+export type RelicUsage = "Purge" | "Exhaust" | "Attach" | "Passive";
\ No newline at end of file
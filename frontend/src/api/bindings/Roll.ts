@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A dice roll, e.g. "9 (x2)" is a roll of two dice that hit on 9 or higher.
+ */
+export type Roll = { 
+/**
+ * The value each die has to roll for a hit.
+ */
+hit: number, 
+/**
+ * The number of dice rolled.
+ */
+dice: number, };
\ No newline at end of file
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StartingTechPool } from "./StartingTechPool";
+
+export type StartingTechChoice = { 
+/**
+ * How many technologies are picked.
+ */
+count: number, 
+/**
+ * Where the technologies are picked from.
+ */
+pool: StartingTechPool, };
\ No newline at end of file
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Technology } from "./Technology";
+
+/**
+ * Where a faction picks its starting technologies from during setup.
+ */
+export type StartingTechPool = "NoPrerequisites" | { "Listed": Array<Technology> } | "OtherPlayers" | "Research";
\ No newline at end of file
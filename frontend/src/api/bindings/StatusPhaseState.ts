@@ -18,4 +18,10 @@ revealedObjective: Objective | null,
 /**
  * The number of objectives expected to have been revealed before we start revealing stage II cards.
  */
-expectedObjectivesBeforeStageTwo: number, };
\ No newline at end of file
+expectedObjectivesBeforeStageTwo: number, 
+/**
+ * The number of action cards each player draws during this status phase.
+ *
+ * Includes the extra card that the Yssaril Tribes draw and then discard.
+ */
+actionCardsToDraw: { [key: string]: number }, };
\ No newline at end of file
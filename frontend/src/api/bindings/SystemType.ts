@@ -5,4 +5,4 @@ import type { Faction } from "./Faction";
 /**
  * A type of system.
  */
-export type SystemType = { "Anomaly": AnomalyType } | "Hyperlane" | "Normal" | { "HomeSystem": Faction };
\ No newline at end of file
+export type SystemType = { "Anomaly": Array<AnomalyType> } | "Hyperlane" | "Normal" | { "HomeSystem": Faction };
\ No newline at end of file
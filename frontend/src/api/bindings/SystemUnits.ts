@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Planet } from "./Planet";
+import type { Unit } from "./Unit";
+
+export type SystemUnits = { 
+/**
+ * The units in the space area of the system, per player.
+ */
+space: { [key: string]: { [key in Unit]: number } }, 
+/**
+ * The units and structures on each planet in the system, per player.
+ */
+planets: { [key in Planet]: { [key: string]: { [key in Unit]: number } } }, 
+/**
+ * The players that have a command token in the system.
+ */
+commandTokens: Array<string>, };
\ No newline at end of file
@@ -1,4 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExplorationCard } from "./ExplorationCard";
+import type { FrontierCard } from "./FrontierCard";
 import type { Planet } from "./Planet";
 import type { PlanetAttachment } from "./PlanetAttachment";
 
@@ -15,4 +17,17 @@ takenPlanets: { [key in Planet]: string | null },
  * What planet attachments have been selected for the taken planets.
  * NOTE: Does not include attachments kept when taken from another player.
  */
-planetAttachments: { [key in Planet]: PlanetAttachment }, };
\ No newline at end of file
+planetAttachments: { [key in Planet]: PlanetAttachment }, 
+/**
+ * Whether the player spent 6 influence to remove the custodians token from Mecatol Rex
+ * during this action.
+ */
+removedCustodians: boolean, 
+/**
+ * The exploration cards drawn for the planets explored this far.
+ */
+exploredPlanets: { [key in Planet]: ExplorationCard }, 
+/**
+ * The frontier card drawn when exploring a frontier token during this action, if any.
+ */
+exploredFrontier: FrontierCard | null, };
\ No newline at end of file
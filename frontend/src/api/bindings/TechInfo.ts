@@ -2,7 +2,11 @@
 import type { Expansion } from "./Expansion";
 import type { TechCategory } from "./TechCategory";
 import type { TechOrigin } from "./TechOrigin";
+import type { TechPlay } from "./TechPlay";
 import type { TechType } from "./TechType";
+import type { Technology } from "./Technology";
+import type { Unit } from "./Unit";
+import type { UnitStats } from "./UnitStats";
 
 export type TechInfo = { 
 /**
@@ -28,4 +32,20 @@ expansion: Expansion,
 /**
  * The effects of the technology. Each element corresponds to an effect of the technology.
  */
-effects: Array<string>, };
\ No newline at end of file
+effects: Array<string>, 
+/**
+ * How the technology is exhausted, or `None` if it has no exhaust ability.
+ */
+play: TechPlay | null, 
+/**
+ * The generic unit upgrade that this faction unit upgrade replaces, if any.
+ */
+replaces: Technology | null, 
+/**
+ * The unit that this unit upgrade upgrades, if any.
+ */
+unit: Unit | null, 
+/**
+ * The stats of the unit after researching this unit upgrade, if known.
+ */
+unitStats: UnitStats | null, };
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a technology with an exhaust ability is used.
+ */
+export type TechPlay = "Action" | "Ability";
\ No newline at end of file
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Technology } from "./Technology";
+
+export type TechProgress = { 
+/**
+ * The technology that is exhausted for the action.
+ */
+tech: Technology, };
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A unit type that players place on the board.
+ */
+export type Unit = "Flagship" | "WarSun" | "Dreadnought" | "Carrier" | "Cruiser" | "Destroyer" | "Fighter" | "Mech" | "Infantry" | "Pds" | "SpaceDock";
\ No newline at end of file
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Roll } from "./Roll";
+
+/**
+ * A unit ability printed on a unit.
+ */
+export type UnitAbility = { "AntiFighterBarrage": Roll } | { "Bombardment": Roll } | { "SpaceCannon": { 
+/**
+ * The roll of the ability.
+ */
+roll: Roll, 
+/**
+ * If the ability can also be used against ships in adjacent systems.
+ */
+adjacent: boolean, } } | "PlanetaryShield";
\ No newline at end of file
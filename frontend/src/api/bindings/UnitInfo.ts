@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Expansion } from "./Expansion";
+import type { Technology } from "./Technology";
+import type { UnitKind } from "./UnitKind";
+import type { UnitStats } from "./UnitStats";
+
+export type UnitInfo = { 
+/**
+ * The name of the unit in 'pretty' format.
+ */
+name: string, 
+/**
+ * What kind of unit this is.
+ */
+kind: UnitKind, 
+/**
+ * Which expansion the unit belongs to.
+ */
+expansion: Expansion, 
+/**
+ * The stats of the unit, `None` if every faction has their own version of the unit.
+ */
+stats: UnitStats | null, 
+/**
+ * The technology that upgrades the unit, if any.
+ */
+upgrade: Technology | null, 
+/**
+ * The stats of the unit after researching its upgrade.
+ */
+upgradedStats: UnitStats | null, };
\ No newline at end of file
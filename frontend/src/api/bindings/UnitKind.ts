@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What kind of unit a [Unit] is.
+ */
+export type UnitKind = "Ship" | "GroundForce" | "Structure";
\ No newline at end of file
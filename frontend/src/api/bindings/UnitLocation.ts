@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Planet } from "./Planet";
+
+/**
+ * Where on the board units are placed.
+ */
+export type UnitLocation = { "Space": string } | { "Planet": Planet };
\ No newline at end of file
@@ -0,0 +1,38 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Production } from "./Production";
+import type { Roll } from "./Roll";
+import type { UnitAbility } from "./UnitAbility";
+
+export type UnitStats = { 
+/**
+ * The resource cost of producing the unit, `None` for units that can't be produced.
+ */
+cost: number | null, 
+/**
+ * How many units are produced when paying the cost.
+ */
+unitsPerCost: number, 
+/**
+ * The combat roll of the unit.
+ */
+combat: Roll | null, 
+/**
+ * The movement value of the unit.
+ */
+movement: number | null, 
+/**
+ * The capacity of the unit.
+ */
+capacity: number | null, 
+/**
+ * The production ability of the unit.
+ */
+production: Production | null, 
+/**
+ * If the unit has sustain damage.
+ */
+sustainDamage: boolean, 
+/**
+ * The other unit abilities of the unit.
+ */
+abilities: Array<UnitAbility>, };
\ No newline at end of file
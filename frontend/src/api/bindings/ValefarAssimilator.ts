@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The Nekro Virus' "Valefar Assimilator" unit upgrades, which gain the text of another faction's
+ * unit upgrade that an assimilator token is placed on.
+ */
+export type ValefarAssimilator = "X" | "Y";
\ No newline at end of file
@@ -26,6 +26,10 @@ candidates: Array<AgendaElect>,
  * Player-cast votes.
  */
 playerVotes: { [key: string]: Vote | null }, 
+/**
+ * Players that are not allowed to vote on this agenda, e.g. due to "Political Secret".
+ */
+blockedVoters: Array<string>, 
 /**
  * Votes tallied on a per-outcome basis.
  *
@@ -3,4 +3,4 @@
 /**
  * What type of wormhole this is.
  */
-export type WormHoleType = "Alpha" | "Beta" | "Gamma" | "Delta";
\ No newline at end of file
+export type WormHoleType = "Alpha" | "Beta" | "Gamma" | "Delta" | "Epsilon";
\ No newline at end of file
@@ -1,4 +1,8 @@
 import { ActionCardProgress } from "@/api/bindings/ActionCardProgress";
+import { Agenda } from "@/api/bindings/Agenda";
+import { Planet } from "@/api/bindings/Planet";
+import { PlanetTrait } from "@/api/bindings/PlanetTrait";
+import { RelicFragment } from "@/api/bindings/RelicFragment";
 import { Technology } from "@/api/bindings/Technology";
 import { Button } from "@/components/elements/button/Button";
 import { SelectTechView } from "../select_tech_view/SelectTechView";
@@ -36,7 +40,21 @@ interface ActionCardProgressViewProps {
 const ActionCardProgressView = ({
   cardProgress,
 }: ActionCardProgressViewProps) => {
-  const { gameState, sendEvent } = useGameContext();
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+
+  const otherPlayers = Object.keys(gameState.players)
+    .filter((p) => p !== gameState.currentPlayer)
+    .sort(stringSort);
+  const ownPlanets = Object.keys(
+    gameState.players[gameState.currentPlayer!!].planets,
+  ) as Planet[];
+  const otherPlanets = otherPlayers.flatMap(
+    (p) => Object.keys(gameState.players[p].planets) as Planet[],
+  );
+  const planetOptions = (planets: Planet[]) =>
+    planets
+      .map((p) => ({ id: p, name: gameOptions.planetInfos[p].name }))
+      .sort(nameSort);
 
   const sendCommitMessage = (data: any) => {
     sendEvent({
@@ -66,6 +84,99 @@ const ActionCardProgressView = ({
     return <DivertFundingView sendCommitMessage={sendCommitMessage} />;
   } else if (cardProgress.card === "Plagiarize") {
     return <PlagiarizeView sendCommitMessage={sendCommitMessage} />;
+  } else if (cardProgress.card === "MiningInitiative") {
+    return (
+      <SelectCommitView
+        placeholder="--Select Planet--"
+        options={planetOptions(ownPlanets)}
+        onCommit={(planet) =>
+          sendCommitMessage({ MiningInitiative: { planet } })
+        }
+      />
+    );
+  } else if (cardProgress.card === "Uprising") {
+    return (
+      <SelectCommitView
+        placeholder="--Select Planet--"
+        options={planetOptions(otherPlanets)}
+        onCommit={(planet) => sendCommitMessage({ Uprising: { planet } })}
+      />
+    );
+  } else if (cardProgress.card === "RepealLaw") {
+    return (
+      <SelectCommitView
+        placeholder="--Select Law--"
+        options={(Object.keys(gameState.laws) as Agenda[])
+          .map((law) => ({ id: law, name: gameOptions.agendas[law].name }))
+          .sort(nameSort)}
+        onCommit={(law) => sendCommitMessage({ RepealLaw: { law } })}
+      />
+    );
+  } else if (cardProgress.card === "Spy") {
+    return (
+      <SelectCommitView
+        placeholder="--Select Player--"
+        options={otherPlayers.map((p) => ({ id: p, name: p }))}
+        onCommit={(player) => sendCommitMessage({ Spy: { player } })}
+      />
+    );
+  } else if (cardProgress.card === "SeizeArtifact") {
+    return (
+      <SelectCommitView
+        placeholder="--Select Relic Fragment--"
+        options={otherPlayers.flatMap((p) =>
+          (Object.keys(gameState.players[p].relicFragments) as RelicFragment[])
+            .filter((f) => (gameState.players[p].relicFragments[f] ?? 0) > 0)
+            .map((f) => ({ id: `${p}/${f}`, name: `${p}: ${f}` })),
+        )}
+        onCommit={(selected) => {
+          const [player, fragment] = selected.split("/");
+          sendCommitMessage({ SeizeArtifact: { player, fragment } });
+        }}
+      />
+    );
+  } else if (cardProgress.card === "Insubordination") {
+    return (
+      <SelectCommitView
+        placeholder="--Select Player--"
+        options={otherPlayers.map((p) => ({ id: p, name: p }))}
+        onCommit={(player) =>
+          sendCommitMessage({ Insubordination: { player } })
+        }
+      />
+    );
+  } else if (
+    cardProgress.card === "UnexpectedAction" &&
+    gameState.gameSettings.trackUnits
+  ) {
+    return (
+      <SelectCommitView
+        placeholder="--Select System--"
+        options={Object.keys(gameState.mapData.systems)
+          .filter((s) =>
+            gameState.mapData.systems[s].commandTokens.includes(
+              gameState.currentPlayer!!,
+            ),
+          )
+          .sort(stringSort)
+          .map((s) => ({ id: s, name: s }))}
+        onCommit={(system) =>
+          sendCommitMessage({ UnexpectedAction: { system } })
+        }
+      />
+    );
+  } else if (cardProgress.card === "ArchaeologicalExpedition") {
+    return (
+      <SelectCommitView
+        placeholder="--Select Exploration Deck--"
+        options={(
+          ["Cultural", "Hazardous", "Industrial"] as PlanetTrait[]
+        ).map((t) => ({ id: t, name: t }))}
+        onCommit={(planetTrait) =>
+          sendCommitMessage({ ArchaeologicalExpedition: { planetTrait } })
+        }
+      />
+    );
   }
 
   return (
@@ -278,3 +389,33 @@ const PlagiarizePlayerRow = ({
     </tr>
   );
 };
+
+interface SelectCommitViewProps {
+  placeholder: string;
+  options: { id: string; name: string }[];
+  onCommit: (id: string) => void;
+}
+
+const SelectCommitView = ({
+  placeholder,
+  options,
+  onCommit,
+}: SelectCommitViewProps) => {
+  const [selected, setSelected] = useState<string>("");
+
+  return (
+    <div className={styles.selectCommitContainer}>
+      <Dropdown value={selected} onChange={(e) => setSelected(e.target.value)}>
+        <option value="">{placeholder}</option>
+        {options.map((o) => (
+          <option key={o.id} value={o.id}>
+            {o.name}
+          </option>
+        ))}
+      </Dropdown>
+      <Button disabled={selected === ""} onClick={() => onCommit(selected)}>
+        Commit
+      </Button>
+    </div>
+  );
+};
@@ -3,9 +3,11 @@
 import { Button } from "@/components/elements/button/Button";
 import { StrategyCard } from "@/resources/types/strategyCards";
 import styles from "./ActionPhaseView.module.scss";
+import { GameOptions } from "@/api/bindings/GameOptions";
 import { GameState } from "@/api/bindings/GameState";
 import { ActionCard } from "@/api/bindings/ActionCard";
 import { Leader } from "@/api/bindings/Leader";
+import { RelicFragment } from "@/api/bindings/RelicFragment";
 import { useEffect, useState } from "react";
 import { Dropdown } from "@/components/elements/dropdown/Dropdown";
 import { InfoButton } from "@/components/elements/button/InfoButton";
@@ -13,9 +15,10 @@ import { useGameContext } from "@/hooks/GameContext";
 import { nameSort, stringSort } from "@/utils/Utils";
 
 export const ActionPhaseView = () => {
-  const { gameState, sendEvent, isActive } = useGameContext();
+  const { gameState, gameOptions, sendEvent, isActive } = useGameContext();
 
   const [isComponent, setIsComponent] = useState<boolean>(false);
+  const [activatedSystem, setActivatedSystem] = useState<string>("");
 
   useEffect(() => setIsComponent(false), [gameState]);
 
@@ -25,6 +28,15 @@ export const ActionPhaseView = () => {
     return <div>Invalid state, currentPlayer is null in action phase!</div>;
   }
 
+  const activatableSystems = Object.values(gameOptions.systems)
+    .filter((s) => s.systemType !== "Hyperlane")
+    .filter(
+      (s) =>
+        !gameState.mapData.systems[s.id]?.commandTokens.includes(currentPlayer),
+    )
+    .map((s) => s.id)
+    .sort(stringSort);
+
   const playableStrategyCards = getPlayableStrategyCards(
     gameState,
     currentPlayer,
@@ -78,12 +90,29 @@ export const ActionPhaseView = () => {
                 {c}
               </Button>
             ))}
+            {gameState.gameSettings.trackUnits && (
+              <Dropdown
+                value={activatedSystem}
+                onChange={(e) => setActivatedSystem(e.target.value)}
+              >
+                <option value="">--select a system--</option>
+                {activatableSystems.map((s) => (
+                  <option key={s} value={s}>
+                    {s}
+                  </option>
+                ))}
+              </Dropdown>
+            )}
             <Button
               className={styles.actionButton}
+              disabled={
+                gameState.gameSettings.trackUnits && activatedSystem === ""
+              }
               onClick={() =>
                 sendEvent({
                   TacticalActionBegin: {
                     player: gameState.currentPlayer,
+                    system: activatedSystem !== "" ? activatedSystem : null,
                   },
                 })
               }
@@ -113,6 +142,7 @@ type ComponentMode =
   | "GAIN_RELIC"
   | "FRONTIER_CARD"
   | "PLAY_LEADER"
+  | "PLAY_TECH"
   | "";
 
 const ComponentSelectRow = () => {
@@ -129,7 +159,13 @@ const ComponentSelectRow = () => {
   const actionLeaders = availableLeaders.filter(
     (l) => gameOptions.leaders[l].kind === "Action",
   );
-  const canPlayLeaders = actionLeaders.length > 0;
+  const ownLeaders = currentPlayer
+    ? Object.keys(gameState.players[currentPlayer].leaders)
+    : [];
+  const canPlayLeaders = actionLeaders.length > 0 || ownLeaders.length > 0;
+  const canPlayTechs =
+    currentPlayer !== null &&
+    readyActionTechs(gameState, gameOptions, currentPlayer).length > 0;
 
   return (
     <>
@@ -173,6 +209,12 @@ const ComponentSelectRow = () => {
         >
           Play Leader
         </Button>
+        <Button
+          disabled={componentMode === "PLAY_TECH" || !canPlayTechs}
+          onClick={() => setComponentMode("PLAY_TECH")}
+        >
+          Tech Action
+        </Button>
       </div>
       {componentMode !== "" && <DisplayComponentMode mode={componentMode} />}
     </>
@@ -195,6 +237,8 @@ const DisplayComponentMode = ({ mode }: DisplayComponentModeProps) => {
       return <FrontierCardView />;
     case "PLAY_LEADER":
       return <PlayLeaderView />;
+    case "PLAY_TECH":
+      return <TechActionSelectView />;
     default:
       return <p>Invalid display mode {mode}</p>;
   }
@@ -250,11 +294,32 @@ const GainRelicCardView = () => {
 
   const [selected, setSelected] = useState<string>("");
 
-  const takenRelics = Object.values(gameState.players).flatMap((p) => p.relics);
+  const takenRelics = Object.values(gameState.players).flatMap((p) => [
+    ...p.relics,
+    ...p.purgedRelics,
+  ]);
   const availableRelics = Object.values(gameOptions.relics)
     .filter((r) => !takenRelics.includes(r.card))
     .sort(nameSort);
 
+  const fragments = gameState.players[gameState.currentPlayer!!].relicFragments;
+  const unknownFragments = fragments.Unknown ?? 0;
+  const purgeableFragments = (
+    ["Cultural", "Hazardous", "Industrial"] as RelicFragment[]
+  )
+    .map((f) => {
+      const known = Math.min(fragments[f] ?? 0, 3);
+      return {
+        fragment: f,
+        toPurge: [
+          ...Array(known).fill(f),
+          ...Array(3 - known).fill("Unknown"),
+        ] as RelicFragment[],
+        possible: known + unknownFragments >= 3 && known > 0,
+      };
+    })
+    .filter((f) => f.possible);
+
   return (
     <div>
       <fieldset className={styles.playActionCardContainer}>
@@ -281,6 +346,26 @@ const GainRelicCardView = () => {
           Gain
         </Button>
       </fieldset>
+      {purgeableFragments.length > 0 && (
+        <fieldset className={styles.playActionCardContainer}>
+          <legend>Purge Relic Fragments</legend>
+          {purgeableFragments.map((f) => (
+            <Button
+              key={f.fragment}
+              onClick={() =>
+                sendEvent({
+                  PurgeRelicFragments: {
+                    player: gameState.currentPlayer,
+                    fragments: f.toPurge,
+                  },
+                })
+              }
+            >
+              Purge {f.fragment} fragments
+            </Button>
+          ))}
+        </fieldset>
+      )}
     </div>
   );
 };
@@ -333,6 +418,50 @@ const PlayRelicCardView = () => {
   );
 };
 
+const TechActionSelectView = () => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+
+  const [selected, setSelected] = useState<string>("");
+
+  const techs = readyActionTechs(
+    gameState,
+    gameOptions,
+    gameState.currentPlayer!!,
+  );
+
+  return (
+    <div>
+      <fieldset className={styles.playActionCardContainer}>
+        <legend>Tech Action</legend>
+        <Dropdown
+          value={selected}
+          onChange={(e) => setSelected(e.target.value)}
+        >
+          <option value="">--Select tech--</option>
+          {techs.map((t) => (
+            <option key={t.id} value={t.id}>
+              {t.name}
+            </option>
+          ))}
+        </Dropdown>
+        <Button
+          disabled={selected === ""}
+          onClick={() =>
+            sendEvent({
+              TechActionBegin: {
+                player: gameState.currentPlayer,
+                tech: selected,
+              },
+            })
+          }
+        >
+          Exhaust
+        </Button>
+      </fieldset>
+    </div>
+  );
+};
+
 const FrontierCardView = () => {
   const { gameState, gameOptions, sendEvent } = useGameContext();
 
@@ -387,6 +516,14 @@ const PlayLeaderView = () => {
   const actionLeaders = availableLeaders
     .map((l) => ({ id: l, info: gameOptions.leaders[l] }))
     .filter((leader) => leader.info.kind === "Action");
+  const leaderStates = currentPlayer
+    ? gameState.players[currentPlayer].leaders
+    : {};
+  const ownLeaders = (Object.keys(leaderStates) as Leader[]).map((l) => ({
+    id: l,
+    info: gameOptions.leaders[l],
+    state: leaderStates[l],
+  }));
 
   return (
     <div>
@@ -412,10 +549,161 @@ const PlayLeaderView = () => {
           ))}
         </table>
       </fieldset>
+      <fieldset className={styles.playActionCardContainer}>
+        <legend>Leaders</legend>
+        <table>
+          {ownLeaders.map((leader) => (
+            <tr key={leader.id}>
+              <td>{leader.info.name}</td>
+              <td>{leader.state}</td>
+              <td>
+                {leader.info.type === "Commander" &&
+                  leader.state === "Locked" && (
+                    <Button
+                      onClick={() =>
+                        sendEvent({
+                          UnlockCommander: {
+                            player: gameState.currentPlayer,
+                            commander: leader.id,
+                          },
+                        })
+                      }
+                    >
+                      Unlock
+                    </Button>
+                  )}
+                {leader.info.type !== "Commander" &&
+                  leader.info.kind !== "Action" &&
+                  (leader.state === "Ready" || leader.state === "Unlocked") && (
+                    <Button
+                      onClick={() =>
+                        sendEvent({
+                          UseLeader: {
+                            player: gameState.currentPlayer,
+                            leader: leader.id,
+                          },
+                        })
+                      }
+                    >
+                      Use
+                    </Button>
+                  )}
+              </td>
+              <InfoButton info={{ Leader: leader.info }} />
+            </tr>
+          ))}
+        </table>
+      </fieldset>
+      <AllianceView />
     </div>
   );
 };
 
+const AllianceView = () => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+
+  const [receiver, setReceiver] = useState<string>("");
+
+  const currentPlayer = gameState.currentPlayer;
+  if (!currentPlayer) {
+    return;
+  }
+
+  const availableLeaders = gameState.availableLeaders[currentPlayer] ?? [];
+  const allianceCommanders = availableLeaders.filter(
+    (l) =>
+      gameOptions.leaders[l].type === "Commander" &&
+      gameState.players[currentPlayer].leaders[l] === undefined,
+  );
+  const heldAlliances = gameState.players[
+    currentPlayer
+  ].promissoryNotes.filter((held) => held.note === "Alliance");
+  const ownAllianceGiven = Object.values(gameState.players).some((p) =>
+    p.promissoryNotes.some(
+      (held) => held.note === "Alliance" && held.owner === currentPlayer,
+    ),
+  );
+
+  return (
+    <fieldset className={styles.playActionCardContainer}>
+      <legend>Alliances</legend>
+      <table>
+        {heldAlliances.map((held) => (
+          <tr key={held.owner}>
+            <td>{held.owner}</td>
+            <td>
+              <Button
+                onClick={() =>
+                  sendEvent({
+                    ReturnPromissoryNote: {
+                      player: currentPlayer,
+                      owner: held.owner,
+                      note: "Alliance",
+                    },
+                  })
+                }
+              >
+                Return
+              </Button>
+            </td>
+          </tr>
+        ))}
+        {allianceCommanders.map((leader) => (
+          <tr key={leader}>
+            <td>{gameOptions.leaders[leader].name}</td>
+            <InfoButton info={{ Leader: gameOptions.leaders[leader] }} />
+          </tr>
+        ))}
+      </table>
+      {!ownAllianceGiven && (
+        <div>
+          <Dropdown
+            value={receiver}
+            onChange={(e) => setReceiver(e.target.value)}
+          >
+            <option value="">--give alliance to--</option>
+            {Object.keys(gameState.players)
+              .filter((p) => p !== currentPlayer)
+              .sort(stringSort)
+              .map((p) => (
+                <option key={p} value={p}>
+                  {p}
+                </option>
+              ))}
+          </Dropdown>
+          <Button
+            disabled={receiver === ""}
+            onClick={() =>
+              sendEvent({
+                GivePromissoryNote: {
+                  player: currentPlayer,
+                  receiver: receiver,
+                  note: "Alliance",
+                },
+              })
+            }
+          >
+            Give
+          </Button>
+        </div>
+      )}
+    </fieldset>
+  );
+};
+
+function readyActionTechs(
+  gameState: GameState,
+  gameOptions: GameOptions,
+  player: string,
+) {
+  const p = gameState.players[player];
+  return p.technologies
+    .filter((t) => !p.exhaustedTechnologies.includes(t))
+    .map((t) => ({ id: t, ...gameOptions.technologies[t] }))
+    .filter((t) => t.play === "Action")
+    .sort(nameSort);
+}
+
 function getPlayableStrategyCards(
   gameState: GameState,
   currentPlayer: string,
@@ -48,9 +48,12 @@ export const AgendaActionsView = ({ state }: AgendaActionsViewProps) => {
       };
     });
 
+  const blockedVoters = state.vote?.blockedVoters ?? [];
   const everyoneHasVoted =
     Object.keys(state.vote?.playerVotes ?? {}).length ===
-    players.filter((p) => p.faction !== "NekroVirus").length;
+    players.filter(
+      (p) => p.faction !== "NekroVirus" && !blockedVoters.includes(p.id),
+    ).length;
 
   const castVote = (
     player: string,
@@ -136,17 +139,19 @@ export const AgendaActionsView = ({ state }: AgendaActionsViewProps) => {
                 </li>
                 <li>
                   Vote:
-                  {players.map((p) => (
-                    <PlayerVoteView
-                      key={p.id}
-                      player={p}
-                      castVote={castVote}
-                      candidates={state.vote!!.candidates}
-                      playerVote={state.vote?.playerVotes[p.id]}
-                      voteKind={state.vote!!.elect}
-                      isCurrentOrGlobal={p.id === playingAs || isGlobal}
-                    />
-                  ))}
+                  {players
+                    .filter((p) => !blockedVoters.includes(p.id))
+                    .map((p) => (
+                      <PlayerVoteView
+                        key={p.id}
+                        player={p}
+                        castVote={castVote}
+                        candidates={state.vote!!.candidates}
+                        playerVote={state.vote?.playerVotes[p.id]}
+                        voteKind={state.vote!!.elect}
+                        isCurrentOrGlobal={p.id === playingAs || isGlobal}
+                      />
+                    ))}
                 </li>
                 {isSpeaker || isGlobal ? (
                   <li>
@@ -4,6 +4,7 @@ import { Color } from "@/api/bindings/Color";
 import { FactionResponse } from "@/api/bindings/FactionResponse";
 import { Player } from "@/api/bindings/Player";
 import { Faction } from "@/api/bindings/Faction";
+import { GalacticEvent } from "@/api/bindings/GalacticEvent";
 import { Button } from "@/components/elements/button/Button";
 import styles from "./CreationPhase.module.scss";
 import { FactionIcon } from "@/components/elements/factionIcon/FactionIcon";
@@ -64,6 +65,7 @@ export const CreationPhase = () => {
           addPlayer={addPlayer}
         />
       )}
+      <SelectGalacticEvents />
       <Button
         className={styles.startGameButton}
         disabled={!allowedNumberOfPlayers}
@@ -102,6 +104,46 @@ const DisplayPlayer = ({ player }: DisplayPlayerProps) => {
   );
 };
 
+const SelectGalacticEvents = () => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+
+  const selected = gameState.gameSettings.galacticEvents;
+  const events = Object.keys(gameOptions.galacticEvents)
+    .map((e) => e as GalacticEvent)
+    .map((e) => ({ id: e, ...gameOptions.galacticEvents[e] }))
+    .sort(nameSort);
+
+  if (events.length === 0) {
+    return null;
+  }
+
+  const toggleEvent = (event: GalacticEvent) =>
+    sendEvent({
+      SetGalacticEvents: {
+        events: selected.includes(event)
+          ? selected.filter((e) => e !== event)
+          : [...selected, event],
+      },
+    });
+
+  return (
+    <div className={styles.galacticEventsContainer}>
+      <h3>Galactic events</h3>
+      {events.map((e) => (
+        <div key={e.id}>
+          <input
+            type="checkbox"
+            id={`galactic-event-${e.id}`}
+            checked={selected.includes(e.id)}
+            onChange={() => toggleEvent(e.id)}
+          />
+          <label htmlFor={`galactic-event-${e.id}`}>{e.name}</label>
+        </div>
+      ))}
+    </div>
+  );
+};
+
 interface AddPlayerProps {
   availableFactions: FactionResponse[];
   colors: Color[];
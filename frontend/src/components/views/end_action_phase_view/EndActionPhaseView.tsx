@@ -5,11 +5,27 @@ import styles from "./EndActionPhaseView.module.scss";
 export const EndActionPhaseView = () => {
   const { gameState, sendEvent, isActive } = useGameContext();
 
+  const secretObjectivesToDraw =
+    gameState.players[gameState.currentPlayer!!].secretObjectivesToDraw;
+
   return (
     <div className={`card ${styles.endActionPhaseViewContainer}`}>
       <h2>End player turn?</h2>
       {isActive ? (
         <>
+          {secretObjectivesToDraw > 0 && (
+            <Button
+              onClick={() =>
+                sendEvent({
+                  DrawSecretObjective: {
+                    player: gameState.currentPlayer,
+                  },
+                })
+              }
+            >
+              Draw secret objective ({secretObjectivesToDraw})
+            </Button>
+          )}
           <Button
             onClick={() =>
               sendEvent({
@@ -14,12 +14,14 @@ import {
 import styles from "./GameView.module.scss";
 import { useRouter } from "next/navigation";
 import { PhaseView } from "../phase_view/PhaseView";
+import { ResponseActionCardView } from "../response_action_card_view/ResponseActionCardView";
 import { Button } from "@/components/elements/button/Button";
 import { InfoModal, InfoObject } from "../info_modal/InfoModal";
 import { ScoreViewMode } from "../score_view_mode/ScoreViewMode";
 import { TechViewMode } from "../tech_view_mode/TechViewMode";
 import { PlanetViewMode } from "../planet_view_mode/PlanetViewMode";
 import { LawsViewMode } from "../laws_view_mode/LawsViewMode";
+import { PromissoryNotesViewMode } from "../promissory_notes_view_mode/PromissoryNotesViewMode";
 import { GameContext, useGameContext } from "@/hooks/GameContext";
 import Link from "next/link";
 import { FontAwesomeIcon } from "@fortawesome/react-fontawesome";
@@ -35,7 +37,7 @@ interface GameViewProps {
   gameId: string;
 }
 
-type View = "Game" | "Score" | "Planets" | "Techs" | "Laws" | "Map";
+type View = "Game" | "Score" | "Planets" | "Techs" | "Laws" | "Notes" | "Map";
 
 export const GameView = ({ gameId, wsUri }: GameViewProps) => {
   const [error, setError] = useState<string | null>(null);
@@ -234,6 +236,12 @@ export const GameView = ({ gameId, wsUri }: GameViewProps) => {
           >
             Laws
           </Button>
+          <Button
+            onClick={() => setCurrentViewMode("Notes")}
+            disabled={currentViewMode === "Notes"}
+          >
+            Notes
+          </Button>
           <Button
             onClick={() => setCurrentViewMode("Map")}
             disabled={currentViewMode === "Map"}
@@ -466,6 +474,7 @@ const DisplayViewMode = ({ viewMode, wsUri }: DisplayViewModeProps) => {
           )}
           <div className={styles.phaseContainer}>
             <PhaseView />
+            <ResponseActionCardView />
           </div>
         </div>
       );
@@ -477,6 +486,8 @@ const DisplayViewMode = ({ viewMode, wsUri }: DisplayViewModeProps) => {
       return <PlanetViewMode />;
     case "Laws":
       return <LawsViewMode />;
+    case "Notes":
+      return <PromissoryNotesViewMode />;
     case "Map":
       return <MapViewMode wsUri={wsUri} />;
     default:
@@ -15,6 +15,7 @@ export const LawsViewMode = () => {
   return (
     <div className={styles.lawsViewContainer}>
       <ActiveLawsTable />
+      <ActiveGalacticEvents />
       <AddLawForm />
       <AgendaHistoryView />
     </div>
@@ -87,6 +88,27 @@ const ActiveLawsTable = () => {
   );
 };
 
+const ActiveGalacticEvents = () => {
+  const { gameState, gameOptions } = useGameContext();
+
+  const events = gameState.activeGalacticEvents
+    .map((e) => ({ id: e, ...gameOptions.galacticEvents[e] }))
+    .sort(nameSort);
+
+  if (events.length === 0) {
+    return null;
+  }
+
+  return (
+    <div className="card">
+      <h2>Galactic events</h2>
+      {events.map((e) => (
+        <p key={e.id}>{e.name}</p>
+      ))}
+    </div>
+  );
+};
+
 const AddLawForm = () => {
   const { gameState, gameOptions, sendEvent } = useGameContext();
 
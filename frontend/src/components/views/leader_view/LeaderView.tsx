@@ -0,0 +1,295 @@
+import { useGameContext } from "@/hooks/GameContext";
+import { Button } from "@/components/elements/button/Button";
+import { Dropdown } from "@/components/elements/dropdown/Dropdown";
+import { Leader } from "@/api/bindings/Leader";
+import { LeaderProgress } from "@/api/bindings/LeaderProgress";
+import { Planet } from "@/api/bindings/Planet";
+import { Agenda } from "@/api/bindings/Agenda";
+import { Technology } from "@/api/bindings/Technology";
+import { SelectTechView } from "../select_tech_view/SelectTechView";
+import { useState } from "react";
+import { nameSort } from "@/utils/Utils";
+
+const LEADER_BY_ACTION: Record<
+  Exclude<LeaderProgress["action"], "nothing">,
+  Leader
+> = {
+  rinTheMastersLegacy: "RinTheMastersLegacy",
+  unitDsgnFlayesh: "UnitDsgnFlayesh",
+  ulTheProgenitor: "UlTheProgenitor",
+  xxekirGrom: "XxekirGrom",
+  heshAndPrit: "HeshAndPrit",
+  harkaLeeds: "HarkaLeeds",
+  dannelOfTheTenthCxIII: "DannelOfTheTenthCxIII",
+  airoShirAur: "AiroShirAur",
+};
+
+const progressLeader = (progress: LeaderProgress): Leader =>
+  progress.action === "nothing"
+    ? progress.leader
+    : LEADER_BY_ACTION[progress.action];
+
+export const LeaderView = () => {
+  const { gameState, gameOptions, isActive } = useGameContext();
+
+  const progress = gameState.actionProgress!!;
+  if (progress.t !== "Leader") {
+    return;
+  }
+
+  const leader = progressLeader(progress);
+  const leaderInfo = gameOptions.leaders[leader];
+  return (
+    <div className="card column">
+      <h2>{leaderInfo.name}</h2>
+      {isActive ? (
+        <>
+          <p>{leaderInfo.description}</p>
+          <LeaderProgressView progress={progress} leader={leader} />
+        </>
+      ) : (
+        <p>Not your turn, currently {gameState.currentPlayer} is playing</p>
+      )}
+    </div>
+  );
+};
+
+interface LeaderProgressViewProps {
+  progress: LeaderProgress;
+  leader: Leader;
+}
+
+const LeaderProgressView = ({ progress, leader }: LeaderProgressViewProps) => {
+  const { gameState, sendEvent } = useGameContext();
+
+  const commit = (data?: object) =>
+    sendEvent({
+      LeaderActionCommit: {
+        player: gameState.currentPlayer,
+        leader: leader,
+        data: data ?? null,
+      },
+    });
+
+  switch (progress.action) {
+    case "rinTheMastersLegacy":
+      return <RinTheMastersLegacyView commit={commit} />;
+    case "unitDsgnFlayesh":
+      return <UnitDsgnFlayeshView commit={commit} />;
+    case "xxekirGrom":
+      return <XxekirGromView commit={commit} />;
+    case "dannelOfTheTenthCxIII":
+      return <DannelOfTheTenthView commit={commit} />;
+    case "airoShirAur":
+      return <AiroShirAurView commit={commit} />;
+    default:
+      return <Button onClick={() => commit()}>Commit</Button>;
+  }
+};
+
+interface CommitProps {
+  commit: (data?: object) => void;
+}
+
+const RinTheMastersLegacyView = ({ commit }: CommitProps) => {
+  const { gameState, gameOptions } = useGameContext();
+  const [replacements, setReplacements] = useState<
+    Partial<Record<Technology, Technology>>
+  >({});
+
+  const player = gameState.players[gameState.currentPlayer!!];
+  const replaceable = player.technologies
+    .map((t) => ({ id: t, ...gameOptions.technologies[t] }))
+    .filter((t) => t.techType !== "UnitUpgrade")
+    .sort(nameSort);
+
+  return (
+    <>
+      {replaceable.map((tech) => (
+        <div key={tech.id}>
+          <label>{tech.name}: </label>
+          <Dropdown
+            value={replacements[tech.id] ?? ""}
+            onChange={(e) =>
+              setReplacements({
+                ...replacements,
+                [tech.id]: e.target.value === "" ? undefined : e.target.value,
+              })
+            }
+          >
+            <option value="">--keep--</option>
+            {(Object.keys(gameOptions.technologies) as Technology[])
+              .map((t) => ({ id: t, ...gameOptions.technologies[t] }))
+              .filter(
+                (t) =>
+                  t.origin === "Base" &&
+                  JSON.stringify(t.techType) === JSON.stringify(tech.techType),
+              )
+              .filter((t) => !player.technologies.includes(t.id))
+              .sort(nameSort)
+              .map((t) => (
+                <option key={t.id} value={t.id}>
+                  {t.name}
+                </option>
+              ))}
+          </Dropdown>
+        </div>
+      ))}
+      <Button
+        onClick={() =>
+          commit({
+            RinTheMastersLegacy: {
+              replacements: Object.fromEntries(
+                Object.entries(replacements).filter(([, v]) => v),
+              ),
+            },
+          })
+        }
+      >
+        Commit
+      </Button>
+    </>
+  );
+};
+
+const UnitDsgnFlayeshView = ({ commit }: CommitProps) => {
+  const { gameState, gameOptions } = useGameContext();
+  const [planet, setPlanet] = useState<Planet | "">("");
+
+  const planets = (Object.keys(gameOptions.planetInfos) as Planet[])
+    .map((p) => ({ id: p, ...gameOptions.planetInfos[p] }))
+    .sort(nameSort);
+
+  return (
+    <>
+      <Dropdown
+        value={planet}
+        onChange={(e) => setPlanet(e.target.value as Planet | "")}
+      >
+        <option value="">--select a planet--</option>
+        {planets.map((p) => (
+          <option key={p.id} value={p.id}>
+            {p.name}
+          </option>
+        ))}
+      </Dropdown>
+      {planet !== "" && (
+        <SelectTechView
+          playerId={gameState.currentPlayer!!}
+          onSelect={(tech) =>
+            commit({ UnitDsgnFlayesh: { planet: planet, tech: tech } })
+          }
+        />
+      )}
+    </>
+  );
+};
+
+const XxekirGromView = ({ commit }: CommitProps) => {
+  const { gameState, gameOptions } = useGameContext();
+  const [law, setLaw] = useState<Agenda | "">("");
+
+  return (
+    <>
+      <Dropdown
+        value={law}
+        onChange={(e) => setLaw(e.target.value as Agenda | "")}
+      >
+        <option value="">--don&apos;t discard a law--</option>
+        {(Object.keys(gameState.laws) as Agenda[]).map((l) => (
+          <option key={l} value={l}>
+            {gameOptions.agendas[l].name}
+          </option>
+        ))}
+      </Dropdown>
+      <Button
+        onClick={() =>
+          commit({
+            XxekirGrom: { discardedLaw: law === "" ? null : law },
+          })
+        }
+      >
+        Commit
+      </Button>
+    </>
+  );
+};
+
+const DannelOfTheTenthView = ({ commit }: CommitProps) => {
+  const { gameState, gameOptions } = useGameContext();
+  const [planets, setPlanets] = useState<Planet[]>([]);
+
+  const ownPlanets = Object.keys(
+    gameState.players[gameState.currentPlayer!!].planets,
+  );
+  const available = (Object.keys(gameOptions.planetInfos) as Planet[])
+    .filter((p) => !ownPlanets.includes(p) && !planets.includes(p))
+    .map((p) => ({ id: p, ...gameOptions.planetInfos[p] }))
+    .sort(nameSort);
+
+  return (
+    <>
+      <p>
+        Invaded:{" "}
+        {planets.map((p) => gameOptions.planetInfos[p].name).join(", ")}
+      </p>
+      {planets.length < 3 && (
+        <Dropdown
+          value=""
+          onChange={(e) => setPlanets([...planets, e.target.value as Planet])}
+        >
+          <option value="">--add a planet--</option>
+          {available.map((p) => (
+            <option key={p.id} value={p.id}>
+              {p.name}
+            </option>
+          ))}
+        </Dropdown>
+      )}
+      <Button
+        onClick={() => commit({ DannelOfTheTenthCxIII: { planets: planets } })}
+      >
+        Commit
+      </Button>
+    </>
+  );
+};
+
+const AiroShirAurView = ({ commit }: CommitProps) => {
+  const { gameOptions } = useGameContext();
+  const [from, setFrom] = useState<string>("");
+  const [to, setTo] = useState<string>("");
+
+  const systems = Object.keys(gameOptions.systems).sort((a, b) =>
+    a.localeCompare(b, undefined, { numeric: true }),
+  );
+
+  return (
+    <>
+      <Dropdown value={from} onChange={(e) => setFrom(e.target.value)}>
+        <option value="">--move units from--</option>
+        {systems.map((s) => (
+          <option key={s} value={s}>
+            {s}
+          </option>
+        ))}
+      </Dropdown>
+      <Dropdown value={to} onChange={(e) => setTo(e.target.value)}>
+        <option value="">--move units to--</option>
+        {systems
+          .filter((s) => s !== from)
+          .map((s) => (
+            <option key={s} value={s}>
+              {s}
+            </option>
+          ))}
+      </Dropdown>
+      <Button
+        disabled={from === "" || to === ""}
+        onClick={() => commit({ AiroShirAur: { from: from, to: to } })}
+      >
+        Commit
+      </Button>
+    </>
+  );
+};
@@ -1,7 +1,8 @@
+import { Button } from "@/components/elements/button/Button";
 import { useGameContext } from "@/hooks/GameContext";
 
 export const MapViewMode = ({ wsUri }: { wsUri: string }) => {
-  const { gameId, gameState } = useGameContext();
+  const { gameId, gameState, sendEvent } = useGameContext();
 
   if (!gameState.mapData) {
     return (
@@ -22,6 +23,12 @@ export const MapViewMode = ({ wsUri }: { wsUri: string }) => {
         width="1280"
         height="720"
       ></iframe>
+      {gameState.gameSettings.expansions.thundersEdge &&
+        !gameState.mapData.fractureInPlay && (
+          <Button onClick={() => sendEvent("RevealFracture")}>
+            Reveal the Fracture
+          </Button>
+        )}
     </div>
   );
 };
@@ -11,7 +11,9 @@ import { useGameContext } from "@/hooks/GameContext";
 import { EndActionPhaseView } from "../end_action_phase_view/EndActionPhaseView";
 import { FrontierCardView } from "../frontier_card_view/FrontierCardView";
 import { RelicCardView } from "../relic_card_view/RelicCardView";
+import { LeaderView } from "../leader_view/LeaderView";
 import { RelicsPhaseView } from "../relics_phase_view/RelicsPhaseView";
+import { TechActionView } from "../tech_action_view/TechActionView";
 import { SetupPhase } from "../setup/Setup";
 
 export const PhaseView = () => {
@@ -36,6 +38,10 @@ export const PhaseView = () => {
       return <FrontierCardView />;
     case "RelicAction":
       return <RelicCardView />;
+    case "TechAction":
+      return <TechActionView />;
+    case "LeaderAction":
+      return <LeaderView />;
     case "EndActionTurn":
       return <EndActionPhaseView />;
     case "Status":
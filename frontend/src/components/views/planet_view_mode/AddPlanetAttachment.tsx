@@ -78,7 +78,9 @@ export const AddPlanetAttachment = () => {
             .filter(
               (a) =>
                 a.planetTrait === null ||
-                gameOptions.planetInfos[planet].planetTrait === a.planetTrait,
+                gameOptions.planetInfos[planet].planetTraits.includes(
+                  a.planetTrait,
+                ),
             )
             .filter(
               (a) =>
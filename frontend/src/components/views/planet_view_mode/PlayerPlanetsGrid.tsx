@@ -62,14 +62,14 @@ const PlayerPlanetsCard = ({ playerId, player }: PlayerPlanetsCardProps) => {
         return {
           cultural:
             tot.cultural +
-            (planet.planetTrait === "Cultural" ||
+            (planet.planetTraits.includes("Cultural") ||
             attachments.filter((a) => a.addedPlanetTraits.includes("Cultural"))
               .length > 0
               ? 1
               : 0),
           industrial:
             tot.industrial +
-            (planet.planetTrait === "Industrial" ||
+            (planet.planetTraits.includes("Industrial") ||
             attachments.filter((a) =>
               a.addedPlanetTraits.includes("Industrial"),
             ).length > 0
@@ -77,7 +77,7 @@ const PlayerPlanetsCard = ({ playerId, player }: PlayerPlanetsCardProps) => {
               : 0),
           hazardous:
             tot.hazardous +
-            (planet.planetTrait === "Hazardous" ||
+            (planet.planetTraits.includes("Hazardous") ||
             attachments.filter((a) => a.addedPlanetTraits.includes("Hazardous"))
               .length > 0
               ? 1
@@ -92,27 +92,27 @@ const PlayerPlanetsCard = ({ playerId, player }: PlayerPlanetsCardProps) => {
             attachments.reduce((acc, a) => acc + a.influence, 0),
           warfare:
             tot.warfare +
-            (planet.techSpecialty === "Warfare" ||
+            (planet.techSpecialities.includes("Warfare") ||
             attachments.filter((a) => a.techSpecialty === "Warfare").length > 0
               ? 1
               : 0),
           propulsion:
             tot.propulsion +
-            (planet.techSpecialty === "Propulsion" ||
+            (planet.techSpecialities.includes("Propulsion") ||
             attachments.filter((a) => a.techSpecialty === "Propulsion").length >
               0
               ? 1
               : 0),
           cybernetic:
             tot.cybernetic +
-            (planet.techSpecialty === "Cybernetic" ||
+            (planet.techSpecialities.includes("Cybernetic") ||
             attachments.filter((a) => a.techSpecialty === "Cybernetic").length >
               0
               ? 1
               : 0),
           biotic:
             tot.biotic +
-            (planet.techSpecialty === "Biotic" ||
+            (planet.techSpecialities.includes("Biotic") ||
             attachments.filter((a) => a.techSpecialty === "Biotic").length > 0
               ? 1
               : 0),
@@ -261,19 +261,20 @@ const PlayerPlanetRow = ({
       <tr className={styles.planetRow}>
         <td align={NAME_COL_ALIGN}>{planet.name}</td>
         <td align={TRAIT_COL_ALIGN}>
-          {planet.planetTrait && (
-            <Icon name={planet.planetTrait.toLowerCase() as IconType} />
-          )}
+          {planet.planetTraits.map((t) => (
+            <Icon key={t} name={t.toLowerCase() as IconType} />
+          ))}
         </td>
         <td align={RESOURCE_COL_ALIGN}>{planet.resources}</td>
         <td align={INFLUENCE_COL_ALIGN}>{planet.influence}</td>
         <td align={TECH_COL_ALIGN}>
-          {planet.techSpecialty && (
+          {planet.techSpecialities.map((t) => (
             <Icon
-              name={planet.techSpecialty.toLowerCase() as IconType}
+              key={t}
+              name={t.toLowerCase() as IconType}
               isFilled={true}
             />
-          )}
+          ))}
         </td>
         <td align={DELETE_COL_ALIGN}>
           <Button
@@ -94,7 +94,7 @@ function getPlanetTraitCount(
 ): number {
   return planets.filter(
     (p) =>
-      p.info.planetTrait === trait ||
+      p.info.planetTraits.includes(trait) ||
       p.attachments.filter((p) => p.addedPlanetTraits.includes(trait)).length >
         0,
   ).length;
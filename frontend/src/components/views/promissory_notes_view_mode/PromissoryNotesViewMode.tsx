@@ -0,0 +1,232 @@
+import { Button } from "@/components/elements/button/Button";
+import { Dropdown } from "@/components/elements/dropdown/Dropdown";
+import { FactionIcon } from "@/components/elements/factionIcon/FactionIcon";
+import { HeldPromissoryNote } from "@/api/bindings/HeldPromissoryNote";
+import { Planet } from "@/api/bindings/Planet";
+import { Player } from "@/api/bindings/Player";
+import { PromissoryNote } from "@/api/bindings/PromissoryNote";
+import React, { useState } from "react";
+import styles from "./PromissoryNotesViewMode.module.scss";
+import { useGameContext } from "@/hooks/GameContext";
+import { nameSort } from "@/utils/Utils";
+
+export const PromissoryNotesViewMode = () => {
+  const { gameState } = useGameContext();
+
+  const players = Object.keys(gameState.players)
+    .map((p) => {
+      return {
+        ...gameState.players[p],
+        id: p,
+      };
+    })
+    .sort(nameSort);
+
+  return (
+    <div className={styles.promissoryNotesViewContainer}>
+      <HeldPromissoryNotesTable players={players} />
+      <GivePromissoryNoteForm players={players} />
+    </div>
+  );
+};
+
+interface PlayersProps {
+  players: (Player & { id: string })[];
+}
+
+const HeldPromissoryNotesTable = ({ players }: PlayersProps) => {
+  return (
+    <div className="card">
+      <table className={styles.promissoryNotesTable}>
+        <thead>
+          <tr>
+            <th colSpan={5} align="center">
+              <h2>Promissory notes</h2>
+            </th>
+          </tr>
+        </thead>
+        <tbody>
+          {players.map((p) => (
+            <React.Fragment key={p.id}>
+              <tr>
+                <td>
+                  <FactionIcon faction={p.faction} />
+                </td>
+                <td colSpan={4}>
+                  <b>{p.name}</b>
+                </td>
+              </tr>
+              {p.promissoryNotes.map((held) => (
+                <HeldPromissoryNoteRow
+                  key={`${held.owner}-${held.note}`}
+                  player={p}
+                  held={held}
+                />
+              ))}
+            </React.Fragment>
+          ))}
+        </tbody>
+      </table>
+    </div>
+  );
+};
+
+interface HeldPromissoryNoteRowProps {
+  player: Player & { id: string };
+  held: HeldPromissoryNote;
+}
+
+const HeldPromissoryNoteRow = ({
+  player,
+  held,
+}: HeldPromissoryNoteRowProps) => {
+  const { gameOptions, sendEvent } = useGameContext();
+
+  const [planet, setPlanet] = useState<Planet | "">("");
+
+  const info = gameOptions.promissoryNotes[held.note];
+  const canPlay = !held.inPlayArea && info.usage !== "PlacedOnReceive";
+  const planets = (Object.keys(player.planets) as Planet[])
+    .map((p) => ({ id: p, ...gameOptions.planetInfos[p] }))
+    .sort(nameSort);
+
+  return (
+    <tr>
+      <td />
+      <td>{info.name}</td>
+      <td>
+        {held.owner} ({held.inPlayArea ? "play area" : "hand"})
+      </td>
+      <td>
+        {canPlay && (
+          <>
+            {info.usage === "Attach" && (
+              <Dropdown
+                value={planet}
+                onChange={(e) => setPlanet(e.target.value as Planet | "")}
+              >
+                <option value="">--Select Planet--</option>
+                {planets.map((p) => (
+                  <option key={p.id} value={p.id}>
+                    {p.name}
+                  </option>
+                ))}
+              </Dropdown>
+            )}
+            <Button
+              disabled={info.usage === "Attach" && planet === ""}
+              onClick={() =>
+                sendEvent({
+                  PlayPromissoryNote: {
+                    player: player.id,
+                    owner: held.owner,
+                    note: held.note,
+                    planet: planet === "" ? null : planet,
+                  },
+                })
+              }
+            >
+              Play
+            </Button>
+          </>
+        )}
+      </td>
+      <td>
+        <Button
+          onClick={() =>
+            sendEvent({
+              ReturnPromissoryNote: {
+                player: player.id,
+                owner: held.owner,
+                note: held.note,
+              },
+            })
+          }
+        >
+          Return
+        </Button>
+      </td>
+    </tr>
+  );
+};
+
+const GivePromissoryNoteForm = ({ players }: PlayersProps) => {
+  const { gameOptions, sendEvent } = useGameContext();
+
+  const [giver, setGiver] = useState<string>("");
+  const [note, setNote] = useState<PromissoryNote | "">("");
+  const [receiver, setReceiver] = useState<string>("");
+
+  const giverFaction = players.find((p) => p.id === giver)?.faction;
+  const alreadyGiven = players.flatMap((p) =>
+    p.promissoryNotes
+      .filter((held) => held.owner === giver)
+      .map((held) => held.note),
+  );
+  const notes = (Object.keys(gameOptions.promissoryNotes) as PromissoryNote[])
+    .map((n) => ({ id: n, ...gameOptions.promissoryNotes[n] }))
+    .filter((n) => n.faction === null || n.faction === giverFaction)
+    .filter((n) => !alreadyGiven.includes(n.id))
+    .sort(nameSort);
+
+  return (
+    <div className="card column">
+      <h2>Give Promissory Note</h2>
+      <div className={styles.formContainer}>
+        <Dropdown
+          value={giver}
+          onChange={(e) => {
+            setGiver(e.target.value);
+            setNote("");
+          }}
+        >
+          <option value="">--Select Giver--</option>
+          {players.map((p) => (
+            <option key={p.id} value={p.id}>
+              {p.name}
+            </option>
+          ))}
+        </Dropdown>
+        <Dropdown
+          value={note}
+          disabled={giver === ""}
+          onChange={(e) => setNote(e.target.value as PromissoryNote | "")}
+        >
+          <option value="">--Select Promissory Note--</option>
+          {notes.map((n) => (
+            <option key={n.id} value={n.id}>
+              {n.name}
+            </option>
+          ))}
+        </Dropdown>
+        <Dropdown
+          value={receiver}
+          onChange={(e) => setReceiver(e.target.value)}
+        >
+          <option value="">--Select Receiver--</option>
+          {players
+            .filter((p) => p.id !== giver)
+            .map((p) => (
+              <option key={p.id} value={p.id}>
+                {p.name}
+              </option>
+            ))}
+        </Dropdown>
+        <Button
+          disabled={giver === "" || note === "" || receiver === ""}
+          onClick={() =>
+            sendEvent({
+              GivePromissoryNote: {
+                player: giver,
+                receiver: receiver,
+                note: note,
+              },
+            })
+          }
+        >
+          Give
+        </Button>
+      </div>
+    </div>
+  );
+};
@@ -1,3 +1,4 @@
+import { ActionCard } from "@/api/bindings/ActionCard";
 import { Planet } from "@/api/bindings/Planet";
 import { RelicProgress } from "@/api/bindings/RelicProgress";
 import { Button } from "@/components/elements/button/Button";
@@ -39,6 +40,12 @@ const RelicProgressView = ({ progress }: RelicProgressViewProps) => {
       return <StellarConvertersView />;
     case "NanoForge":
       return <NanoForgeView />;
+    case "TheCodex":
+      return <TheCodexView />;
+    case "JrXs4550":
+      return <JrXs4550View />;
+    case "DynamisCore":
+      return <DynamisCoreView />;
     default:
       return (
         <Button
@@ -56,6 +63,46 @@ const RelicProgressView = ({ progress }: RelicProgressViewProps) => {
   }
 };
 
+const DynamisCoreView = () => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+  const [commodities, setCommodities] = useState<number>(0);
+
+  const faction = gameState.players[gameState.currentPlayer!!].faction;
+  const known = gameOptions.factions.find(
+    (f) => f.faction === faction,
+  )?.commodities;
+
+  return (
+    <div className="column">
+      {known === null && (
+        <label>
+          Commodity value:{" "}
+          <input
+            type="number"
+            min={0}
+            value={commodities}
+            onChange={(e) =>
+              setCommodities(Math.max(0, e.target.valueAsNumber || 0))
+            }
+          />
+        </label>
+      )}
+      <Button
+        onClick={() =>
+          sendEvent({
+            RelicActionCommit: {
+              player: gameState.currentPlayer,
+              data: known === null ? { DynamisCore: { commodities } } : null,
+            },
+          })
+        }
+      >
+        Commit
+      </Button>
+    </div>
+  );
+};
+
 const StellarConvertersView = () => {
   const { gameOptions, gameState, sendEvent } = useGameContext();
 
@@ -192,3 +239,109 @@ const NanoForgeView = () => {
     </fieldset>
   );
 };
+
+const TheCodexView = () => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+
+  const [cards, setCards] = useState<ActionCard[]>([]);
+
+  const availableCards = Object.keys(gameOptions.actionCards)
+    .map((c) => {
+      return c as ActionCard;
+    })
+    .map((c) => {
+      return {
+        id: c,
+        ...gameOptions.actionCards[c],
+      };
+    })
+    .sort(nameSort);
+
+  return (
+    <fieldset>
+      <legend>The Codex</legend>
+      <div className="column">
+        {cards.map((c, i) => (
+          <p key={i}>{gameOptions.actionCards[c].name}</p>
+        ))}
+        {cards.length < 3 && (
+          <Dropdown
+            value=""
+            onChange={(e) =>
+              setCards([...cards, e.target.value as ActionCard])
+            }
+          >
+            <option value="">--Select action card--</option>
+            {availableCards.map((c) => (
+              <option key={c.id} value={c.id}>
+                {c.name}
+              </option>
+            ))}
+          </Dropdown>
+        )}
+        <Button
+          className="marginTop"
+          onClick={() =>
+            sendEvent({
+              RelicActionCommit: {
+                player: gameState.currentPlayer,
+                data: {
+                  TheCodex: {
+                    cards: cards,
+                  },
+                },
+              },
+            })
+          }
+        >
+          Commit
+        </Button>
+      </div>
+    </fieldset>
+  );
+};
+
+const JrXs4550View = () => {
+  const { gameState, sendEvent } = useGameContext();
+
+  const [player, setPlayer] = useState<string>("");
+
+  const commit = (placedStructure: boolean) =>
+    sendEvent({
+      RelicActionCommit: {
+        player: gameState.currentPlayer,
+        data: {
+          JrXs4550: {
+            player: player,
+            placedStructure: placedStructure,
+          },
+        },
+      },
+    });
+
+  return (
+    <fieldset>
+      <legend>JR-XS455-0</legend>
+      <div className="column">
+        <Dropdown value={player} onChange={(e) => setPlayer(e.target.value)}>
+          <option value="">--Select player--</option>
+          {Object.keys(gameState.players).map((p) => (
+            <option key={p} value={p}>
+              {p}
+            </option>
+          ))}
+        </Dropdown>
+        <Button
+          className="marginTop"
+          disabled={player === ""}
+          onClick={() => commit(true)}
+        >
+          Placed structure
+        </Button>
+        <Button disabled={player === ""} onClick={() => commit(false)}>
+          Gained trade good
+        </Button>
+      </div>
+    </fieldset>
+  );
+};
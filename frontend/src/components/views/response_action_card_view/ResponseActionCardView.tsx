@@ -0,0 +1,257 @@
+import { ActionCard } from "@/api/bindings/ActionCard";
+import { ActionCardPlay } from "@/api/bindings/ActionCardPlay";
+import { GameOptions } from "@/api/bindings/GameOptions";
+import { GameState } from "@/api/bindings/GameState";
+import { Planet } from "@/api/bindings/Planet";
+import { Unit } from "@/api/bindings/Unit";
+import { Button } from "@/components/elements/button/Button";
+import { Dropdown } from "@/components/elements/dropdown/Dropdown";
+import { useEffect, useState } from "react";
+import styles from "./ResponseActionCardView.module.scss";
+import { useGameContext } from "@/hooks/GameContext";
+import { nameSort, stringSort } from "@/utils/Utils";
+
+export const ResponseActionCardView = () => {
+  const { gameState, gameOptions, sendEvent, isGlobal, playingAs } =
+    useGameContext();
+
+  const [player, setPlayer] = useState<string>("");
+  const [card, setCard] = useState<ActionCard | "">("");
+  const [ghostSquad, setGhostSquad] = useState<GhostSquadMove>(
+    emptyGhostSquadMove,
+  );
+
+  useEffect(() => {
+    setCard("");
+    setGhostSquad(emptyGhostSquadMove);
+  }, [gameState]);
+
+  const playerId = isGlobal ? player : (playingAs ?? "");
+  const playedThisPhase = gameState.playedActionCards.filter(
+    (p) => p.round === gameState.round && p.phase === gameState.phase,
+  );
+  const cards = (Object.keys(gameOptions.actionCards) as ActionCard[])
+    .map((c) => gameOptions.actionCards[c])
+    .filter((c) => isInWindow(gameState, playerId, c.card, c.play))
+    .sort(nameSort);
+
+  if (cards.length === 0 && playedThisPhase.length === 0) {
+    return null;
+  }
+
+  const ghostSquadPlanets = ghostSquadPlanetOptions(
+    gameState,
+    gameOptions,
+    playerId,
+  ).map((p) => ({ id: p, name: gameOptions.planetInfos[p].name }));
+  const withGhostSquad = card === "GhostSquad" && ghostSquadPlanets.length > 0;
+  const ghostSquadReady =
+    ghostSquad.from !== "" &&
+    ghostSquad.to !== "" &&
+    ghostSquad.from !== ghostSquad.to &&
+    ghostSquad.count > 0;
+
+  return (
+    <div className={`card ${styles.responseContainer}`}>
+      <h2>Action Cards</h2>
+      {playedThisPhase.length > 0 && (
+        <ul className={styles.playedCardsList}>
+          {playedThisPhase.map((p, i) => (
+            <li
+              key={i}
+              className={p.cancelled ? styles.cancelled : undefined}
+            >
+              {p.player}: {gameOptions.actionCards[p.card].name}
+            </li>
+          ))}
+        </ul>
+      )}
+      {isGlobal && (
+        <Dropdown value={player} onChange={(e) => setPlayer(e.target.value)}>
+          <option value="">--Select Player--</option>
+          {Object.keys(gameState.players)
+            .sort(stringSort)
+            .map((p) => (
+              <option key={p} value={p}>
+                {p}
+              </option>
+            ))}
+        </Dropdown>
+      )}
+      <Dropdown
+        value={card}
+        disabled={playerId === ""}
+        onChange={(e) => setCard(e.target.value as ActionCard | "")}
+      >
+        <option value="">--Select an Action Card--</option>
+        {cards.map((c) => (
+          <option key={c.card} value={c.card}>
+            {c.name}
+          </option>
+        ))}
+      </Dropdown>
+      {withGhostSquad && (
+        <>
+          <Dropdown
+            value={ghostSquad.from}
+            onChange={(e) =>
+              setGhostSquad({ ...ghostSquad, from: e.target.value as Planet })
+            }
+          >
+            <option value="">--Move from--</option>
+            {ghostSquadPlanets.map((p) => (
+              <option key={p.id} value={p.id}>
+                {p.name}
+              </option>
+            ))}
+          </Dropdown>
+          <Dropdown
+            value={ghostSquad.to}
+            onChange={(e) =>
+              setGhostSquad({ ...ghostSquad, to: e.target.value as Planet })
+            }
+          >
+            <option value="">--Move to--</option>
+            {ghostSquadPlanets.map((p) => (
+              <option key={p.id} value={p.id}>
+                {p.name}
+              </option>
+            ))}
+          </Dropdown>
+          <Dropdown
+            value={ghostSquad.unit}
+            onChange={(e) =>
+              setGhostSquad({ ...ghostSquad, unit: e.target.value as Unit })
+            }
+          >
+            <option value="Infantry">Infantry</option>
+            <option value="Mech">Mech</option>
+          </Dropdown>
+          <input
+            type="number"
+            min={1}
+            value={ghostSquad.count}
+            onChange={(e) =>
+              setGhostSquad({ ...ghostSquad, count: Number(e.target.value) })
+            }
+          />
+        </>
+      )}
+      <Button
+        disabled={
+          playerId === "" || card === "" || (withGhostSquad && !ghostSquadReady)
+        }
+        onClick={() =>
+          sendEvent({
+            PlayActionCard: {
+              player: playerId,
+              card: card,
+              data: withGhostSquad
+                ? {
+                    GhostSquad: {
+                      from: ghostSquad.from,
+                      to: ghostSquad.to,
+                      units: { [ghostSquad.unit]: ghostSquad.count },
+                    },
+                  }
+                : null,
+            },
+          })
+        }
+      >
+        Play
+      </Button>
+    </div>
+  );
+};
+
+interface GhostSquadMove {
+  from: Planet | "";
+  to: Planet | "";
+  unit: Unit;
+  count: number;
+}
+
+const emptyGhostSquadMove: GhostSquadMove = {
+  from: "",
+  to: "",
+  unit: "Infantry",
+  count: 1,
+};
+
+/**
+ * The planets the player controls in the active system, when units are tracked.
+ */
+function ghostSquadPlanetOptions(
+  gameState: GameState,
+  gameOptions: GameOptions,
+  player: string,
+): Planet[] {
+  const progress = gameState.actionProgress;
+  if (
+    !gameState.gameSettings.trackUnits ||
+    player === "" ||
+    progress?.t !== "Tactical" ||
+    progress.activatedSystem === null
+  ) {
+    return [];
+  }
+  const planets = gameOptions.systems[progress.activatedSystem]?.planets ?? [];
+  return planets.filter(
+    (p) => gameState.players[player].planets[p] !== undefined,
+  );
+}
+
+/**
+ * Weather the player can play an action card with the given timing right now.
+ */
+function isInWindow(
+  gameState: GameState,
+  player: string,
+  card: ActionCard,
+  play: ActionCardPlay,
+): boolean {
+  const last = gameState.playedActionCards.at(-1);
+  const lastInPhase =
+    last !== undefined &&
+    last.round === gameState.round &&
+    (last.phase === gameState.phase ||
+      (last.resolved && gameState.phase === "EndActionTurn"))
+      ? last
+      : undefined;
+
+  if (typeof play === "object") {
+    if ("StartOfPhase" in play) {
+      return gameState.phase === play.StartOfPhase;
+    }
+    return gameState.phase === "Agenda" && !!gameState.agenda?.vote;
+  }
+
+  switch (play) {
+    case "StatusPhaseReturnStrategyCards":
+      return gameState.phase === "Status";
+    case "AfterAction":
+      return (
+        gameState.phase === "EndActionTurn" &&
+        gameState.currentPlayer === player
+      );
+    case "AfterActionCardIsPlayed":
+      return (
+        lastInPhase !== undefined &&
+        !lastInPhase.cancelled &&
+        lastInPhase.player !== player &&
+        !(card === "Sabotage" && lastInPhase.card === "Sabotage")
+      );
+    case "AfterStrategyCardIsPlayed":
+      return (
+        gameState.phase === "StrategicAction" &&
+        gameState.currentPlayer !== player
+      );
+    case "StrategyCardIsChosen":
+      return gameState.phase === "Strategy";
+    case "TacticalAction":
+      return gameState.phase === "TacticalAction";
+    default:
+      return false;
+  }
+}
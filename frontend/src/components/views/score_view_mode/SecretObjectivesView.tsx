@@ -82,6 +82,10 @@ const PlayerSecretView = ({
     .filter((o) => !allTakenSecrets.includes(o.id as SecretObjective))
     .sort(nameSort);
 
+  const opportunities = (
+    gameState.secretObjectiveOpportunities[playerId] ?? []
+  ).sort(stringSort);
+
   return (
     <div>
       <div className={styles.playerSecretTitleRow}>
@@ -108,6 +112,24 @@ const PlayerSecretView = ({
           </Button>
         </div>
       ))}
+      {opportunities.map((secret) => (
+        <div key={secret} className={styles.secretObjectiveRow}>
+          {gameOptions.objectives[secret].name}
+          <InfoButton info={{ Objective: gameOptions.objectives[secret] }} />
+          <Button
+            onClick={() =>
+              sendEvent({
+                ScoreExtraSecretObjective: {
+                  player: playerId,
+                  objective: secret,
+                },
+              })
+            }
+          >
+            Score
+          </Button>
+        </div>
+      ))}
       <Dropdown
         value={secret}
         onChange={(e) => setSecret(e.target.value as SecretObjective | "")}
@@ -181,5 +181,6 @@ function getAvailableTechs(
 
       return t.info.origin.Faction === playerFaction;
     })
+    .filter((t) => !taken.some((owned) => owned.info.replaces === t.tech))
     .sort((a, b) => nameSort(a.info, b.info));
 }
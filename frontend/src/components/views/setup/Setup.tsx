@@ -1,6 +1,10 @@
 import { Player } from "@/api/bindings/Player";
 import { Objective } from "@/api/bindings/Objective";
 import { Technology } from "@/api/bindings/Technology";
+import { Faction } from "@/api/bindings/Faction";
+import { StartingTechChoice } from "@/api/bindings/StartingTechChoice";
+import { StartingTechPool } from "@/api/bindings/StartingTechPool";
+import { Unit } from "@/api/bindings/Unit";
 import { PublicObjective } from "@/api/bindings/PublicObjective";
 import { Button } from "@/components/elements/button/Button";
 import styles from "./Setup.module.scss";
@@ -95,6 +99,7 @@ export const SetupPhase = () => {
                   .name
               }
               <FactionSpecificSetup player={p} />
+              <StartingUnits player={p} />
             </fieldset>
           ))}
         </div>
@@ -171,262 +176,198 @@ interface FactionSpecificSetupProps {
 }
 
 const FactionSpecificSetup = ({ player }: FactionSpecificSetupProps) => {
-  switch (player.faction) {
-    case "Winnu":
-      return <WinnuSetup player={player} />;
-    case "ArgentFlight":
-      return <ArgentFlightSetup player={player} />;
-    case "CouncilKeleres":
-      return <CouncilKeleresSetup player={player} />;
-    default:
-      return <div>No faction specific setup</div>;
+  const { gameOptions } = useGameContext();
+
+  const setup = gameOptions.factions.find(
+    (f) => f.faction === player.faction,
+  )?.setup;
+
+  if (
+    setup === undefined ||
+    (setup.techs === null && setup.homeSystemChoices.length === 0)
+  ) {
+    return <div>No faction specific setup</div>;
   }
+
+  return (
+    <div className={styles.setupColumn}>
+      {setup.techs !== null && (
+        <StartingTechsSetup player={player} choice={setup.techs} />
+      )}
+      {setup.homeSystemChoices.length > 0 && (
+        <HomeSystemSetup player={player} choices={setup.homeSystemChoices} />
+      )}
+    </div>
+  );
 };
 
-const WinnuSetup = ({ player }: FactionSpecificSetupProps) => {
-  const { gameOptions, sendEvent } = useGameContext();
+const StartingUnits = ({ player }: FactionSpecificSetupProps) => {
+  const { gameOptions } = useGameContext();
 
-  const [selectedTech, setSelectedTech] = useState<string>("");
+  const faction = gameOptions.factions.find(
+    (f) => f.faction === player.faction,
+  );
+  const startingUnits = faction?.setup.startingUnits ?? null;
+  if (faction === undefined || startingUnits === null) {
+    return null;
+  }
 
-  const availableTechs = Object.keys(gameOptions.technologies)
-    .map((t) => {
-      return t as Technology;
-    })
-    .map((t) => {
-      return {
-        id: t,
-        ...gameOptions.technologies[t],
-      };
-    })
-    .filter((t) => t.origin === "Base")
-    .filter((t) => Object.keys(t.requirements).length === 0)
-    .sort(nameSort);
+  const units = Object.entries(startingUnits).map(
+    ([unit, count]) => `${count} ${gameOptions.units[unit as Unit].name}`,
+  );
 
   return (
-    <div>
-      {player.technologies.length === 0 ? (
-        <div className={styles.setupColumn}>
-          <Dropdown
-            value={selectedTech}
-            onChange={(e) => setSelectedTech(e.target.value)}
-          >
-            <option value="">--Select technology--</option>
-            {availableTechs.map((t) => (
-              <option key={t.id} value={t.id}>
-                {t.name}
-              </option>
-            ))}
-          </Dropdown>
-          <Button
-            disabled={selectedTech === ""}
-            onClick={() =>
-              sendEvent({
-                SetupPlayerTechs: {
-                  player: player.id,
-                  technologies: [selectedTech],
-                },
-              })
-            }
-          >
-            Select
-          </Button>
-        </div>
-      ) : (
-        <p>{gameOptions.technologies[player.technologies[0]].name}</p>
-      )}
+    <div className={styles.setupColumn}>
+      <p>Starting units: {units.join(", ")}</p>
+      {faction.flagship !== null && <p>Flagship: {faction.flagship.name}</p>}
+      {faction.mech !== null && <p>Mech: {faction.mech.name}</p>}
     </div>
   );
 };
 
-const ArgentFlightSetup = ({ player }: FactionSpecificSetupProps) => {
-  const { gameOptions, sendEvent } = useGameContext();
+interface StartingTechsSetupProps extends FactionSpecificSetupProps {
+  choice: StartingTechChoice;
+}
 
-  const [firstTech, setFirstTech] = useState<Technology | "">("");
-  const [secondTech, setSecondTech] = useState<Technology | "">("");
+const StartingTechsSetup = ({ player, choice }: StartingTechsSetupProps) => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
 
-  const takenTechs = player.technologies
-    .map((t) => {
-      return {
-        id: t,
-        ...gameOptions.technologies[t],
-      };
-    })
-    .sort(nameSort);
-  const possibleTechs = ["NeuralMotivator", "SarweenTools", "PlasmaScoring"]
-    .map((t) => {
-      return t as Technology;
-    })
+  const [selectedTechs, setSelectedTechs] = useState<(Technology | "")[]>(
+    Array(choice.count).fill(""),
+  );
+
+  const poolTechs = (pool: StartingTechPool): Technology[] => {
+    const allTechs = Object.keys(gameOptions.technologies).map(
+      (t) => t as Technology,
+    );
+    if (pool === "NoPrerequisites") {
+      return allTechs.filter(
+        (t) =>
+          gameOptions.technologies[t].origin === "Base" &&
+          Object.keys(gameOptions.technologies[t].requirements).length === 0,
+      );
+    }
+    if (pool === "OtherPlayers") {
+      return Object.keys(gameState.players)
+        .filter((p) => p !== player.id)
+        .flatMap((p) => gameState.players[p].technologies)
+        .filter((t) => gameOptions.technologies[t].origin === "Base");
+    }
+    if (pool === "Research") {
+      return allTechs;
+    }
+    return pool.Listed;
+  };
+
+  const possibleTechs = poolTechs(choice.pool)
+    .filter((t, i, techs) => techs.indexOf(t) === i)
     .map((t) => {
       return { id: t, ...gameOptions.technologies[t] };
     })
     .sort(nameSort);
 
-  return (
-    <div className={styles.setupColumn}>
-      {player.technologies.length > 0 ? (
-        <>
-          {takenTechs.map((t) => (
-            <p key={t.id}>{t.name}</p>
-          ))}
-        </>
-      ) : (
-        <>
-          <Dropdown
-            value={firstTech}
-            onChange={(e) => setFirstTech(e.target.value as Technology | "")}
-          >
-            <option value="">--Select technology--</option>
-            {possibleTechs
-              .filter((t) => t.id !== secondTech)
-              .map((t) => (
-                <option key={t.id} value={t.id}>
-                  {t.name}
-                </option>
-              ))}
-          </Dropdown>
-          <Dropdown
-            value={secondTech}
-            onChange={(e) => setSecondTech(e.target.value as Technology | "")}
-          >
-            <option value="">--Select technology--</option>
-            {possibleTechs
-              .filter((t) => t.id !== firstTech)
-              .map((t) => (
-                <option key={t.id} value={t.id}>
-                  {t.name}
-                </option>
-              ))}
-          </Dropdown>
+  if (player.technologies.length > 0) {
+    return (
+      <>
+        {player.technologies.map((t) => (
+          <p key={t}>{gameOptions.technologies[t].name}</p>
+        ))}
+      </>
+    );
+  }
 
-          <Button
-            disabled={firstTech === "" || secondTech === ""}
-            onClick={() =>
-              sendEvent({
-                SetupPlayerTechs: {
-                  player: player.id,
-                  technologies: [firstTech, secondTech],
-                },
-              })
-            }
-          >
-            Choose
-          </Button>
-        </>
-      )}
-    </div>
+  return (
+    <>
+      {selectedTechs.map((selected, i) => (
+        <Dropdown
+          key={i}
+          value={selected}
+          onChange={(e) =>
+            setSelectedTechs(
+              selectedTechs.map((t, j) =>
+                i === j ? (e.target.value as Technology | "") : t,
+              ),
+            )
+          }
+        >
+          <option value="">--Select technology--</option>
+          {possibleTechs
+            .filter((t) => t.id === selected || !selectedTechs.includes(t.id))
+            .map((t) => (
+              <option key={t.id} value={t.id}>
+                {t.name}
+              </option>
+            ))}
+        </Dropdown>
+      ))}
+      <Button
+        disabled={selectedTechs.includes("")}
+        onClick={() =>
+          sendEvent({
+            SetupPlayerTechs: {
+              player: player.id,
+              technologies: selectedTechs,
+            },
+          })
+        }
+      >
+        {choice.pool === "Research" ? "Research" : "Select technology"}
+      </Button>
+    </>
   );
 };
 
-const CouncilKeleresSetup = ({ player }: FactionSpecificSetupProps) => {
+interface HomeSystemSetupProps extends FactionSpecificSetupProps {
+  choices: Faction[];
+}
+
+const HomeSystemSetup = ({ player, choices }: HomeSystemSetupProps) => {
   const { gameState, gameOptions, sendEvent } = useGameContext();
 
-  const [firstTech, setFirstTech] = useState<string>("");
-  const [secondTech, setSecondTech] = useState<string>("");
   const [selectedFaction, setSelectedFaction] = useState<string>("");
 
-  const possibleTechs = Object.keys(gameState.players)
-    .filter((p) => p !== player.name)
-    .map((p) => gameState.players[p])
-    .flatMap((p) => p.technologies)
-    .map((t) => {
-      return {
-        id: t,
-        ...gameOptions.technologies[t],
-      };
-    })
-    .filter((t) => t.origin === "Base")
-    .sort(nameSort);
-
   const takenFactions = Object.values(gameState.players).map((p) => p.faction);
   const possibleFactions = gameOptions.factions
-    .filter(
-      (f) =>
-        f.faction === "MentakCoalition" ||
-        f.faction === "XxchaKingdom" ||
-        f.faction === "ArgentFlight",
-    )
+    .filter((f) => choices.includes(f.faction))
     .filter((f) => !takenFactions.includes(f.faction))
     .sort(nameSort);
 
-  return (
-    <div className={styles.setupColumn}>
-      {player.technologies.length === 0 ? (
-        <>
-          <Dropdown onChange={(e) => setFirstTech(e.target.value)}>
-            <option value="">--Select technology--</option>
-            {possibleTechs
-              .filter((t) => t.id !== secondTech)
-              .map((t) => (
-                <option key={t.id} value={t.id}>
-                  {t.name}
-                </option>
-              ))}
-          </Dropdown>
-          <Dropdown onChange={(e) => setSecondTech(e.target.value)}>
-            <option value="">--Select technology--</option>
-            {possibleTechs
-              .filter((t) => t.id !== firstTech)
-              .map((t) => (
-                <option key={t.id} value={t.id}>
-                  {t.name}
-                </option>
-              ))}
-          </Dropdown>
-          <Button
-            disabled={firstTech === "" || secondTech === ""}
-            onClick={() =>
-              sendEvent({
-                SetupPlayerTechs: {
-                  player: player.id,
-                  technologies: [firstTech, secondTech],
-                },
-              })
-            }
-          >
-            Select technology
-          </Button>
-        </>
-      ) : (
-        <>
-          <p>{gameOptions.technologies[player.technologies[0]].name}</p>
-          <p>{gameOptions.technologies[player.technologies[1]].name}</p>
-        </>
-      )}
+  if (Object.keys(player.planets).length > 0) {
+    return (
+      <>
+        {Object.keys(player.planets).map((p) => (
+          <p key={p}>{p}</p>
+        ))}
+      </>
+    );
+  }
 
-      {Object.keys(player.planets).length === 0 ? (
-        <>
-          <Dropdown
-            value={selectedFaction}
-            onChange={(e) => setSelectedFaction(e.target.value)}
-          >
-            <option value="">--Select faction--</option>
-            {possibleFactions.map((f) => (
-              <option value={f.faction} key={f.faction}>
-                {f.name}
-              </option>
-            ))}
-          </Dropdown>
-          <Button
-            disabled={selectedFaction === ""}
-            onClick={() =>
-              sendEvent({
-                SetupTheTribunii: {
-                  player: player.id,
-                  faction: selectedFaction,
-                },
-              })
-            }
-          >
-            Select faction
-          </Button>
-        </>
-      ) : (
-        <>
-          {Object.keys(player.planets).map((p) => (
-            <p key={p}>{p}</p>
-          ))}
-        </>
-      )}
-    </div>
+  return (
+    <>
+      <Dropdown
+        value={selectedFaction}
+        onChange={(e) => setSelectedFaction(e.target.value)}
+      >
+        <option value="">--Select faction--</option>
+        {possibleFactions.map((f) => (
+          <option value={f.faction} key={f.faction}>
+            {f.name}
+          </option>
+        ))}
+      </Dropdown>
+      <Button
+        disabled={selectedFaction === ""}
+        onClick={() =>
+          sendEvent({
+            SetupTheTribunii: {
+              player: player.id,
+              faction: selectedFaction,
+            },
+          })
+        }
+      >
+        Select faction
+      </Button>
+    </>
   );
 };
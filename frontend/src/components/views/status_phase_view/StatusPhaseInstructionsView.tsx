@@ -8,6 +8,7 @@ export const StatusPhaseInstructionsView = () => {
   // Relies on this only being true iff objectives have already been scored which should be handled in the BE.
   const statusPhaseComplete =
     gameState.statusPhaseState!!.revealedObjective !== null;
+  const actionCardsToDraw = gameState.statusPhaseState!!.actionCardsToDraw;
 
   return (
     <div className={`card ${styles.statusViewCard}`}>
@@ -15,7 +16,36 @@ export const StatusPhaseInstructionsView = () => {
       <ol className={styles.statusStepList}>
         <li>Score Objectives</li>
         <li>Reveal Public Objective</li>
-        <li>Draw Action Cards</li>
+        <li>
+          Draw Action Cards
+          <ul>
+            {Object.entries(actionCardsToDraw).map(([player, count]) => (
+              <li key={player}>
+                {player}: draw {count}, holding{" "}
+                {gameState.players[player].actionCards}
+                {gameState.players[player].actionCardsToDiscard > 0 && (
+                  <>
+                    , discard{" "}
+                    {gameState.players[player].actionCardsToDiscard}{" "}
+                    <Button
+                      onClick={() =>
+                        sendEvent({
+                          DiscardActionCards: {
+                            player,
+                            count: gameState.players[player]
+                              .actionCardsToDiscard,
+                          },
+                        })
+                      }
+                    >
+                      Discard
+                    </Button>
+                  </>
+                )}
+              </li>
+            ))}
+          </ul>
+        </li>
         <li>Remove Command Tokens</li>
         <li>Gain and Redistribute Tokens</li>
         <li>Ready Cards</li>
@@ -0,0 +1,244 @@
+import { Planet } from "@/api/bindings/Planet";
+import { SystemUnits } from "@/api/bindings/SystemUnits";
+import { Unit } from "@/api/bindings/Unit";
+import { UnitLocation } from "@/api/bindings/UnitLocation";
+import { Button } from "@/components/elements/button/Button";
+import { Dropdown } from "@/components/elements/dropdown/Dropdown";
+import { useState } from "react";
+import styles from "./TacticalView.module.scss";
+import { useGameContext } from "@/hooks/GameContext";
+
+type UnitMode = "MOVE" | "LAND" | "PRODUCE" | "PLACE" | "REMOVE" | "COMBAT";
+
+interface LocationOption {
+  key: string;
+  label: string;
+  location: UnitLocation;
+}
+
+const spaceOption = (system: string): LocationOption => ({
+  key: `space-${system}`,
+  label: `Space (${system})`,
+  location: { Space: system },
+});
+
+const planetOption = (planet: Planet): LocationOption => ({
+  key: `planet-${planet}`,
+  label: planet,
+  location: { Planet: planet },
+});
+
+/* Locations in the system that hold units of the player. */
+const playerLocations = (
+  system: string,
+  units: SystemUnits,
+  player: string,
+): LocationOption[] => [
+  ...(units.space[player] !== undefined ? [spaceOption(system)] : []),
+  ...(Object.keys(units.planets) as Planet[])
+    .filter((p) => units.planets[p][player] !== undefined)
+    .map(planetOption),
+];
+
+interface Props {
+  system: string;
+}
+
+export const ActivatedSystemUnits = ({ system }: Props) => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+
+  const [mode, setMode] = useState<UnitMode>("MOVE");
+  const [owner, setOwner] = useState<string>("");
+  const [winner, setWinner] = useState<string>("");
+  const [locationKey, setLocationKey] = useState<string>("");
+  const [unit, setUnit] = useState<Unit | "">("");
+  const [count, setCount] = useState<number>(1);
+
+  const player = gameState.currentPlayer!!;
+  const systems = gameState.mapData.systems;
+  const activated = systems[system] ?? {
+    space: {},
+    planets: {} as SystemUnits["planets"],
+    commandTokens: [],
+  };
+  const planets = gameOptions.systems[system]?.planets ?? [];
+
+  const unitsText = (units: { [key in Unit]?: number }) =>
+    (Object.keys(units) as Unit[])
+      .map((u) => `${units[u]} ${gameOptions.units[u].name}`)
+      .join(", ");
+
+  const modeLocations = (): LocationOption[] => {
+    if (mode === "MOVE") {
+      return Object.keys(systems)
+        .filter((s) => s !== system)
+        .filter((s) => !systems[s].commandTokens.includes(player))
+        .flatMap((s) => playerLocations(s, systems[s], player));
+    }
+    if (mode === "LAND") {
+      return planets.map(planetOption);
+    }
+    if (mode === "PRODUCE" || mode === "PLACE") {
+      return [spaceOption(system), ...planets.map(planetOption)];
+    }
+    return playerLocations(system, activated, owner);
+  };
+
+  const locations = modeLocations();
+  const location = locations.find((l) => l.key === locationKey);
+
+  const send = () => {
+    const units = { [unit]: count };
+    if (mode === "COMBAT") {
+      sendEvent({
+        CombatResult: {
+          winner: winner,
+          loser: owner,
+          location: location!!.location,
+        },
+      });
+    } else if (mode === "MOVE") {
+      sendEvent({
+        MoveUnits: { player: player, from: location!!.location, units: units },
+      });
+    } else if (mode === "LAND") {
+      sendEvent({
+        LandUnits: {
+          player: player,
+          planet: (location!!.location as { Planet: Planet }).Planet,
+          units: units,
+        },
+      });
+    } else {
+      const event =
+        mode === "PRODUCE"
+          ? "ProduceUnits"
+          : mode === "PLACE"
+            ? "PlaceUnits"
+            : "RemoveUnits";
+      sendEvent({
+        [event]: {
+          player: mode === "REMOVE" ? owner : player,
+          location: location!!.location,
+          units: units,
+        },
+      });
+    }
+  };
+
+  const owners = Object.keys(gameState.players).filter(
+    (p) => playerLocations(system, activated, p).length > 0,
+  );
+
+  return (
+    <fieldset>
+      <legend>Units in {system}</legend>
+      <div className={styles.column}>
+        {Object.keys(activated.space).map((p) => (
+          <p key={`space-${p}`}>
+            Space, {p}: {unitsText(activated.space[p])}
+          </p>
+        ))}
+        {(Object.keys(activated.planets) as Planet[]).flatMap((planet) =>
+          Object.keys(activated.planets[planet]).map((p) => (
+            <p key={`${planet}-${p}`}>
+              {gameOptions.planetInfos[planet].name}, {p}:{" "}
+              {unitsText(activated.planets[planet][p])}
+            </p>
+          )),
+        )}
+        <div className={styles.takePlanetContainer}>
+          <Dropdown
+            value={mode}
+            onChange={(e) => {
+              setMode(e.target.value as UnitMode);
+              setLocationKey("");
+            }}
+          >
+            <option value="MOVE">Move units from</option>
+            <option value="LAND">Land units on</option>
+            <option value="PRODUCE">Produce units at</option>
+            <option value="PLACE">Place units at</option>
+            <option value="REMOVE">Remove units from</option>
+            <option value="COMBAT">Combat lost by</option>
+          </Dropdown>
+          {(mode === "REMOVE" || mode === "COMBAT") && (
+            <Dropdown
+              value={owner}
+              onChange={(e) => {
+                setOwner(e.target.value);
+                setLocationKey("");
+              }}
+            >
+              <option value="">--select a player--</option>
+              {owners.map((p) => (
+                <option key={p} value={p}>
+                  {p}
+                </option>
+              ))}
+            </Dropdown>
+          )}
+          <Dropdown
+            value={locationKey}
+            onChange={(e) => setLocationKey(e.target.value)}
+          >
+            <option value="">--select a location--</option>
+            {locations.map((l) => (
+              <option key={l.key} value={l.key}>
+                {l.label}
+              </option>
+            ))}
+          </Dropdown>
+          {mode === "COMBAT" ? (
+            <Dropdown
+              value={winner}
+              onChange={(e) => setWinner(e.target.value)}
+            >
+              <option value="">--select the winner--</option>
+              {Object.keys(gameState.players)
+                .filter((p) => p !== owner)
+                .map((p) => (
+                  <option key={p} value={p}>
+                    {p}
+                  </option>
+                ))}
+            </Dropdown>
+          ) : (
+            <>
+              <Dropdown
+                value={unit}
+                onChange={(e) => setUnit(e.target.value as Unit | "")}
+              >
+                <option value="">--select a unit--</option>
+                {(Object.keys(gameOptions.units) as Unit[]).map((u) => (
+                  <option key={u} value={u}>
+                    {gameOptions.units[u].name}
+                  </option>
+                ))}
+              </Dropdown>
+              <input
+                type="number"
+                min={1}
+                value={count}
+                onChange={(e) =>
+                  setCount(Math.max(1, e.target.valueAsNumber || 1))
+                }
+              />
+            </>
+          )}
+          <Button
+            disabled={
+              location === undefined ||
+              (mode === "COMBAT"
+                ? winner === "" || winner === owner
+                : unit === "")
+            }
+            onClick={send}
+          >
+            Confirm
+          </Button>
+        </div>
+      </div>
+    </fieldset>
+  );
+};
@@ -1,16 +1,24 @@
+import { ExplorationCard } from "@/api/bindings/ExplorationCard";
+import { IonStormSide } from "@/api/bindings/IonStormSide";
 import { Planet } from "@/api/bindings/Planet";
 import { PlanetAttachment } from "@/api/bindings/PlanetAttachment";
+import { PlanetTrait } from "@/api/bindings/PlanetTrait";
 import { Button } from "@/components/elements/button/Button";
 import { Dropdown } from "@/components/elements/dropdown/Dropdown";
 import { useState } from "react";
 import styles from "./TacticalView.module.scss";
 import { useGameContext } from "@/hooks/GameContext";
 import { nameSort } from "@/utils/Utils";
+import { ActivatedSystemUnits } from "./ActivatedSystemUnits";
 
 export const TacticalView = () => {
   const { gameState, gameOptions, sendEvent, isActive } = useGameContext();
 
   const [selectedPlanet, setSelectedPlanet] = useState<Planet | null>(null);
+  const [selectedFrontierSystem, setSelectedFrontierSystem] = useState<
+    string | null
+  >(null);
+  const [ionStormSide, setIonStormSide] = useState<IonStormSide>("Alpha");
   const currentPlayerPlanets =
     gameState.players[gameState.currentPlayer!!].planets;
 
@@ -22,6 +30,9 @@ export const TacticalView = () => {
   const activatedSystem = tactical.activatedSystem;
   const takenPlanets = tactical.takenPlanets;
   const attachments = tactical.planetAttachments;
+  const exploredPlanets = tactical.exploredPlanets;
+  const exploredFrontier = tactical.exploredFrontier;
+  const ionStorm = gameState.mapData.ionStorm;
   const anyPlayerOwnsMirage =
     Object.values(gameState.players)
       .flatMap((p) => Object.keys(p.planets) as Planet[])
@@ -57,11 +68,27 @@ export const TacticalView = () => {
     })
     .sort(nameSort);
 
-  const takePlanet = (planet: string) => {
+  const systemsWithoutPlanets = Object.values(gameOptions.systems)
+    .filter((s) => s.planets.length === 0 && s.systemType !== "Hyperlane")
+    .filter((s) => !gameState.mapData.exploredFrontiers.includes(s.id))
+    .filter((s) => activatedSystem === null || s.id === activatedSystem)
+    .sort((a, b) => a.id.localeCompare(b.id, undefined, { numeric: true }));
+
+  const takePlanet = (planet: Planet) => {
+    const ownedByOther = Object.values(gameState.players).some((p) =>
+      Object.keys(p.planets).includes(planet),
+    );
+    // Planets with a single trait are explored right away, others are explored manually.
+    const explore =
+      gameState.gameSettings.expansions.prophecyOfKings &&
+      gameOptions.planetInfos[planet].planetTraits.length === 1 &&
+      !ownedByOther;
     sendEvent({
       TacticalActionTakePlanet: {
         player: gameState.currentPlayer,
         planet: planet,
+        explore: explore,
+        planetTrait: null,
       },
     });
   };
@@ -71,7 +98,8 @@ export const TacticalView = () => {
       <h2>Tactical</h2>
       {isActive ? (
         <>
-          {Object.keys(takenPlanets).length > 0 ? (
+          {Object.keys(takenPlanets).length > 0 ||
+          gameState.gameSettings.trackUnits ? (
             <div className={styles.column}>
               {Object.keys(takenPlanets)
 
@@ -87,6 +115,16 @@ export const TacticalView = () => {
                         planet={p}
                         attachment={attachments[p] ?? null}
                         previousOwner={takenPlanets[p]}
+                        explored={exploredPlanets[p] ?? null}
+                        explore={(planetTrait) => {
+                          sendEvent({
+                            ExplorePlanet: {
+                              player: gameState.currentPlayer,
+                              planet: p,
+                              planetTrait: planetTrait,
+                            },
+                          });
+                        }}
                         selectAttachment={(attachment) => {
                           sendEvent({
                             TacticalActionAttachPlanetAttachment: {
@@ -103,7 +141,11 @@ export const TacticalView = () => {
               {availablePlanetsInSystem.length > 0 && (
                 <>
                   <fieldset>
-                    <legend>Take another planet</legend>
+                    <legend>
+                      {Object.keys(takenPlanets).length > 0
+                        ? "Take another planet"
+                        : "Take a planet"}
+                    </legend>
                     <div className={styles.selectAnotherPlanetContainer}>
                       {availablePlanetsInSystem.map((p) => (
                         <Button key={p.id} onClick={() => takePlanet(p.id)}>
@@ -139,6 +181,78 @@ export const TacticalView = () => {
               </Button>
             </div>
           )}
+          {gameState.gameSettings.trackUnits && activatedSystem !== null && (
+            <ActivatedSystemUnits system={activatedSystem} />
+          )}
+          <fieldset>
+            <legend>Frontier</legend>
+            {exploredFrontier !== null ? (
+              <div className={styles.column}>
+                <p>
+                  Explored: {gameOptions.frontierCards[exploredFrontier].name}
+                </p>
+                {gameState.players[gameState.currentPlayer!!]
+                  .secretObjectivesToDraw > 0 && (
+                  <p>You may draw 1 secret objective</p>
+                )}
+              </div>
+            ) : (
+              <div className={styles.takePlanetContainer}>
+                <Dropdown
+                  onChange={(e) => {
+                    const v = e.target.value;
+                    setSelectedFrontierSystem(v === "" ? null : v);
+                  }}
+                >
+                  <option value={""}>--select a system--</option>
+                  {systemsWithoutPlanets.map((s) => (
+                    <option key={s.id} value={s.id}>
+                      {s.id}
+                    </option>
+                  ))}
+                </Dropdown>
+                <Dropdown
+                  value={ionStormSide}
+                  onChange={(e) =>
+                    setIonStormSide(e.target.value as IonStormSide)
+                  }
+                >
+                  <option value="Alpha">Ion storm on alpha side</option>
+                  <option value="Beta">Ion storm on beta side</option>
+                </Dropdown>
+                <Button
+                  disabled={!selectedFrontierSystem}
+                  onClick={() =>
+                    sendEvent({
+                      ExploreFrontier: {
+                        player: gameState.currentPlayer,
+                        system: selectedFrontierSystem,
+                        ionStormSide: ionStormSide,
+                      },
+                    })
+                  }
+                >
+                  Explore
+                </Button>
+              </div>
+            )}
+          </fieldset>
+          {ionStorm !== null && (
+            <Button
+              onClick={() =>
+                sendEvent({
+                  FlipIonStorm: {
+                    player: gameState.currentPlayer,
+                  },
+                })
+              }
+            >
+              Flip ion storm in {ionStorm.system} (currently {ionStorm.side})
+            </Button>
+          )}
+          {gameState.score.custodians === null &&
+            activatedSystem !== null &&
+            ["18", "112"].includes(activatedSystem) && <RemoveCustodians />}
           <Button
             onClick={() =>
               sendEvent({
@@ -158,10 +272,79 @@ export const TacticalView = () => {
   );
 };
 
+const RemoveCustodians = () => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+  const [planets, setPlanets] = useState<Planet[]>([]);
+  const [tradeGoods, setTradeGoods] = useState<number>(0);
+
+  const player = gameState.players[gameState.currentPlayer!!];
+  const planetInfluence = (planet: Planet) =>
+    gameOptions.planetInfos[planet].influence +
+    player.planets[planet]!!.reduce(
+      (tot, a) => tot + gameOptions.planetAttachments[a].influence,
+      0,
+    );
+  const influence =
+    planets.reduce((tot, p) => tot + planetInfluence(p), 0) + tradeGoods;
+  const available = (Object.keys(player.planets) as Planet[])
+    .filter((p) => !planets.includes(p))
+    .map((p) => ({ id: p, ...gameOptions.planetInfos[p] }))
+    .sort(nameSort);
+
+  return (
+    <fieldset>
+      <legend>Remove custodians (6 influence)</legend>
+      <div className={styles.column}>
+        <p>
+          Exhausted:{" "}
+          {planets.map((p) => gameOptions.planetInfos[p].name).join(", ")}
+        </p>
+        <Dropdown
+          value=""
+          onChange={(e) => setPlanets([...planets, e.target.value as Planet])}
+        >
+          <option value="">--exhaust a planet--</option>
+          {available.map((p) => (
+            <option key={p.id} value={p.id}>
+              {p.name}
+            </option>
+          ))}
+        </Dropdown>
+        <label>
+          Trade goods:{" "}
+          <input
+            type="number"
+            min={0}
+            max={player.tradeGoods}
+            value={tradeGoods}
+            onChange={(e) => setTradeGoods(e.target.valueAsNumber || 0)}
+          />
+        </label>
+        <Button
+          disabled={influence < 6}
+          onClick={() =>
+            sendEvent({
+              TacticalActionRemoveCustodians: {
+                player: gameState.currentPlayer,
+                planets: planets,
+                tradeGoods: tradeGoods,
+              },
+            })
+          }
+        >
+          Remove custodians ({influence} influence)
+        </Button>
+      </div>
+    </fieldset>
+  );
+};
+
 interface SelectPlanetAttachmentProps {
   planet: Planet;
   attachment: PlanetAttachment | null;
   previousOwner: string | null;
+  explored: ExplorationCard | null;
+  explore: (planetTrait: PlanetTrait | null) => void;
   selectAttachment: (attachment: PlanetAttachment) => void;
 }
 
@@ -169,14 +352,21 @@ export const SelectPlanetAttachment = ({
   planet,
   attachment,
   previousOwner,
+  explored,
+  explore,
   selectAttachment,
 }: SelectPlanetAttachmentProps) => {
   const { gameState, gameOptions } = useGameContext();
   const [selectedAttachment, setSelectedAttachment] = useState<string>("");
 
   const planetInfo = gameOptions.planetInfos[planet];
+  // Thunder's Edge has no traits, but can be explored using any deck.
+  const exploreTraits: PlanetTrait[] =
+    planet === "ThundersEdge"
+      ? ["Cultural", "Hazardous", "Industrial"]
+      : planetInfo.planetTraits;
 
-  if (planetInfo.planetTrait === null) {
+  if (exploreTraits.length === 0) {
     return <div>Cannot explore</div>;
   }
 
@@ -184,6 +374,10 @@ export const SelectPlanetAttachment = ({
     return <p>Taken from {gameState.players[previousOwner].name}</p>;
   }
 
+  if (explored !== null) {
+    return <p>Explored: {gameOptions.explorationCards[explored].name}</p>;
+  }
+
   if (attachment !== null) {
     return <p>{gameOptions.planetAttachments[attachment].name}</p>;
   }
@@ -192,10 +386,10 @@ export const SelectPlanetAttachment = ({
     .map((a) => {
       return a as PlanetAttachment;
     })
-    .filter(
-      (a) =>
-        gameOptions.planetAttachments[a].planetTrait === planetInfo.planetTrait,
-    )
+    .filter((a) => {
+      const trait = gameOptions.planetAttachments[a].planetTrait;
+      return trait !== null && planetInfo.planetTraits.includes(trait);
+    })
     .filter((a) => !a.toLocaleLowerCase().endsWith("resources"))
     .map((a) => {
       return {
@@ -207,6 +401,15 @@ export const SelectPlanetAttachment = ({
 
   return (
     <>
+      {exploreTraits.length === 1 ? (
+        <Button onClick={() => explore(null)}>Explore</Button>
+      ) : (
+        exploreTraits.map((t) => (
+          <Button key={t} onClick={() => explore(t)}>
+            Explore {t}
+          </Button>
+        ))
+      )}
       <Dropdown
         value={selectedAttachment}
         onChange={(e) => setSelectedAttachment(e.target.value)}
@@ -0,0 +1,87 @@
+import { TechProgress } from "@/api/bindings/TechProgress";
+import { Button } from "@/components/elements/button/Button";
+import { Dropdown } from "@/components/elements/dropdown/Dropdown";
+import { useGameContext } from "@/hooks/GameContext";
+import { stringSort } from "@/utils/Utils";
+import { useState } from "react";
+
+export const TechActionView = () => {
+  const { gameState, gameOptions, isActive } = useGameContext();
+
+  const progress = gameState.actionProgress!!;
+  if (progress.t !== "Tech") {
+    return;
+  }
+  const tech = gameOptions.technologies[progress.tech];
+
+  return (
+    <div className="card column">
+      <h2>{tech.name}</h2>
+      {isActive ? (
+        <TechProgressView progress={progress} />
+      ) : (
+        <p>Not your turn, currently {gameState.currentPlayer} is playing</p>
+      )}
+    </div>
+  );
+};
+
+interface TechProgressViewProps {
+  progress: TechProgress;
+}
+
+const TechProgressView = ({ progress }: TechProgressViewProps) => {
+  const { gameState, sendEvent } = useGameContext();
+
+  const sendCommitMessage = (data: any) =>
+    sendEvent({
+      TechActionCommit: {
+        player: gameState.currentPlayer,
+        data: data,
+      },
+    });
+
+  switch (progress.tech) {
+    case "ProductionBiomes":
+    case "MageonImplants":
+      return (
+        <SelectOtherPlayerView
+          onCommit={(player) =>
+            sendCommitMessage({ [progress.tech]: { player: player } })
+          }
+        />
+      );
+    default:
+      return <Button onClick={() => sendCommitMessage(null)}>Commit</Button>;
+  }
+};
+
+interface SelectOtherPlayerViewProps {
+  onCommit: (player: string) => void;
+}
+
+const SelectOtherPlayerView = ({ onCommit }: SelectOtherPlayerViewProps) => {
+  const { gameState } = useGameContext();
+
+  const [selected, setSelected] = useState<string>("");
+
+  const players = Object.keys(gameState.players)
+    .filter((p) => p !== gameState.currentPlayer)
+    .sort(stringSort);
+
+  return (
+    <>
+      <Dropdown value={selected} onChange={(e) => setSelected(e.target.value)}>
+        <option value="">--Select Player--</option>
+        {players.map((p) => (
+          <option key={p} value={p}>
+            {p}
+          </option>
+        ))}
+      </Dropdown>
+      <Button disabled={selected === ""} onClick={() => onCommit(selected)}>
+        Commit
+      </Button>
+    </>
+  );
+};
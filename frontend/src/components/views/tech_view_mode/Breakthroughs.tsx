@@ -0,0 +1,89 @@
+import { Breakthrough } from "@/api/bindings/Breakthrough";
+import { Button } from "@/components/elements/button/Button";
+import { FactionIcon } from "@/components/elements/factionIcon/FactionIcon";
+import styles from "./TechViewMode.module.scss";
+import { useGameContext } from "@/hooks/GameContext";
+import { nameSort } from "@/utils/Utils";
+
+export const Breakthroughs = () => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+
+  const factionName = (breakthrough: Breakthrough) =>
+    gameOptions.factions.find((f) => f.faction === breakthrough)?.name ??
+    breakthrough;
+
+  const breakthroughs = Object.keys(gameState.players)
+    .flatMap((p) => {
+      const player = gameState.players[p];
+      return Object.entries(player.breakthroughs).map(([b, state]) => ({
+        player: p,
+        playerName: player.name,
+        faction: player.faction,
+        breakthrough: b as Breakthrough,
+        state: state,
+        name: `${factionName(b as Breakthrough)} breakthrough`,
+      }));
+    })
+    .sort(nameSort);
+
+  if (breakthroughs.length === 0) {
+    return null;
+  }
+
+  const unlock = (player: string, breakthrough: Breakthrough) =>
+    sendEvent({
+      UnlockBreakthrough: {
+        player: player,
+        breakthrough: breakthrough,
+      },
+    });
+
+  const exhaust = (player: string, breakthrough: Breakthrough) =>
+    sendEvent({
+      ExhaustBreakthrough: {
+        player: player,
+        breakthrough: breakthrough,
+      },
+    });
+
+  return (
+    <table className={`card ${styles.exhaustableTechsTable}`}>
+      <thead>
+        <tr>
+          <th colSpan={4}>
+            <h2>Breakthroughs</h2>
+          </th>
+        </tr>
+      </thead>
+      <tbody>
+        {breakthroughs.map((b) => (
+          <tr key={b.breakthrough}>
+            <td>
+              <FactionIcon faction={b.faction} width={16} height={16} />
+            </td>
+            <td>
+              <b>{b.playerName}</b>
+            </td>
+            <td>
+              {b.name} ({b.state.toLowerCase()})
+            </td>
+            <td>
+              {b.state === "Locked" ? (
+                <Button onClick={() => unlock(b.player, b.breakthrough)}>
+                  Unlock
+                </Button>
+              ) : (
+                <Button
+                  disabled={b.state === "Exhausted"}
+                  onClick={() => exhaust(b.player, b.breakthrough)}
+                >
+                  Exhaust
+                </Button>
+              )}
+            </td>
+          </tr>
+        ))}
+      </tbody>
+    </table>
+  );
+};
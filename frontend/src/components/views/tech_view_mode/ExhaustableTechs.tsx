@@ -0,0 +1,84 @@
+import { Technology } from "@/api/bindings/Technology";
+import { Button } from "@/components/elements/button/Button";
+import { FactionIcon } from "@/components/elements/factionIcon/FactionIcon";
+import styles from "./TechViewMode.module.scss";
+import React from "react";
+import { useGameContext } from "@/hooks/GameContext";
+import { nameSort } from "@/utils/Utils";
+
+export const ExhaustableTechs = () => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+
+  const players = Object.keys(gameState.players)
+    .map((p) => {
+      return {
+        id: p,
+        ...gameState.players[p],
+        exhaustable: gameState.players[p].technologies
+          .map((t) => ({ id: t, ...gameOptions.technologies[t] }))
+          .filter((t) => t.play !== null)
+          .sort(nameSort),
+      };
+    })
+    .filter((p) => p.exhaustable.length > 0)
+    .sort(nameSort);
+
+  if (players.length === 0) {
+    return null;
+  }
+
+  const exhaustTech = (player: string, tech: Technology) =>
+    sendEvent({
+      ExhaustTech: {
+        player: player,
+        tech: tech,
+      },
+    });
+
+  return (
+    <table className={`card ${styles.exhaustableTechsTable}`}>
+      <thead>
+        <tr>
+          <th colSpan={3}>
+            <h2>Exhaustable Techs</h2>
+          </th>
+        </tr>
+      </thead>
+      <tbody>
+        {players.map((p) => (
+          <React.Fragment key={p.id}>
+            <tr>
+              <td>
+                <FactionIcon faction={p.faction} width={16} height={16} />
+              </td>
+              <td colSpan={2}>
+                <b>{p.name}</b>
+              </td>
+            </tr>
+            {p.exhaustable.map((t) => {
+              const exhausted = p.exhaustedTechnologies.includes(t.id);
+              return (
+                <tr key={t.id}>
+                  <td />
+                  <td>
+                    {t.name} ({exhausted ? "exhausted" : "ready"})
+                  </td>
+                  <td>
+                    {t.play === "Ability" && (
+                      <Button
+                        disabled={exhausted}
+                        onClick={() => exhaustTech(p.id, t.id)}
+                      >
+                        Exhaust
+                      </Button>
+                    )}
+                  </td>
+                </tr>
+              );
+            })}
+          </React.Fragment>
+        ))}
+      </tbody>
+    </table>
+  );
+};
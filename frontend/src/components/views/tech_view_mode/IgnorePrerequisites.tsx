@@ -0,0 +1,73 @@
+import { Button } from "@/components/elements/button/Button";
+import { FactionIcon } from "@/components/elements/factionIcon/FactionIcon";
+import styles from "./TechViewMode.module.scss";
+import { useGameContext } from "@/hooks/GameContext";
+import { nameSort } from "@/utils/Utils";
+
+/* The backend uses the maximum value to ignore all prerequisites. */
+const IGNORE_ALL = 4294967295;
+
+export const IgnorePrerequisites = () => {
+  const { gameState, sendEvent } = useGameContext();
+
+  if (!gameState.gameSettings.validatePrerequisites) {
+    return null;
+  }
+
+  const players = Object.keys(gameState.players)
+    .map((p) => ({ id: p, ...gameState.players[p] }))
+    .sort(nameSort);
+
+  const ignorePrerequisites = (player: string, count: number) =>
+    sendEvent({
+      IgnorePrerequisites: {
+        player: player,
+        count: count,
+      },
+    });
+
+  return (
+    <table className={`card ${styles.exhaustableTechsTable}`}>
+      <thead>
+        <tr>
+          <th colSpan={4}>
+            <h2>Ignored Prerequisites</h2>
+          </th>
+        </tr>
+      </thead>
+      <tbody>
+        {players.map((p) => (
+          <tr key={p.id}>
+            <td>
+              <FactionIcon faction={p.faction} width={16} height={16} />
+            </td>
+            <td>
+              <b>{p.name}</b>
+            </td>
+            <td>
+              {p.ignoredPrerequisites === IGNORE_ALL
+                ? "All"
+                : p.ignoredPrerequisites}
+            </td>
+            <td>
+              <Button
+                disabled={p.ignoredPrerequisites === IGNORE_ALL}
+                onClick={() =>
+                  ignorePrerequisites(p.id, p.ignoredPrerequisites + 1)
+                }
+              >
+                +1
+              </Button>
+              <Button
+                disabled={p.ignoredPrerequisites === 0}
+                onClick={() => ignorePrerequisites(p.id, 0)}
+              >
+                Reset
+              </Button>
+            </td>
+          </tr>
+        ))}
+      </tbody>
+    </table>
+  );
+};
@@ -1,11 +1,19 @@
+import { Breakthroughs } from "./Breakthroughs";
+import { ExhaustableTechs } from "./ExhaustableTechs";
+import { IgnorePrerequisites } from "./IgnorePrerequisites";
 import { TechLedger } from "./TechLedger";
 import { TechTable } from "./TechTable";
+import { TechnologicalSingularity } from "./TechnologicalSingularity";
 import styles from "./TechViewMode.module.scss";
 
 export const TechViewMode = () => {
   return (
     <div className={styles.techViewContainer}>
       <TechLedger />
+      <ExhaustableTechs />
+      <Breakthroughs />
+      <IgnorePrerequisites />
+      <TechnologicalSingularity />
       <TechTable />
     </div>
   );
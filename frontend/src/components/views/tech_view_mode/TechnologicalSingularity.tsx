@@ -0,0 +1,128 @@
+import { Technology } from "@/api/bindings/Technology";
+import { ValefarAssimilator } from "@/api/bindings/ValefarAssimilator";
+import { Button } from "@/components/elements/button/Button";
+import { Dropdown } from "@/components/elements/dropdown/Dropdown";
+import styles from "./TechViewMode.module.scss";
+import { useState } from "react";
+import { useGameContext } from "@/hooks/GameContext";
+import { nameSort } from "@/utils/Utils";
+
+const ASSIMILATORS: ValefarAssimilator[] = ["X", "Y"];
+
+export const TechnologicalSingularity = () => {
+  const { gameState, gameOptions, sendEvent } = useGameContext();
+  const [from, setFrom] = useState<string | null>(null);
+  const [tech, setTech] = useState<Technology | null>(null);
+  const [assimilator, setAssimilator] = useState<ValefarAssimilator>("X");
+
+  const nekro = Object.keys(gameState.players).find(
+    (p) => gameState.players[p].faction === "NekroVirus",
+  );
+  if (nekro === undefined) {
+    return null;
+  }
+  const nekroPlayer = gameState.players[nekro];
+
+  const otherPlayers = Object.keys(gameState.players)
+    .filter((p) => p !== nekro)
+    .map((p) => ({ id: p, ...gameState.players[p] }))
+    .sort(nameSort);
+
+  const techs =
+    from !== null
+      ? gameState.players[from].technologies
+          .filter((t) => !nekroPlayer.technologies.includes(t))
+          .map((t) => ({ id: t, ...gameOptions.technologies[t] }))
+          .sort(nameSort)
+      : [];
+  const selectedTech = techs.find((t) => t.id === tech);
+  const canAssimilate =
+    selectedTech !== undefined &&
+    selectedTech.origin !== "Base" &&
+    selectedTech.techType === "UnitUpgrade";
+
+  const copyTech = () =>
+    sendEvent({
+      TechnologicalSingularity: {
+        player: nekro,
+        from: from!,
+        tech: tech!,
+      },
+    });
+
+  const placeAssimilator = () =>
+    sendEvent({
+      PlaceValefarAssimilator: {
+        player: nekro,
+        from: from!,
+        assimilator: assimilator,
+        tech: tech!,
+      },
+    });
+
+  return (
+    <div className={`card ${styles.technologicalSingularity}`}>
+      <h2>Technological Singularity</h2>
+      {ASSIMILATORS.map((a) => {
+        const placed = nekroPlayer.valefarAssimilators[a];
+        return (
+          <p key={a}>
+            Valefar Assimilator {a}:{" "}
+            {placed !== undefined
+              ? gameOptions.technologies[placed].name
+              : "Not placed"}
+          </p>
+        );
+      })}
+      <Dropdown
+        value={from ?? ""}
+        onChange={(e) => {
+          setFrom(e.target.value !== "" ? e.target.value : null);
+          setTech(null);
+        }}
+      >
+        <option value="">--Select Player--</option>
+        {otherPlayers.map((p) => (
+          <option key={p.id} value={p.id}>
+            {p.name}
+          </option>
+        ))}
+      </Dropdown>
+      <Dropdown
+        value={tech ?? ""}
+        onChange={(e) =>
+          setTech(
+            e.target.value !== "" ? (e.target.value as Technology) : null,
+          )
+        }
+      >
+        <option value="">--Select Tech--</option>
+        {techs.map((t) => (
+          <option key={t.id} value={t.id}>
+            {t.name}
+          </option>
+        ))}
+      </Dropdown>
+      <Button disabled={tech === null} onClick={copyTech}>
+        Copy tech
+      </Button>
+      <div>
+        <Dropdown
+          value={assimilator}
+          onChange={(e) =>
+            setAssimilator(e.target.value as ValefarAssimilator)
+          }
+        >
+          {ASSIMILATORS.map((a) => (
+            <option key={a} value={a}>
+              Valefar Assimilator {a}
+            </option>
+          ))}
+        </Dropdown>
+        <Button disabled={!canAssimilate} onClick={placeAssimilator}>
+          Place assimilator
+        </Button>
+      </div>
+    </div>
+  );
+};
@@ -8,6 +8,7 @@ export type GamePhase =
   | "ActionCardAction"
   | "FrontierCardAction"
   | "RelicAction"
+  | "TechAction"
   | "EndActionTurn"
   | "Status"
   | "Relics"